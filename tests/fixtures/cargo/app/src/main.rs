@@ -0,0 +1,7 @@
+use core_util::text::shout;
+
+fn main() {
+    core_util::init();
+    let message = shout("ready");
+    println!("{message}");
+}
@@ -0,0 +1,3 @@
+fn main() {
+    core_util::text::shout("tool");
+}
@@ -0,0 +1,8 @@
+pub mod text;
+
+#[path = "platform_unix.rs"]
+mod platform;
+
+pub fn init() {
+    platform::setup();
+}
@@ -0,0 +1,3 @@
+pub fn shout(input: &str) -> String {
+    input.to_uppercase()
+}
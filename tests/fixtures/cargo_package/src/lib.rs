@@ -0,0 +1,5 @@
+mod util;
+
+pub fn build() {
+    util::helper();
+}
@@ -0,0 +1,6 @@
+mod util;
+
+fn main() {
+    util::helper();
+    widget::build();
+}
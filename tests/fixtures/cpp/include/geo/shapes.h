@@ -0,0 +1,35 @@
+#ifndef GEO_SHAPES_H
+#define GEO_SHAPES_H
+
+namespace geo {
+
+class Shape {
+public:
+    virtual ~Shape() = default;
+    virtual double area() const = 0;
+    double describe() const;
+};
+
+class Circle : public Shape {
+public:
+    explicit Circle(double radius);
+    double area() const override;
+
+private:
+    double radius_;
+};
+
+class Square : public Shape {
+public:
+    explicit Square(double side) : side_(side) {}
+    double area() const override { return side_ * side_; }
+
+private:
+    double side_;
+};
+
+double pi();
+
+}  // namespace geo
+
+#endif
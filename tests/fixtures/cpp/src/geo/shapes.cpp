@@ -0,0 +1,17 @@
+#include "geo/shapes.h"
+
+namespace geo {
+
+namespace {
+double square(double x) { return x * x; }
+}  // namespace
+
+double pi() { return 3.14159265358979; }
+
+Circle::Circle(double radius) : radius_(radius) {}
+
+double Circle::area() const { return pi() * square(radius_); }
+
+}  // namespace geo
+
+double geo::Shape::describe() const { return area(); }
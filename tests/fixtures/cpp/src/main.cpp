@@ -0,0 +1,21 @@
+#include <cstdio>
+
+#include "geo/shapes.h"
+#include "util.h"
+
+using namespace geo;
+
+static void report(const Shape& shape) {
+    log_value(shape.describe());
+}
+
+int main(int argc, char** argv) {
+    Circle circle(2.0);
+    Square* square = new Square(3.0);
+    report(circle);
+    report(*square);
+    log_value(scale(circle.area(), 2));
+    log_value(argc);
+    delete square;
+    return 0;
+}
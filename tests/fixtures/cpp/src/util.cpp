@@ -0,0 +1,13 @@
+#include "util.h"
+
+#include <cstdio>
+
+static double clamp(double value) { return value < 0 ? 0 : value; }
+
+double scale(double value, int factor) { return clamp(value) * factor; }
+
+double scale(double value, double factor) { return clamp(value * factor); }
+
+void log_value(double value) { std::printf("%f\n", value); }
+
+void log_value(int value) { log_value(static_cast<double>(value)); }
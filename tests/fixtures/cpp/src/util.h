@@ -0,0 +1,7 @@
+#pragma once
+
+double scale(double value, int factor);
+double scale(double value, double factor);
+
+void log_value(double);
+void log_value(int);
@@ -0,0 +1,11 @@
+package main
+
+import "os"
+
+func init() {
+	parseFlags(os.Args)
+}
+
+func parseFlags(args []string) map[string]string {
+	return nil
+}
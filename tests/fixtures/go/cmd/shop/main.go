@@ -0,0 +1,18 @@
+package main
+
+import (
+	"fmt"
+
+	"example.com/shop/internal/cart"
+	"example.com/shop/pkg/go-money"
+)
+
+func main() {
+	c := cart.New()
+	c.Add(cart.Item{Name: "tea", Price: money.Cents(250)})
+	report(c)
+}
+
+func report(c *cart.Cart) {
+	fmt.Println(c.Total())
+}
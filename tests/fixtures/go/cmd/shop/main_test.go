@@ -0,0 +1,7 @@
+package main
+
+import "testing"
+
+func TestReport(t *testing.T) {
+	report(nil)
+}
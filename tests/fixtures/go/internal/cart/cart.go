@@ -0,0 +1,34 @@
+package cart
+
+import (
+	"github.com/google/uuid"
+
+	"example.com/shop/internal/pricing"
+)
+
+type Item struct {
+	Name  string
+	Price int64
+}
+
+type Cart struct {
+	ID    string
+	items []Item
+}
+
+func New() *Cart {
+	return &Cart{ID: uuid.NewString()}
+}
+
+func (c *Cart) Add(item Item) {
+	c.validate(item)
+	c.items = append(c.items, item)
+}
+
+func (c *Cart) Total() int64 {
+	var total int64
+	for _, item := range c.items {
+		total += item.Price
+	}
+	return pricing.WithTax(total)
+}
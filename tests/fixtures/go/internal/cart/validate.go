@@ -0,0 +1,7 @@
+package cart
+
+func (c *Cart) validate(item Item) {
+	if item.Price < 0 {
+		panic("negative price")
+	}
+}
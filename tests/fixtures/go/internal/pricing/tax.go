@@ -0,0 +1,11 @@
+package pricing
+
+const rate = 20
+
+func WithTax(amount int64) int64 {
+	return amount + percent(amount, rate)
+}
+
+func percent(amount, pct int64) int64 {
+	return amount * pct / 100
+}
@@ -0,0 +1,6 @@
+// Package money lives in a directory whose name differs from the package's.
+package money
+
+func Cents(n int64) int64 {
+	return n
+}
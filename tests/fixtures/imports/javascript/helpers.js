@@ -0,0 +1,11 @@
+export function normalize(value) {
+    return value.trim();
+}
+
+export function parse(value) {
+    return value.split(",");
+}
+
+export function report(items) {
+    console.log(items.length);
+}
@@ -0,0 +1,9 @@
+import { normalize as clean } from './helpers.js';
+import * as h from './helpers.js';
+const { parse } = require('./helpers');
+
+function main() {
+    const value = clean("  raw  ");
+    const items = parse(value);
+    h.report(items);
+}
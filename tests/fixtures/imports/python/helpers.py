@@ -0,0 +1,10 @@
+def normalize(value):
+    return value.strip()
+
+
+def parse(value):
+    return value.split(",")
+
+
+def report(items):
+    print(len(items))
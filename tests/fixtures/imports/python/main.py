@@ -0,0 +1,9 @@
+import helpers as h
+from helpers import normalize as clean
+from helpers import *
+
+
+def main():
+    value = clean("  raw  ")
+    items = parse(value)
+    h.report(items)
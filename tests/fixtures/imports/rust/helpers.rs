@@ -0,0 +1,11 @@
+pub fn normalize(input: &str) -> String {
+    input.trim().to_string()
+}
+
+pub fn parse(input: String) -> Vec<String> {
+    input.split(',').map(str::to_string).collect()
+}
+
+pub fn report(items: Vec<String>) {
+    println!("{}", items.len());
+}
@@ -0,0 +1,10 @@
+mod helpers;
+
+use helpers::normalize as clean;
+use helpers::*;
+
+fn main() {
+    let input = clean("  raw  ");
+    let parsed = parse(input);
+    helpers::report(parsed);
+}
@@ -0,0 +1,23 @@
+package com.acme.shop;
+
+import com.acme.shop.cart.Cart;
+import com.acme.shop.pricing.*;
+
+import static com.acme.shop.util.Strings.capitalize;
+
+public class App {
+    public static void main(String[] args) {
+        Cart cart = new Cart();
+        cart.add("apple", 2);
+        cart.add("pear");
+
+        PriceList prices = new PriceList();
+        Discount discount = new SeasonalDiscount(10);
+        double total = discount.apply(prices.total(cart));
+        System.out.println(capitalize(report(total)));
+    }
+
+    static String report(double total) {
+        return Formatter.format(total);
+    }
+}
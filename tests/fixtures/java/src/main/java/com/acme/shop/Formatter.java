@@ -0,0 +1,7 @@
+package com.acme.shop;
+
+class Formatter {
+    static String format(double value) {
+        return String.format("total: %.2f", value);
+    }
+}
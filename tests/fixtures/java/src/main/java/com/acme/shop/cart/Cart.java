@@ -0,0 +1,34 @@
+package com.acme.shop.cart;
+
+import java.util.ArrayList;
+import java.util.List;
+
+public class Cart {
+    private final List<Line> lines = new ArrayList<>();
+
+    public void add(String sku) {
+        add(sku, 1);
+    }
+
+    public void add(String sku, int quantity) {
+        lines.add(new Line(sku, quantity));
+    }
+
+    public List<Line> lines() {
+        return lines;
+    }
+
+    public static class Line {
+        private final String sku;
+        private final int quantity;
+
+        Line(String sku, int quantity) {
+            this.sku = sku;
+            this.quantity = quantity;
+        }
+
+        public int quantity() {
+            return quantity;
+        }
+    }
+}
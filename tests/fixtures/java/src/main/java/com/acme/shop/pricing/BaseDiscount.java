@@ -0,0 +1,13 @@
+package com.acme.shop.pricing;
+
+abstract class BaseDiscount implements Discount {
+    private final int percent;
+
+    protected BaseDiscount(int percent) {
+        this.percent = percent;
+    }
+
+    protected double reduce(double total) {
+        return total * (100 - percent) / 100;
+    }
+}
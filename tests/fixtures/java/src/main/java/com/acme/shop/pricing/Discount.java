@@ -0,0 +1,5 @@
+package com.acme.shop.pricing;
+
+public interface Discount {
+    double apply(double total);
+}
@@ -0,0 +1,13 @@
+package com.acme.shop.pricing;
+
+import com.acme.shop.cart.Cart;
+
+public class PriceList {
+    public double total(Cart cart) {
+        double sum = 0;
+        for (Cart.Line line : cart.lines()) {
+            sum += line.quantity() * 1.5;
+        }
+        return sum;
+    }
+}
@@ -0,0 +1,12 @@
+package com.acme.shop.pricing;
+
+public class SeasonalDiscount extends BaseDiscount {
+    public SeasonalDiscount(int percent) {
+        super(percent);
+    }
+
+    @Override
+    public double apply(double total) {
+        return Math.max(0, reduce(total));
+    }
+}
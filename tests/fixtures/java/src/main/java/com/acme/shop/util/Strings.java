@@ -0,0 +1,10 @@
+package com.acme.shop.util;
+
+public final class Strings {
+    private Strings() {
+    }
+
+    public static String capitalize(String text) {
+        return text.isEmpty() ? text : Character.toUpperCase(text.charAt(0)) + text.substring(1);
+    }
+}
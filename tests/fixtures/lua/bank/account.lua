@@ -0,0 +1,20 @@
+--[[ Accounts as a metatable-backed class ]]
+local Account = {}
+Account.__index = Account
+
+function Account.new(balance)
+  return setmetatable({ balance = balance or 0 }, Account)
+end
+
+function Account:deposit(amount)
+  self:validate(amount)
+  self.balance = self.balance + amount
+end
+
+function Account:validate(amount)
+  if amount <= 0 then
+    error("deposit must be positive")
+  end
+end
+
+return Account
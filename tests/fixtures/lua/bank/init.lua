@@ -0,0 +1,11 @@
+local bank = {}
+
+function bank.open(account)
+  bank.audit("opened", account)
+end
+
+function bank.audit(event, account)
+  print(event, account.balance)
+end
+
+return bank
@@ -0,0 +1,12 @@
+local M = {}
+
+function M.load(path)
+  local file = io.open(path)
+  return M.parse(file:read("a"))
+end
+
+function M.parse(text)
+  return { opening = tonumber(text) }
+end
+
+return M
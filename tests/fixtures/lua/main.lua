@@ -0,0 +1,14 @@
+local config = require("config")
+local Account = require("bank.account")
+local bank = require "bank"
+require("util.globals")
+
+local function run()
+  local settings = config.load("bank.ini")
+  local acct = Account.new(settings.opening)
+  acct:deposit(10)
+  bank.open(acct)
+  log_line("done")
+end
+
+run()
@@ -0,0 +1,4 @@
+-- Loaded for its side effect of defining globals
+function log_line(message)
+  print(os.date() .. " " .. message)
+end
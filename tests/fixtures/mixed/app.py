@@ -0,0 +1,12 @@
+import fastmath
+
+
+def total(values):
+    result = 0
+    for value in values:
+        result = fastmath.add(result, value)
+    return result
+
+
+def main():
+    print(total([1, 2, 3]))
@@ -0,0 +1,8 @@
+/// Exposed to Python as the `fastmath` extension module
+pub fn add(a: i64, b: i64) -> i64 {
+    saturate(a.checked_add(b))
+}
+
+fn saturate(value: Option<i64>) -> i64 {
+    value.unwrap_or(i64::MAX)
+}
@@ -0,0 +1,6 @@
+import { formatTotal } from './format.js';
+
+export async function fetchTotal(values) {
+  const response = await fetch('/api/total', { method: 'POST', body: JSON.stringify(values) });
+  return formatTotal(await response.json());
+}
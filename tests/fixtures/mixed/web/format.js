@@ -0,0 +1,3 @@
+export function formatTotal(total) {
+  return `Total: ${total}`;
+}
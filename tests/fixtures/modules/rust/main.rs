@@ -0,0 +1,30 @@
+mod net;
+mod store;
+
+mod util {
+    pub fn log(message: &str) {
+        println!("{message}");
+    }
+
+    pub mod fmt {
+        pub fn pad(text: &str) -> String {
+            super::log(text);
+            format!(" {text} ")
+        }
+    }
+}
+
+fn main() {
+    net::client::connect();
+    store::save();
+    util::fmt::pad("done");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn smoke() {
+        main();
+    }
+}
@@ -0,0 +1,3 @@
+pub fn connect() {
+    crate::util::log("connect");
+}
@@ -0,0 +1,3 @@
+pub fn put() {
+    super::super::util::log("put");
+}
@@ -0,0 +1,5 @@
+mod cache;
+
+pub fn save() {
+    cache::put();
+}
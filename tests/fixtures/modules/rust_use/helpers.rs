@@ -0,0 +1,5 @@
+mod inner;
+
+pub fn run() {
+    inner::step();
+}
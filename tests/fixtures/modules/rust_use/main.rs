@@ -0,0 +1,5 @@
+use helpers::run;
+
+fn main() {
+    run();
+}
@@ -0,0 +1,3 @@
+export function snakeCase(text) {
+  return text.toLowerCase().replace(/ /g, '_');
+}
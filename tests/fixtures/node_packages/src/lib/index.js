@@ -0,0 +1,7 @@
+const { pad } = require('../util/pad.js');
+
+function formatDate(date) {
+  return pad(date.getDate());
+}
+
+module.exports = { formatDate };
@@ -0,0 +1,15 @@
+import './polyfill';
+import { formatDate } from './lib';
+import { capitalize } from '@acme/strings';
+import { snakeCase } from '@acme/strings/case';
+import { add } from '@acme/math';
+import { chunk } from 'lodash';
+
+function main() {
+  const title = capitalize(formatDate(new Date()));
+  const key = snakeCase(title);
+  const total = add(1, 2);
+  chunk([title, key, total], 2);
+}
+
+main();
@@ -0,0 +1,5 @@
+function installPolyfills() {
+  globalThis.ready = true;
+}
+
+installPolyfills();
@@ -0,0 +1,5 @@
+function health(req, res) {
+  res.send('ok');
+}
+
+module.exports = health;
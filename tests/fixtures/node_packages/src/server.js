@@ -0,0 +1,9 @@
+const express = require('express');
+
+function start() {
+  const app = express();
+  app.use(require('./routes'));
+  app.listen(3000);
+}
+
+start();
@@ -0,0 +1,5 @@
+function pad(value) {
+  return String(value).padStart(2, '0');
+}
+
+module.exports = { pad };
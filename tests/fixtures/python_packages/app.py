@@ -0,0 +1,7 @@
+from services.api import create_app
+import services.helpers.text
+
+
+def main():
+    app = create_app()
+    services.helpers.text.clean(app)
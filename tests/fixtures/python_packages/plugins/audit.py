@@ -0,0 +1,2 @@
+def record(event):
+    print(event)
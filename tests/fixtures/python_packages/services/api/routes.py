@@ -0,0 +1,19 @@
+from flask import Flask
+
+from ..helpers import slugify
+from .. import helpers
+from . import validators
+from plugins.audit import record
+
+
+def create_app():
+    app = Flask(__name__)
+    validators.check_config(app)
+    register(app)
+    return app
+
+
+def register(app):
+    slugify("index")
+    helpers.clean("index")
+    record("registered")
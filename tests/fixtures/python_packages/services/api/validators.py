@@ -0,0 +1,2 @@
+def check_config(app):
+    return app is not None
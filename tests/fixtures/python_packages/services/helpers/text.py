@@ -0,0 +1,6 @@
+def slugify(value):
+    return clean(value).replace(" ", "-")
+
+
+def clean(value):
+    return value.strip().lower()
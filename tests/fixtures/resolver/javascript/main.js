@@ -0,0 +1,5 @@
+import greet from './greeter.js';
+
+function main() {
+  greet('world');
+}
@@ -0,0 +1,5 @@
+from models import Model
+
+
+def main():
+    Model.create()
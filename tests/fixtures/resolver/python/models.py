@@ -0,0 +1,9 @@
+class Model:
+    @classmethod
+    def create(cls):
+        cls.validate()
+        return cls()
+
+    @classmethod
+    def validate(cls):
+        pass
@@ -0,0 +1,11 @@
+mod net;
+mod util;
+
+use crate::net::fetch;
+
+fn main() {
+    fetch();
+    crate::util::log("started");
+    self::util::retry();
+    net::Client::new();
+}
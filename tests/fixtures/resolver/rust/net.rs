@@ -0,0 +1,16 @@
+use super::util::log;
+
+pub struct Client;
+
+impl Client {
+    pub fn new() -> Self {
+        log("client");
+        Client
+    }
+}
+
+pub fn fetch() {
+    log("fetch");
+    super::util::retry();
+    self::Client::new();
+}
@@ -0,0 +1,5 @@
+pub fn log(message: &str) {
+    println!("{message}");
+}
+
+pub fn retry() {}
@@ -0,0 +1,6 @@
+import importlib
+
+
+def main():
+    for name in ["plugins.greet", "plugins.legacy"]:
+        importlib.import_module(name).run()
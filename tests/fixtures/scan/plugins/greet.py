@@ -0,0 +1,5 @@
+from helpers import shout
+
+
+def run():
+    print(shout("hello"))
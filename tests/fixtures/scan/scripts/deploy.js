@@ -0,0 +1,7 @@
+function deploy() {
+  notify("deployed");
+}
+
+function notify(message) {
+  console.log(message);
+}
@@ -0,0 +1,10 @@
+import { log } from '@shared';
+
+export interface User {
+  name: string;
+}
+
+export async function fetchUser(id: number): Promise<User> {
+  log(`fetching ${id}`);
+  return { name: 'ada' };
+}
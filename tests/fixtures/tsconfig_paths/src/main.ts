@@ -0,0 +1,8 @@
+import { fetchUser } from '@app/services/api';
+import { log } from '@shared';
+import { formatName } from 'utils/format';
+
+export async function main(): Promise<void> {
+  const user = await fetchUser(1);
+  log(formatName(user.name));
+}
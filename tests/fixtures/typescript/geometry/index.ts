@@ -0,0 +1,5 @@
+export namespace Units {
+  export function toCentimetres(value: number): number {
+    return value * 100;
+  }
+}
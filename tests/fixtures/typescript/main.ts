@@ -0,0 +1,13 @@
+import { Shape, Square } from './shapes';
+import { Units } from './geometry';
+
+function report<T extends Shape>(shape: T, scale: number = 1): string {
+  const metres = shape.area();
+  return shape.describe(`${Units.toCentimetres(metres * scale)} cm`);
+}
+
+function main(): void {
+  report(new Square(2));
+}
+
+main();
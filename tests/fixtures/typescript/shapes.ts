@@ -0,0 +1,24 @@
+export interface Shape {
+  area(): number;
+  describe(label: string): string;
+}
+
+export abstract class Polygon implements Shape {
+  constructor(protected readonly sides: number) {}
+
+  abstract area(): number;
+
+  describe(label: string): string {
+    return `${label}: ${this.area()}`;
+  }
+}
+
+export class Square extends Polygon {
+  constructor(private readonly size: number) {
+    super(4);
+  }
+
+  area(): number {
+    return this.size * this.size;
+  }
+}
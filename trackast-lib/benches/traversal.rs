@@ -0,0 +1,120 @@
+//! Traversal benchmarks over synthetic graphs of increasing size
+//!
+//! Every graph has the same shape (each function calls up to three others),
+//! so time per element should stay flat as the size grows if traversal is
+//! linear. Criterion reports throughput in elements per second for each
+//! size; compare the numbers across sizes.
+//!
+//! Before benchmarking, `check_linear_scaling` times a DFS and a BFS on the
+//! smallest and largest graphs and fails the run if the time per element
+//! grows by more than [`MAX_SLOWDOWN`]. The bound is coarse, to absorb cache
+//! effects and timer noise, but a quadratic traversal is about 50 times
+//! slower per element across these sizes and trips it.
+
+use std::time::{Duration, Instant};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use trackast_lib::ast::{FunctionDef, Signature};
+use trackast_lib::function_id::FunctionId;
+use trackast_lib::graph::{CallGraph, GraphEdge, GraphNode};
+use trackast_lib::query::GraphQuery;
+use trackast_lib::traversal::{bfs_traversal, dfs_traversal, TraversalResult};
+
+const SIZES: [usize; 4] = [1_000, 5_000, 20_000, 50_000];
+
+/// Largest allowed growth in time per element from the smallest to the
+/// largest size
+const MAX_SLOWDOWN: f64 = 10.0;
+
+type Traversal = fn(&CallGraph, &FunctionId) -> TraversalResult;
+
+/// Build a graph of `size` functions where function `i` calls `i + 1`,
+/// `i * 2` and `i * 3` when those exist, giving roughly 3 edges per node
+fn synthetic_graph(size: usize) -> (CallGraph, FunctionId) {
+    let mut graph = CallGraph::new();
+    let ids: Vec<FunctionId> = (0..size)
+        .map(|i| FunctionId::new(format!("bench::f{i}::() -> ()")))
+        .collect();
+
+    for (i, id) in ids.iter().enumerate() {
+        let func = FunctionDef::new(format!("f{i}"), Signature::empty(), "bench".to_string());
+        graph.insert_node(GraphNode::internal(id.clone(), func)).unwrap();
+    }
+    for i in 0..size {
+        for target in [i + 1, i * 2, i * 3] {
+            if target < size && target != i {
+                graph
+                    .insert_edge(GraphEdge::new(ids[i].clone(), ids[target].clone(), 1))
+                    .unwrap();
+            }
+        }
+    }
+
+    (graph, ids[0].clone())
+}
+
+/// Best-of-five time per element, in nanoseconds, of `traverse` on a graph
+/// of `size` functions
+fn nanos_per_element(size: usize, traverse: Traversal) -> f64 {
+    let (graph, root) = synthetic_graph(size);
+    let elements = (graph.node_count() + graph.edge_count()) as f64;
+    let best = (0..5)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(traverse(black_box(&graph), black_box(&root)));
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or(Duration::ZERO);
+    best.as_nanos() as f64 / elements
+}
+
+fn check_linear_scaling(_: &mut Criterion) {
+    let (smallest, largest) = (SIZES[0], SIZES[SIZES.len() - 1]);
+    let traversals: [(&str, Traversal); 2] = [("dfs", dfs_traversal), ("bfs", bfs_traversal)];
+    for (name, traverse) in traversals {
+        let small = nanos_per_element(smallest, traverse);
+        let large = nanos_per_element(largest, traverse);
+        assert!(
+            large <= small.max(1.0) * MAX_SLOWDOWN,
+            "{name} is not linear: {small:.1} ns per element at {smallest} nodes, {large:.1} at {largest}"
+        );
+    }
+}
+
+fn bench_traversal(c: &mut Criterion) {
+    let mut group = c.benchmark_group("traversal");
+    for size in SIZES {
+        let (graph, root) = synthetic_graph(size);
+        let elements = (graph.node_count() + graph.edge_count()) as u64;
+        group.throughput(Throughput::Elements(elements));
+
+        group.bench_with_input(BenchmarkId::new("dfs", size), &graph, |b, graph| {
+            b.iter(|| dfs_traversal(black_box(graph), black_box(&root)));
+        });
+        group.bench_with_input(BenchmarkId::new("bfs", size), &graph, |b, graph| {
+            b.iter(|| bfs_traversal(black_box(graph), black_box(&root)));
+        });
+    }
+    group.finish();
+}
+
+fn bench_direct_callers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("direct_callers_all_nodes");
+    for size in SIZES {
+        let (graph, _) = synthetic_graph(size);
+        let ids: Vec<FunctionId> = graph.node_ids().cloned().collect();
+        group.throughput(Throughput::Elements((graph.node_count() + graph.edge_count()) as u64));
+
+        group.bench_with_input(BenchmarkId::from_parameter(size), &graph, |b, graph| {
+            b.iter(|| {
+                for id in &ids {
+                    black_box(graph.direct_callers(id));
+                }
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, check_linear_scaling, bench_traversal, bench_direct_callers);
+criterion_main!(benches);
@@ -1,3 +1,3 @@
 pub mod types;
 
-pub use types::{Signature, FunctionCall, FunctionDef, AbstractAST};
+pub use types::{Signature, FunctionCall, FunctionDef, AbstractAST, SourceSpan, Import};
@@ -1,9 +1,12 @@
 use std::fmt;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// Function signature with parameters and return type
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Signature {
+    /// Generic and lifetime parameters, e.g. `'a` or `T: Clone`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub type_params: Vec<String>,
     pub params: Vec<(String, String)>, // (name, type)
     pub return_type: String,
 }
@@ -11,16 +14,27 @@ pub struct Signature {
 impl Signature {
     #[must_use] 
     pub fn new(params: Vec<(String, String)>, return_type: String) -> Self {
-        Signature { params, return_type }
+        Signature {
+            type_params: vec![],
+            params,
+            return_type,
+        }
     }
 
     #[must_use] 
     pub fn empty() -> Self {
         Signature {
+            type_params: vec![],
             params: vec![],
             return_type: "()".to_string(),
         }
     }
+
+    #[must_use]
+    pub fn with_type_params(mut self, type_params: Vec<String>) -> Self {
+        self.type_params = type_params;
+        self
+    }
 }
 
 impl fmt::Display for Signature {
@@ -31,16 +45,39 @@ impl fmt::Display for Signature {
             .map(|(name, ty)| format!("{name}: {ty}"))
             .collect::<Vec<_>>()
             .join(", ");
+        if !self.type_params.is_empty() {
+            write!(f, "<{}>", self.type_params.join(", "))?;
+        }
         write!(f, "({}) -> {}", params_str, self.return_type)
     }
 }
 
 /// A function call within another function
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+///
+/// `line` and `column` are 1-based (the column counts bytes); 0 means unknown.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FunctionCall {
     pub target_name: String,
     pub target_module: Option<String>, // None = unresolved/external
     pub line: usize,
+    #[serde(default)]
+    pub column: usize,
+    /// Source file containing the call site
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    /// Path the call was made through, `::`-separated, e.g. `utils` in
+    /// `utils::helper()` or `np` in `np.array()`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub qualifier: Option<String>,
+    /// Number of arguments passed, used to pick between overloads; `None`
+    /// when the translator does not record it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub arg_count: Option<usize>,
+    /// Type of each argument where the call site shows it, e.g. through a
+    /// cast or a literal, used to pick between overloads of one arity;
+    /// empty when the translator does not record it
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub arg_types: Vec<Option<String>>,
 }
 
 impl FunctionCall {
@@ -50,17 +87,149 @@ impl FunctionCall {
             target_name,
             target_module,
             line,
+            column: 0,
+            file: None,
+            qualifier: None,
+            arg_count: None,
+            arg_types: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_column(mut self, column: usize) -> Self {
+        self.column = column;
+        self
+    }
+
+    #[must_use]
+    pub fn with_file(mut self, file: String) -> Self {
+        self.file = Some(file);
+        self
+    }
+
+    #[must_use]
+    pub fn with_qualifier(mut self, qualifier: String) -> Self {
+        self.qualifier = Some(qualifier);
+        self
+    }
+
+    #[must_use]
+    pub fn with_arg_count(mut self, arg_count: usize) -> Self {
+        self.arg_count = Some(arg_count);
+        self
+    }
+
+    #[must_use]
+    pub fn with_arg_types(mut self, arg_types: Vec<Option<String>>) -> Self {
+        self.arg_types = arg_types;
+        self
+    }
+}
+
+/// An import binding a local name in one module to another module or an item in it
+///
+/// Module paths use `::` separators regardless of the source language.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Import {
+    /// Module containing the import statement
+    pub module: String,
+    /// Module being imported from
+    pub source: String,
+    /// Imported item; `None` when the module itself is imported, `*` for a glob import
+    pub name: Option<String>,
+    /// Local name the import is bound to
+    pub alias: String,
+}
+
+impl Import {
+    /// `import source as alias` / `use source as alias`
+    #[must_use]
+    pub fn module(module: String, source: String, alias: String) -> Self {
+        Import {
+            module,
+            source,
+            name: None,
+            alias,
+        }
+    }
+
+    /// `from source import name as alias` / `use source::name as alias`
+    #[must_use]
+    pub fn item(module: String, source: String, name: String, alias: String) -> Self {
+        Import {
+            module,
+            source,
+            name: Some(name),
+            alias,
+        }
+    }
+
+    /// `from source import *` / `use source::*`
+    #[must_use]
+    pub fn glob(module: String, source: String) -> Self {
+        Import {
+            module,
+            source,
+            name: Some("*".to_string()),
+            alias: "*".to_string(),
+        }
+    }
+
+    #[must_use]
+    pub fn is_glob(&self) -> bool {
+        self.name.as_deref() == Some("*")
+    }
+}
+
+/// Location of a definition in its source file
+///
+/// Lines are 1-based and inclusive; bytes are a half-open range into the file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceSpan {
+    pub start_line: usize,
+    pub end_line: usize,
+    pub start_byte: usize,
+    pub end_byte: usize,
+}
+
+impl SourceSpan {
+    #[must_use]
+    pub fn new(start_line: usize, end_line: usize, start_byte: usize, end_byte: usize) -> Self {
+        SourceSpan {
+            start_line,
+            end_line,
+            start_byte,
+            end_byte,
+        }
+    }
+}
+
+impl fmt::Display for SourceSpan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.start_line == self.end_line {
+            write!(f, "{}", self.start_line)
+        } else {
+            write!(f, "{}-{}", self.start_line, self.end_line)
         }
     }
 }
 
 /// A function definition extracted from source code
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FunctionDef {
     pub name: String,
     pub signature: Signature,
     pub calls: Vec<FunctionCall>,
     pub module: String,
+    /// Source file containing the definition
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    /// Where the definition sits in `file`; `None` for synthetic or external functions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<SourceSpan>,
+    /// Language the definition is written in, e.g. `Rust`; `None` when unknown
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
 }
 
 impl FunctionDef {
@@ -71,6 +240,9 @@ impl FunctionDef {
             signature,
             calls: vec![],
             module,
+            file: None,
+            span: None,
+            language: None,
         }
     }
 
@@ -80,6 +252,28 @@ impl FunctionDef {
         self
     }
 
+    #[must_use]
+    pub fn with_span(mut self, span: SourceSpan) -> Self {
+        self.span = Some(span);
+        self
+    }
+
+    #[must_use]
+    pub fn with_language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    /// `file:start-end` for display, if the definition's location is known
+    #[must_use]
+    pub fn location(&self) -> Option<String> {
+        match (&self.file, &self.span) {
+            (Some(file), Some(span)) => Some(format!("{file}:{span}")),
+            (Some(file), None) => Some(file.clone()),
+            (None, _) => None,
+        }
+    }
+
     pub fn add_call(&mut self, call: FunctionCall) {
         self.calls.push(call);
     }
@@ -91,10 +285,12 @@ impl FunctionDef {
 }
 
 /// Abstract syntax tree representation of code, language-independent
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AbstractAST {
     pub functions: Vec<FunctionDef>,
     pub module_path: String,
+    #[serde(default)]
+    pub imports: Vec<Import>,
 }
 
 impl AbstractAST {
@@ -103,6 +299,7 @@ impl AbstractAST {
         AbstractAST {
             functions: vec![],
             module_path,
+            imports: vec![],
         }
     }
 
@@ -115,6 +312,37 @@ impl AbstractAST {
         self.functions.push(func);
     }
 
+    pub fn add_import(&mut self, import: Import) {
+        self.imports.push(import);
+    }
+
+    /// Move the functions and imports of another AST into this one
+    pub fn merge(&mut self, other: AbstractAST) {
+        self.functions.extend(other.functions);
+        self.imports.extend(other.imports);
+    }
+
+    /// Record the source file on every function and call site that does not have one yet
+    pub fn set_source_file(&mut self, file: &str) {
+        for func in &mut self.functions {
+            if func.file.is_none() {
+                func.file = Some(file.to_string());
+            }
+            for call in &mut func.calls {
+                if call.file.is_none() {
+                    call.file = Some(file.to_string());
+                }
+            }
+        }
+    }
+
+    /// Record the language on every function that does not have one yet
+    pub fn set_language(&mut self, language: &str) {
+        for func in self.functions.iter_mut().filter(|f| f.language.is_none()) {
+            func.language = Some(language.to_string());
+        }
+    }
+
     #[must_use] 
     pub fn module_path(&self) -> &str {
         &self.module_path
@@ -140,6 +368,13 @@ mod tests {
         assert_eq!(sig.to_string(), "() -> ()");
     }
 
+    #[test]
+    fn test_signature_display_with_type_params() {
+        let sig = Signature::new(vec![("value".to_string(), "&'a T".to_string())], "T".to_string())
+            .with_type_params(vec!["'a".to_string(), "T: Clone".to_string()]);
+        assert_eq!(sig.to_string(), "<'a, T: Clone>(value: &'a T) -> T");
+    }
+
     #[test]
     fn test_signature_equality() {
         let sig1 = Signature::new(
@@ -159,6 +394,49 @@ mod tests {
         assert_eq!(call.target_name, "foo");
         assert_eq!(call.target_module, Some("mod".to_string()));
         assert_eq!(call.line, 5);
+        assert_eq!(call.column, 0);
+        assert_eq!(call.file, None);
+    }
+
+    #[test]
+    fn test_set_source_file() {
+        let mut ast = AbstractAST::new("mymod".to_string());
+        let calls = vec![
+            FunctionCall::new("a".to_string(), None, 2).with_column(5),
+            FunctionCall::new("b".to_string(), None, 3).with_file("other.rs".to_string()),
+        ];
+        ast.add_function(FunctionDef::new("main".to_string(), Signature::empty(), "mymod".to_string()).with_calls(calls));
+
+        ast.set_source_file("src/mymod.rs");
+        let calls = &ast.functions[0].calls;
+        assert_eq!(calls[0].file.as_deref(), Some("src/mymod.rs"));
+        assert_eq!(calls[0].column, 5);
+        assert_eq!(calls[1].file.as_deref(), Some("other.rs"));
+        assert_eq!(ast.functions[0].file.as_deref(), Some("src/mymod.rs"));
+    }
+
+    #[test]
+    fn test_set_language() {
+        let mut ast = AbstractAST::new("app".to_string());
+        ast.add_function(FunctionDef::new("main".to_string(), Signature::empty(), "app".to_string()));
+        ast.add_function(FunctionDef::new("add".to_string(), Signature::empty(), "ext".to_string()).with_language("Rust"));
+
+        ast.set_language("Python");
+        let languages: Vec<Option<&str>> = ast.functions.iter().map(|f| f.language.as_deref()).collect();
+        assert_eq!(languages, [Some("Python"), Some("Rust")]);
+    }
+
+    #[test]
+    fn test_function_def_location() {
+        let func = FunctionDef::new("main".to_string(), Signature::empty(), "root".to_string());
+        assert_eq!(func.location(), None);
+
+        let mut func = func.with_span(SourceSpan::new(3, 7, 20, 95));
+        func.file = Some("src/main.rs".to_string());
+        assert_eq!(func.location().as_deref(), Some("src/main.rs:3-7"));
+
+        func.span = Some(SourceSpan::new(3, 3, 20, 40));
+        assert_eq!(func.location().as_deref(), Some("src/main.rs:3"));
     }
 
     #[test]
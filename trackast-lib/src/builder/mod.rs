@@ -1,3 +1,7 @@
+mod symbols;
+
+pub use symbols::{enclosing, join, SymbolTable};
+
 use std::collections::HashMap;
 use crate::ast::{AbstractAST, FunctionDef};
 use crate::function_id::FunctionId;
@@ -50,15 +54,17 @@ impl CallGraphBuilder {
             graph.insert_node(node)?;
         }
 
+        let symbols = self.symbols();
+
         // Add edges based on calls, marking unresolved calls as external
         for func_def in self.functions_map.values() {
             let from_id = func_def.fn_id();
 
             for call in &func_def.calls {
-                // Try to resolve the call
-                let to_id = if let Some(target_module) = &call.target_module {
-                    crate::function_id::generate_id(target_module, &call.target_name, &crate::ast::Signature::empty())
-                } else {
+                // Try to resolve the call; an overloaded name the call's
+                // arguments do not narrow down links to every candidate
+                let bound = symbols.resolve(&func_def.module, call);
+                let to_ids: Vec<FunctionId> = if bound.is_empty() {
                     // Unresolved call - create external node
                     let external_id = FunctionId::new(format!(
                         "<external>::{}::{}",
@@ -66,7 +72,7 @@ impl CallGraphBuilder {
                     ));
                     
                     // Add external node if it doesn't exist
-                    if !graph.nodes.contains_key(&external_id) {
+                    if !graph.contains_node(&external_id) {
                         let external_func = FunctionDef::new(
                             call.target_name.clone(),
                             crate::ast::Signature::empty(),
@@ -76,29 +82,36 @@ impl CallGraphBuilder {
                         graph.insert_node(external_node)?;
                     }
                     
-                    external_id
+                    vec![external_id]
+                } else {
+                    bound.into_iter().cloned().collect()
                 };
 
-                // Check if target exists, if not add it as external
-                if !graph.nodes.contains_key(&to_id) && !to_id.as_str().starts_with("<external>") {
-                    let external_func = FunctionDef::new(
-                        call.target_name.clone(),
-                        crate::ast::Signature::empty(),
-                        "<external>".to_string(),
-                    );
-                    let external_node = GraphNode::external(to_id.clone(), external_func);
-                    graph.insert_node(external_node)?;
+                for to_id in to_ids {
+                    // Add edge
+                    let mut edge = GraphEdge::new(from_id.clone(), to_id, call.line).with_column(call.column);
+                    edge.file.clone_from(&call.file);
+                    graph.insert_edge(edge)?;
                 }
-
-                // Add edge
-                let edge = GraphEdge::new(from_id.clone(), to_id, call.line);
-                graph.insert_edge(edge)?;
             }
         }
 
         Ok(graph)
     }
 
+    /// Index every added definition and import for call resolution
+    #[must_use]
+    pub fn symbols(&self) -> SymbolTable {
+        let mut symbols = SymbolTable::new();
+        for func in self.functions_map.values() {
+            symbols.add_function(func);
+        }
+        for import in self.asts.iter().flat_map(|ast| &ast.imports) {
+            symbols.add_import(import.clone());
+        }
+        symbols
+    }
+
     /// Build graph and traverse from entry points
     ///
     /// # Errors
@@ -112,7 +125,7 @@ impl CallGraphBuilder {
         let mut result = TraversalResult::new();
 
         for entry in entries {
-            if !graph.nodes.contains_key(entry) {
+            if !graph.contains_node(entry) {
                 return Err(format!("Entry point not found: {entry}"));
             }
             let entry_result = dfs_traversal(&graph, entry);
@@ -199,6 +212,66 @@ mod tests {
         assert_eq!(graph.edge_count(), 1);
     }
 
+    #[test]
+    fn test_build_resolves_call_to_typed_signature() {
+        let mut builder = CallGraphBuilder::new();
+        let mut ast = AbstractAST::new("root".to_string());
+
+        let mut main_func = FunctionDef::new("main".to_string(), Signature::empty(), "root".to_string());
+        main_func.add_call(FunctionCall::new("helper".to_string(), Some("root".to_string()), 5));
+        let helper_sig = Signature::new(vec![("x".to_string(), "i32".to_string())], "String".to_string());
+        let helper_func = FunctionDef::new("helper".to_string(), helper_sig, "root".to_string());
+        let helper_id = helper_func.fn_id();
+
+        ast.add_function(main_func);
+        ast.add_function(helper_func);
+        builder.add_ast(ast).unwrap();
+
+        let graph = builder.build().unwrap();
+        assert_eq!(graph.node_count(), 2);
+        assert_eq!(graph.edges[0].to, helper_id);
+    }
+
+    #[test]
+    fn test_overloads_link_to_the_candidates_argument_types_leave() {
+        let mut builder = CallGraphBuilder::new();
+        let mut ast = AbstractAST::new("root".to_string());
+        let overload = |ty: &str| {
+            let sig = Signature::new(vec![("value".to_string(), ty.to_string())], "void".to_string());
+            FunctionDef::new("log".to_string(), sig, "root".to_string())
+        };
+        // `log(value - 1)`, untyped, may be recursion; `log((double) value)` is not
+        let mut log_int = overload("int");
+        log_int.add_call(FunctionCall::new("log".to_string(), None, 2).with_arg_count(1));
+        log_int.add_call(
+            FunctionCall::new("log".to_string(), None, 3)
+                .with_arg_count(1)
+                .with_arg_types(vec![Some("double".to_string())]),
+        );
+        let log_int_id = log_int.fn_id();
+        let log_double_id = overload("double").fn_id();
+
+        ast.add_function(log_int);
+        ast.add_function(overload("double"));
+        builder.add_ast(ast).unwrap();
+
+        let graph = builder.build().unwrap();
+        let targets = |line: usize| -> Vec<&FunctionId> {
+            let mut targets: Vec<&FunctionId> = graph
+                .get_edges_from(&log_int_id)
+                .iter()
+                .filter(|edge| edge.line == line)
+                .map(|edge| &edge.to)
+                .collect();
+            targets.sort();
+            targets
+        };
+        let mut both = vec![&log_double_id, &log_int_id];
+        both.sort();
+        assert_eq!(targets(2), both);
+        assert_eq!(targets(3), [&log_double_id]);
+    }
+
     #[test]
     fn test_build_with_external_call() {
         let mut builder = CallGraphBuilder::new();
@@ -215,6 +288,26 @@ mod tests {
         assert_eq!(graph.edge_count(), 1);
     }
 
+    #[test]
+    fn test_unresolved_call_into_known_module_is_external() {
+        let mut builder = CallGraphBuilder::new();
+        let mut ast = AbstractAST::new("root".to_string());
+
+        let mut main_func = FunctionDef::new("main".to_string(), Signature::empty(), "root".to_string());
+        main_func.add_call(FunctionCall::new("missing".to_string(), Some("utils".to_string()), 5));
+        let main_id = main_func.fn_id();
+
+        ast.add_function(main_func);
+        ast.add_function(FunctionDef::new("helper".to_string(), Signature::empty(), "utils".to_string()));
+        builder.add_ast(ast).unwrap();
+
+        let graph = builder.build().unwrap();
+        let external_id = FunctionId::new("<external>::missing::()".to_string());
+        assert_eq!(graph.node_count(), 3);
+        assert!(graph.get_node(&external_id).unwrap().is_external);
+        assert_eq!(graph.get_edges_from(&main_id)[0].to, external_id);
+    }
+
     #[test]
     fn test_build_from_entries() {
         let mut builder = CallGraphBuilder::new();
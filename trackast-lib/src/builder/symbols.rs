@@ -0,0 +1,547 @@
+use std::collections::{HashMap, HashSet};
+use crate::ast::{AbstractAST, FunctionCall, FunctionDef, Import};
+use crate::function_id::FunctionId;
+
+/// How many re-exports are followed before giving up, which also stops
+/// import cycles
+const MAX_REEXPORT_DEPTH: usize = 8;
+
+/// Index of every known definition and import, used to bind calls to definitions
+///
+/// Calls carry a name and, optionally, the path they were made through and
+/// their argument count and the argument types the call site shows, but no
+/// signature, so definitions are indexed by `(module, name)` and overloads
+/// are told apart by arity, then by those argument types. Member
+/// functions are named `Type::method` (Rust) or `Class.method` (Python,
+/// JavaScript), and both separators are tried when a call is qualified by a
+/// type. A Rust trait method, `<Type as Trait>::method`, is also indexed as
+/// `Type::method`, where an inherent method of the same name wins.
+#[derive(Debug, Clone, Default)]
+pub struct SymbolTable {
+    by_name: HashMap<(String, String), Vec<Overload>>,
+    imports: HashMap<String, Vec<Import>>,
+    /// Every module that defines a function, and all of its ancestors
+    modules: HashSet<String>,
+}
+
+/// One definition of a possibly overloaded name
+#[derive(Debug, Clone)]
+struct Overload {
+    id: FunctionId,
+    /// Parameter types, without an explicit receiver
+    params: Vec<String>,
+    /// Whether the last parameter takes any number of arguments (`...`)
+    variadic: bool,
+    return_type: String,
+    /// Whether this is a trait method indexed under its type's name
+    from_trait: bool,
+}
+
+impl Overload {
+    fn accepts(&self, arg_count: usize) -> bool {
+        arg_count == self.params.len() || (self.variadic && arg_count + 1 >= self.params.len())
+    }
+
+    /// Whether every argument type the call shows is the type of its
+    /// parameter, ignoring `const` and references
+    fn takes(&self, arg_types: &[Option<String>]) -> bool {
+        arg_types.iter().enumerate().all(|(i, arg_type)| match (arg_type, self.params.get(i)) {
+            (Some(arg_type), Some(param)) => value_type(arg_type) == value_type(param),
+            _ => true,
+        })
+    }
+}
+
+/// A type as passed by value: `const std::string&` is `std::string`
+fn value_type(ty: &str) -> &str {
+    let ty = ty.trim();
+    ty.strip_prefix("const ").unwrap_or(ty).trim_end_matches('&').trim()
+}
+
+impl SymbolTable {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a definition
+    pub fn add_function(&mut self, func: &FunctionDef) {
+        // An explicit receiver (`self`, C++'s `this` on const methods) is
+        // not among a call's arguments
+        let params = &func.signature.params;
+        let receiver = params.first().is_some_and(|(name, _)| name == "self" || name == "this");
+        let overload = Overload {
+            id: func.fn_id(),
+            params: params.iter().skip(usize::from(receiver)).map(|(_, ty)| ty.clone()).collect(),
+            variadic: params.last().is_some_and(|(_, ty)| ty.ends_with("...")),
+            return_type: func.signature.return_type.clone(),
+            from_trait: false,
+        };
+        if let Some(member) = trait_member(&func.name) {
+            let alias = Overload { from_trait: true, ..overload.clone() };
+            self.add_overload(&func.module, member, alias);
+        }
+        self.add_overload(&func.module, func.name.clone(), overload);
+
+        let mut module = Some(func.module.as_str());
+        while let Some(path) = module.filter(|path| !path.is_empty()) {
+            if !self.modules.insert(path.to_string()) {
+                break;
+            }
+            module = enclosing(path);
+        }
+    }
+
+    fn add_overload(&mut self, module: &str, name: String, overload: Overload) {
+        let candidates = self.by_name.entry((module.to_string(), name)).or_default();
+        candidates.push(overload);
+        // Keep lookups deterministic when a name has several signatures
+        candidates.sort_by(|a, b| a.id.cmp(&b.id));
+    }
+
+    /// Register an import of the module named in `import.module`
+    pub fn add_import(&mut self, import: Import) {
+        self.imports.entry(import.module.clone()).or_default().push(import);
+    }
+
+    /// Imports declared by a module
+    #[must_use]
+    pub fn imports_of(&self, module: &str) -> &[Import] {
+        self.imports.get(module).map_or(&[], Vec::as_slice)
+    }
+
+    /// Index every definition and import of an AST
+    #[must_use]
+    pub fn from_ast(ast: &AbstractAST) -> Self {
+        let mut table = Self::new();
+        for func in &ast.functions {
+            table.add_function(func);
+        }
+        for import in &ast.imports {
+            table.add_import(import.clone());
+        }
+        table
+    }
+
+    /// Find the definitions named `name` in `module` that `call` may bind
+    /// to, sorted by ID
+    ///
+    /// Inherent methods are preferred over trait methods of the same type.
+    /// Overloads are then narrowed to those taking the call's argument count
+    /// when it is known, then to those whose parameters have the argument
+    /// types the call shows. A step that leaves no overload is skipped (default
+    /// arguments and conversions are not modelled), and an unknown count
+    /// keeps every overload: a call is never bound to an arbitrary one of
+    /// several candidates.
+    #[must_use]
+    pub fn lookup(&self, module: &str, name: &str, call: &FunctionCall) -> Vec<&FunctionId> {
+        let Some(candidates) = self.by_name.get(&(module.to_string(), name.to_string())) else {
+            return Vec::new();
+        };
+        let mut matching: Vec<&Overload> = candidates.iter().collect();
+        narrow(&mut matching, |candidate| !candidate.from_trait);
+        if let Some(count) = call.arg_count {
+            narrow(&mut matching, |candidate| candidate.accepts(count));
+            narrow(&mut matching, |candidate| candidate.takes(&call.arg_types));
+        }
+        matching.into_iter().map(|candidate| &candidate.id).collect()
+    }
+
+    /// Find a member function `owner::name` or `owner.name` in `module`,
+    /// returning the definition's `(module, name)`
+    #[must_use]
+    pub fn lookup_member(&self, module: &str, owner: &str, name: &str) -> Option<(&str, &str)> {
+        self.key(module, &format!("{owner}::{name}"))
+            .or_else(|| self.key(module, &format!("{owner}.{name}")))
+    }
+
+    /// The return type of `name` in `module`, when it has a single definition
+    #[must_use]
+    pub fn return_type(&self, module: &str, name: &str) -> Option<&str> {
+        match self.by_name.get(&(module.to_string(), name.to_string()))?.as_slice() {
+            [only] => Some(only.return_type.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Whether `module` or one of its descendants defines a function
+    #[must_use]
+    pub fn has_module(&self, module: &str) -> bool {
+        self.modules.contains(module)
+    }
+
+    /// Whether `module` defines a function named `name`
+    #[must_use]
+    pub fn contains(&self, module: &str, name: &str) -> bool {
+        self.key(module, name).is_some()
+    }
+
+    /// Bind a call made from `caller_module` to the definitions it may call
+    ///
+    /// See [`SymbolTable::resolve_target`] for the resolution order and
+    /// [`SymbolTable::lookup`] for how overloads are narrowed. Empty when the
+    /// call cannot be bound.
+    #[must_use]
+    pub fn resolve(&self, caller_module: &str, call: &FunctionCall) -> Vec<&FunctionId> {
+        self.resolve_target(caller_module, call)
+            .map(|(module, name)| self.lookup(module, name, call))
+            .unwrap_or_default()
+    }
+
+    /// Bind a call made from `caller_module`, returning the definition's
+    /// `(module, name)`
+    ///
+    /// Resolution order:
+    /// 1. an explicit `target_module` set by the translator
+    /// 2. for bare calls: the caller's own module, then imported items
+    ///    (honouring aliases), then glob imports, following re-exports of the
+    ///    imported modules
+    /// 3. for qualified calls: the qualifier's head as an import alias, the
+    ///    qualifier as a module path relative to the caller and each of its
+    ///    ancestors, then as a type in the caller's module or a glob import
+    ///
+    /// Returns `None` when the call cannot be bound, i.e. it is external.
+    #[must_use]
+    pub fn resolve_target(&self, caller_module: &str, call: &FunctionCall) -> Option<(&str, &str)> {
+        let name = call.target_name.as_str();
+        if let Some(target_module) = &call.target_module {
+            return self.key(target_module, name);
+        }
+
+        match &call.qualifier {
+            None => self.resolve_bare(caller_module, name),
+            Some(qualifier) => self.resolve_qualified(caller_module, qualifier, name),
+        }
+    }
+
+    fn key(&self, module: &str, name: &str) -> Option<(&str, &str)> {
+        self.by_name
+            .get_key_value(&(module.to_string(), name.to_string()))
+            .map(|((module, name), _)| (module.as_str(), name.as_str()))
+    }
+
+    /// Find `name` as seen from outside `module`: defined there, or
+    /// re-exported through one of its imports (`from .impl import f` in a
+    /// package `__init__.py`, `pub use` in Rust)
+    fn lookup_exported(&self, module: &str, name: &str, depth: usize) -> Option<(&str, &str)> {
+        if let Some(target) = self.key(module, name) {
+            return Some(target);
+        }
+        if depth == 0 {
+            return None;
+        }
+        let imports = self.imports_of(module);
+        imports
+            .iter()
+            .filter(|import| !import.is_glob() && import.alias == name)
+            .find_map(|import| {
+                import
+                    .name
+                    .as_deref()
+                    .and_then(|item| self.lookup_exported(&import.source, item, depth - 1))
+            })
+            .or_else(|| {
+                imports
+                    .iter()
+                    .filter(|import| import.is_glob())
+                    .find_map(|import| self.lookup_exported(&import.source, name, depth - 1))
+            })
+    }
+
+    fn resolve_bare(&self, caller_module: &str, name: &str) -> Option<(&str, &str)> {
+        self.key(caller_module, name)
+            .or_else(|| self.lookup_exported(caller_module, name, MAX_REEXPORT_DEPTH))
+    }
+
+    fn resolve_qualified(&self, caller_module: &str, qualifier: &str, name: &str) -> Option<(&str, &str)> {
+        let imports = self.imports_of(caller_module);
+        let (head, rest) = qualifier
+            .split_once("::")
+            .map_or((qualifier, ""), |(head, rest)| (head, rest));
+
+        // Through an import alias: `import utils as u; u.helper()`
+        for import in imports.iter().filter(|i| !i.is_glob() && i.alias == head) {
+            let base = match &import.name {
+                Some(item) => join(&import.source, item),
+                None => import.source.clone(),
+            };
+            if let Some(target) = self.lookup_exported(&join(&base, rest), name, MAX_REEXPORT_DEPTH) {
+                return Some(target);
+            }
+            // The import names a type: `use a::Foo; Foo::new()`
+            if let (Some(item), "") = (&import.name, rest) {
+                if let Some(target) = self.lookup_member(&import.source, item, name) {
+                    return Some(target);
+                }
+            }
+            // A type inside the imported module: `import "shop/cart"; c.Total()`
+            // with `c *cart.Cart`
+            if !rest.is_empty() {
+                let (path, owner) = rest.rsplit_once("::").unwrap_or(("", rest));
+                if let Some(target) = self.lookup_member(&join(&base, path), owner, name) {
+                    return Some(target);
+                }
+            }
+        }
+
+        // As a module path, or a type inside one, relative to the caller and
+        // then each ancestor
+        let type_path = qualifier.rsplit_once("::");
+        let mut scope = Some(caller_module);
+        while let Some(prefix) = scope {
+            if let Some(target) = self.key(&join(prefix, qualifier), name) {
+                return Some(target);
+            }
+            if let Some((module, owner)) = type_path {
+                if let Some(target) = self.lookup_member(&join(prefix, module), owner, name) {
+                    return Some(target);
+                }
+            }
+            scope = enclosing(prefix);
+        }
+
+        // As a type defined in the caller's module or pulled in by a glob
+        self.lookup_member(caller_module, qualifier, name).or_else(|| {
+            imports
+                .iter()
+                .filter(|import| import.is_glob())
+                .find_map(|import| self.lookup_member(&import.source, qualifier, name))
+        })
+    }
+}
+
+/// `Type::method` for a trait method named `<Type as Trait>::method`
+fn trait_member(name: &str) -> Option<String> {
+    let (ty, rest) = name.strip_prefix('<')?.split_once(" as ")?;
+    let (_, method) = rest.rsplit_once(">::")?;
+    Some(format!("{ty}::{method}"))
+}
+
+/// Keep the overloads `keep` accepts, unless it accepts none
+fn narrow(overloads: &mut Vec<&Overload>, keep: impl Fn(&Overload) -> bool) {
+    if overloads.iter().any(|overload| keep(overload)) {
+        overloads.retain(|overload| keep(overload));
+    }
+}
+
+/// Join two `::`-separated paths, either of which may be empty
+#[must_use]
+pub fn join(base: &str, rest: &str) -> String {
+    match (base.is_empty(), rest.is_empty()) {
+        (true, _) => rest.to_string(),
+        (_, true) => base.to_string(),
+        _ => format!("{base}::{rest}"),
+    }
+}
+
+/// The module enclosing `module`; the root has none
+#[must_use]
+pub fn enclosing(module: &str) -> Option<&str> {
+    if module.is_empty() {
+        None
+    } else {
+        Some(module.rsplit_once("::").map_or("", |(parent, _)| parent))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::Signature;
+
+    fn table(functions: &[(&str, &str)], imports: Vec<Import>) -> SymbolTable {
+        let mut table = SymbolTable::new();
+        for (module, name) in functions {
+            table.add_function(&FunctionDef::new((*name).to_string(), Signature::empty(), (*module).to_string()));
+        }
+        for import in imports {
+            table.add_import(import);
+        }
+        table
+    }
+
+    fn call(name: &str) -> FunctionCall {
+        FunctionCall::new(name.to_string(), None, 1)
+    }
+
+    fn resolved(table: &SymbolTable, caller: &str, call: &FunctionCall) -> Option<String> {
+        match table.resolve(caller, call).as_slice() {
+            [] => None,
+            [id] => Some(id.as_str().to_string()),
+            ids => panic!("{} is ambiguous: {ids:?}", call.target_name),
+        }
+    }
+
+    #[test]
+    fn test_bare_call_in_same_module() {
+        let table = table(&[("app", "helper")], vec![]);
+        assert_eq!(resolved(&table, "app", &call("helper")).as_deref(), Some("app::helper::() -> ()"));
+        assert_eq!(resolved(&table, "other", &call("helper")), None);
+    }
+
+    #[test]
+    fn test_trait_methods_are_found_by_type() {
+        let inherent = FunctionCall::new("T::run".to_string(), Some("app".to_string()), 1);
+        let only_trait = table(&[("app", "<T as Tr>::run")], vec![]);
+        assert_eq!(resolved(&only_trait, "app", &inherent).as_deref(), Some("app::<T as Tr>::run::() -> ()"));
+
+        let both = table(&[("app", "<T as Tr>::run"), ("app", "T::run")], vec![]);
+        assert_eq!(resolved(&both, "app", &inherent).as_deref(), Some("app::T::run::() -> ()"));
+    }
+
+    #[test]
+    fn test_bare_call_through_imported_item() {
+        let imports = vec![
+            Import::item("app".into(), "utils".into(), "helper".into(), "helper".into()),
+            Import::item("app".into(), "utils".into(), "load".into(), "fetch".into()),
+        ];
+        let table = table(&[("utils", "helper"), ("utils", "load")], imports);
+        assert_eq!(resolved(&table, "app", &call("helper")).as_deref(), Some("utils::helper::() -> ()"));
+        assert_eq!(resolved(&table, "app", &call("fetch")).as_deref(), Some("utils::load::() -> ()"));
+        // The original name is not bound once aliased
+        assert_eq!(resolved(&table, "app", &call("load")), None);
+    }
+
+    #[test]
+    fn test_bare_call_through_glob() {
+        let imports = vec![Import::glob("app".into(), "utils".into())];
+        let table = table(&[("utils", "helper")], imports);
+        assert_eq!(resolved(&table, "app", &call("helper")).as_deref(), Some("utils::helper::() -> ()"));
+    }
+
+    #[test]
+    fn test_calls_through_reexports() {
+        let imports = vec![
+            // app: `from pkg import helper` and `import pkg`
+            Import::item("app".into(), "pkg".into(), "helper".into(), "helper".into()),
+            Import::module("app".into(), "pkg".into(), "pkg".into()),
+            // pkg/__init__.py: `from .impl import helper` and `from .more import *`
+            Import::item("pkg".into(), "pkg::impl".into(), "helper".into(), "helper".into()),
+            Import::glob("pkg".into(), "pkg::more".into()),
+            // Cycles end
+            Import::glob("pkg::more".into(), "pkg".into()),
+        ];
+        let table = table(&[("pkg::impl", "helper"), ("pkg::more", "extra")], imports);
+        assert_eq!(resolved(&table, "app", &call("helper")).as_deref(), Some("pkg::impl::helper::() -> ()"));
+        let extra = call("extra").with_qualifier("pkg".into());
+        assert_eq!(resolved(&table, "app", &extra).as_deref(), Some("pkg::more::extra::() -> ()"));
+        let missing = call("missing").with_qualifier("pkg".into());
+        assert_eq!(resolved(&table, "app", &missing), None);
+    }
+
+    #[test]
+    fn test_qualified_call_through_module_alias() {
+        let imports = vec![
+            Import::module("app".into(), "utils".into(), "u".into()),
+            Import::item("app".into(), "pkg".into(), "sub".into(), "s".into()),
+        ];
+        let table = table(&[("utils", "helper"), ("pkg::sub::inner", "run")], imports);
+        let helper = call("helper").with_qualifier("u".into());
+        assert_eq!(resolved(&table, "app", &helper).as_deref(), Some("utils::helper::() -> ()"));
+        let run = call("run").with_qualifier("s::inner".into());
+        assert_eq!(resolved(&table, "app", &run).as_deref(), Some("pkg::sub::inner::run::() -> ()"));
+    }
+
+    #[test]
+    fn test_qualified_call_by_module_path() {
+        let table = table(&[("utils", "helper"), ("app::nested", "go")], vec![]);
+        let helper = call("helper").with_qualifier("utils".into());
+        assert_eq!(resolved(&table, "app::deep", &helper).as_deref(), Some("utils::helper::() -> ()"));
+        let go = call("go").with_qualifier("nested".into());
+        assert_eq!(resolved(&table, "app", &go).as_deref(), Some("app::nested::go::() -> ()"));
+    }
+
+    #[test]
+    fn test_qualified_call_on_type() {
+        let imports = vec![Import::item("app".into(), "shapes".into(), "Circle".into(), "Circle".into())];
+        let table = table(&[("app", "Parser::new"), ("app", "Model.load"), ("shapes", "Circle::area")], imports);
+        let parser = call("new").with_qualifier("Parser".into());
+        assert_eq!(resolved(&table, "app", &parser).as_deref(), Some("app::Parser::new::() -> ()"));
+        let model = call("load").with_qualifier("Model".into());
+        assert_eq!(resolved(&table, "app", &model).as_deref(), Some("app::Model.load::() -> ()"));
+        let area = call("area").with_qualifier("Circle".into());
+        assert_eq!(resolved(&table, "app", &area).as_deref(), Some("shapes::Circle::area::() -> ()"));
+        let by_path = call("area").with_qualifier("shapes::Circle".into());
+        assert_eq!(resolved(&table, "app", &by_path).as_deref(), Some("shapes::Circle::area::() -> ()"));
+    }
+
+    #[test]
+    fn test_qualified_call_on_type_through_module_alias() {
+        let imports = vec![Import::module("app".into(), "shop::cart".into(), "cart".into())];
+        let table = table(&[("shop::cart", "Cart::Total")], imports);
+        let total = call("Total").with_qualifier("cart::Cart".into());
+        assert_eq!(resolved(&table, "app", &total).as_deref(), Some("shop::cart::Cart::Total::() -> ()"));
+    }
+
+    #[test]
+    fn test_unknown_receiver_is_unresolved() {
+        let table = table(&[("app", "method")], vec![]);
+        let method = call("method").with_qualifier("obj".into());
+        assert_eq!(resolved(&table, "app", &method), None);
+    }
+
+    #[test]
+    fn test_resolve_target_names_definition() {
+        let table = table(&[("app", "Parser::new")], vec![]);
+        let parser = call("new").with_qualifier("Parser".into());
+        assert_eq!(table.resolve_target("app", &parser), Some(("app", "Parser::new")));
+        assert!(table.contains("app", "Parser::new"));
+        assert!(!table.contains("app", "new"));
+    }
+
+    #[test]
+    fn test_has_module_includes_ancestors() {
+        let table = table(&[("app::net::client", "connect")], vec![]);
+        assert!(table.has_module("app"));
+        assert!(table.has_module("app::net"));
+        assert!(table.has_module("app::net::client"));
+        assert!(!table.has_module("net"));
+    }
+
+    #[test]
+    fn test_explicit_target_module() {
+        let table = table(&[("app", "Impl::run")], vec![]);
+        let run = FunctionCall::new("Impl::run".into(), Some("app".into()), 1);
+        assert_eq!(resolved(&table, "elsewhere", &run).as_deref(), Some("app::Impl::run::() -> ()"));
+    }
+
+    #[test]
+    fn test_overloads_are_narrowed_by_argument_count() {
+        let mut table = SymbolTable::new();
+        for params in ["int", "int, int", "long, long", "int, ..."] {
+            let params = params.split(", ").enumerate().map(|(i, ty)| (format!("p{i}"), ty.to_string())).collect();
+            table.add_function(&FunctionDef::new("f".to_string(), Signature::new(params, "void".to_string()), "app".to_string()));
+        }
+        let ids = |arg_count: Option<usize>| -> Vec<String> {
+            let call = FunctionCall { arg_count, ..call("f") };
+            table.resolve("app", &call).iter().map(|id| id.as_str().to_string()).collect()
+        };
+
+        assert_eq!(ids(Some(1)), ["app::f::(p0: int) -> void", "app::f::(p0: int, p1: ...) -> void"]);
+        // Same arity, different types: all of them
+        assert_eq!(ids(Some(2)).len(), 3);
+        assert_eq!(ids(Some(5)), ["app::f::(p0: int, p1: ...) -> void"]);
+        // Unknown arity, or no overload taking it: every candidate
+        assert_eq!(ids(None).len(), 4);
+        assert_eq!(ids(Some(0)).len(), 4);
+    }
+
+    #[test]
+    fn test_overloads_are_narrowed_by_argument_types() {
+        let mut table = SymbolTable::new();
+        for ty in ["int", "double", "const std::string&"] {
+            let params = vec![("value".to_string(), ty.to_string())];
+            table.add_function(&FunctionDef::new("log".to_string(), Signature::new(params, "void".to_string()), "app".to_string()));
+        }
+        let ids = |arg_type: Option<&str>| -> Vec<String> {
+            let call = call("log").with_arg_count(1).with_arg_types(vec![arg_type.map(str::to_string)]);
+            table.resolve("app", &call).iter().map(|id| id.as_str().to_string()).collect()
+        };
+
+        assert_eq!(ids(Some("double")), ["app::log::(value: double) -> void"]);
+        assert_eq!(ids(Some("std::string")), ["app::log::(value: const std::string&) -> void"]);
+        // Unknown type, or one no overload takes: every overload of the arity
+        assert_eq!(ids(None).len(), 3);
+        assert_eq!(ids(Some("float")).len(), 3);
+    }
+}
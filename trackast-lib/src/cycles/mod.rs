@@ -1,9 +1,10 @@
-use std::collections::{HashSet, VecDeque};
+use std::collections::{BTreeSet, HashMap};
+use serde::Serialize;
 use crate::function_id::FunctionId;
 use crate::graph::CallGraph;
 
 /// Represents a cycle in the call graph
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct Cycle {
     pub nodes: Vec<FunctionId>,
 }
@@ -25,58 +26,228 @@ impl Cycle {
     }
 }
 
-/// Find all cycles in the call graph using BFS-based cycle detection
-#[must_use] 
-pub fn find_cycles(graph: &CallGraph) -> Vec<Cycle> {
-    let mut cycles = Vec::new();
-    let mut visited_global = HashSet::new();
+/// A strongly connected component of the call graph
+///
+/// Every function in a component can reach every other one. A component is
+/// recursive when it has more than one member or its single member calls itself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Component {
+    /// Member function IDs, sorted
+    pub nodes: Vec<FunctionId>,
+    recursive: bool,
+}
+
+impl Component {
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    #[must_use]
+    pub fn contains(&self, id: &FunctionId) -> bool {
+        self.nodes.binary_search(id).is_ok()
+    }
+
+    /// Whether the members of this component call each other (or themselves)
+    #[must_use]
+    pub fn is_recursive(&self) -> bool {
+        self.recursive
+    }
+}
+
+/// The call graph with each strongly connected component collapsed to a single node
+///
+/// The result is a DAG. Components are in topological order, so every edge
+/// `(from, to)` satisfies `from < to`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Condensation {
+    pub components: Vec<Component>,
+    /// Deduplicated edges between component indices, sorted
+    pub edges: Vec<(usize, usize)>,
+    membership: HashMap<FunctionId, usize>,
+}
 
-    for start_node in graph.nodes.keys() {
-        if visited_global.contains(start_node) {
+impl Condensation {
+    /// Index of the component containing a function
+    #[must_use]
+    pub fn component_of(&self, id: &FunctionId) -> Option<usize> {
+        self.membership.get(id).copied()
+    }
+
+    /// Components that are mutually recursive groups
+    pub fn recursive_components(&self) -> impl Iterator<Item = &Component> {
+        self.components.iter().filter(|c| c.is_recursive())
+    }
+}
+
+/// Per-node bookkeeping for Tarjan's algorithm
+struct TarjanState {
+    order: Vec<usize>,
+    lowlink: Vec<usize>,
+    on_stack: Vec<bool>,
+    stack: Vec<usize>,
+    next_order: usize,
+}
+
+const UNVISITED: usize = usize::MAX;
+
+impl TarjanState {
+    fn visit(&mut self, node: usize) {
+        self.order[node] = self.next_order;
+        self.lowlink[node] = self.next_order;
+        self.next_order += 1;
+        self.stack.push(node);
+        self.on_stack[node] = true;
+    }
+}
+
+/// Raw Tarjan over node indices, returning components in reverse topological order
+///
+/// Iterative so deep call chains cannot overflow the stack.
+fn tarjan(graph: &CallGraph) -> Vec<Vec<usize>> {
+    let n = graph.node_count();
+    let mut state = TarjanState {
+        order: vec![UNVISITED; n],
+        lowlink: vec![0; n],
+        on_stack: vec![false; n],
+        stack: Vec::new(),
+        next_order: 0,
+    };
+    let mut components = Vec::new();
+
+    for root in 0..n {
+        if state.order[root] != UNVISITED {
             continue;
         }
 
-        // Check for self-cycles
-        for edge in graph.get_edges_from(start_node) {
-            if edge.to == *start_node {
-                cycles.push(Cycle::new(vec![start_node.clone()]));
+        state.visit(root);
+        let mut frames: Vec<(usize, Vec<usize>, usize)> =
+            vec![(root, graph.successors(root).collect(), 0)];
+
+        while let Some((node, successors, next)) = frames.last_mut() {
+            let node = *node;
+            if let Some(&succ) = successors.get(*next) {
+                *next += 1;
+                if state.order[succ] == UNVISITED {
+                    state.visit(succ);
+                    frames.push((succ, graph.successors(succ).collect(), 0));
+                } else if state.on_stack[succ] {
+                    state.lowlink[node] = state.lowlink[node].min(state.order[succ]);
+                }
+                continue;
             }
-        }
 
-        // BFS to find paths from start_node back to itself (length > 1)
-        let mut queue = VecDeque::new();
-        let mut visited = HashSet::new();
-        
-        queue.push_back((start_node.clone(), vec![start_node.clone()]));
-        visited.insert(start_node.clone());
-
-        while let Some((current, path)) = queue.pop_front() {
-            for edge in graph.get_edges_from(&current) {
-                if edge.to == *start_node && path.len() > 1 {
-                    // Found a cycle back to start
-                    cycles.push(Cycle::new(path.clone()));
-                } else if !visited.contains(&edge.to) && path.len() < graph.nodes.len() {
-                    visited.insert(edge.to.clone());
-                    let mut new_path = path.clone();
-                    new_path.push(edge.to.clone());
-                    queue.push_back((edge.to.clone(), new_path));
+            frames.pop();
+            if let Some((parent, _, _)) = frames.last() {
+                state.lowlink[*parent] = state.lowlink[*parent].min(state.lowlink[node]);
+            }
+
+            if state.lowlink[node] == state.order[node] {
+                let mut component = Vec::new();
+                while let Some(member) = state.stack.pop() {
+                    state.on_stack[member] = false;
+                    component.push(member);
+                    if member == node {
+                        break;
+                    }
                 }
+                components.push(component);
             }
         }
+    }
 
-        visited_global.insert(start_node.clone());
+    components
+}
+
+fn is_recursive_group(graph: &CallGraph, members: &[usize]) -> bool {
+    match members {
+        [single] => graph.successors(*single).any(|s| s == *single),
+        _ => members.len() > 1,
     }
+}
+
+fn build_component(graph: &CallGraph, members: &[usize]) -> Component {
+    let recursive = is_recursive_group(graph, members);
+    let mut nodes: Vec<FunctionId> = members
+        .iter()
+        .map(|&m| graph.node_at(m).id.clone())
+        .collect();
+    nodes.sort();
+    Component { nodes, recursive }
+}
 
-    // Remove duplicate cycles
-    cycles.sort_by_key(|c| c.nodes.clone());
-    cycles.dedup();
+/// Compute the strongly connected components of the call graph (Tarjan)
+///
+/// Runs in O(V + E). Every node belongs to exactly one component; components
+/// are returned in topological order, callers before callees.
+#[must_use]
+pub fn strongly_connected_components(graph: &CallGraph) -> Vec<Component> {
+    condensation(graph).components
+}
+
+/// Collapse each strongly connected component into a single node
+#[must_use]
+pub fn condensation(graph: &CallGraph) -> Condensation {
+    let mut raw = tarjan(graph);
+    raw.reverse();
+
+    let mut component_of = vec![0; graph.node_count()];
+    for (c, members) in raw.iter().enumerate() {
+        for &m in members {
+            component_of[m] = c;
+        }
+    }
+
+    let mut edges = BTreeSet::new();
+    for (node, &from) in component_of.iter().enumerate() {
+        for succ in graph.successors(node) {
+            let to = component_of[succ];
+            if from != to {
+                edges.insert((from, to));
+            }
+        }
+    }
+
+    let membership = graph
+        .node_ids()
+        .zip(component_of.iter())
+        .map(|(id, &c)| (id.clone(), c))
+        .collect();
+    let components = raw.iter().map(|members| build_component(graph, members)).collect();
+
+    Condensation {
+        components,
+        edges: edges.into_iter().collect(),
+        membership,
+    }
+}
+
+/// Find the recursive groups in the call graph
+///
+/// Each mutually-recursive cluster (or self-recursive function) is reported
+/// exactly once, with its members sorted by ID.
+#[must_use] 
+pub fn find_cycles(graph: &CallGraph) -> Vec<Cycle> {
+    let mut cycles: Vec<Cycle> = strongly_connected_components(graph)
+        .into_iter()
+        .filter(Component::is_recursive)
+        .map(|c| Cycle::new(c.nodes))
+        .collect();
+    cycles.sort_by(|a, b| a.nodes.cmp(&b.nodes));
     cycles
 }
 
 /// Check if the graph has any cycles
 #[must_use] 
 pub fn has_cycles(graph: &CallGraph) -> bool {
-    !find_cycles(graph).is_empty()
+    tarjan(graph)
+        .iter()
+        .any(|members| is_recursive_group(graph, members))
 }
 
 #[cfg(test)]
@@ -84,6 +255,7 @@ mod tests {
     use super::*;
     use crate::ast::{FunctionDef, Signature};
     use crate::graph::{GraphNode, GraphEdge};
+    use crate::graph::test_support::{graph, id};
 
     #[test]
     fn test_cycle_creation() {
@@ -228,4 +400,108 @@ mod tests {
 
         assert!(has_cycles(&graph));
     }
+
+    #[test]
+    fn test_overlapping_cycles_reported_once() {
+        // a <-> b and b <-> c share b, so they form a single recursive group
+        let graph = graph(
+            &["a", "b", "c"],
+            &[("a", "b", 1), ("b", "a", 2), ("b", "c", 3), ("c", "b", 4)],
+        );
+
+        let cycles = find_cycles(&graph);
+        assert_eq!(cycles, vec![Cycle::new(vec![id("a"), id("b"), id("c")])]);
+    }
+
+    #[test]
+    fn test_separate_clusters() {
+        let graph = graph(
+            &["main", "a", "b", "c", "d", "leaf"],
+            &[
+                ("main", "a", 1),
+                ("a", "b", 2),
+                ("b", "a", 3),
+                ("main", "c", 4),
+                ("c", "d", 5),
+                ("d", "c", 6),
+                ("d", "leaf", 7),
+                ("leaf", "leaf", 8),
+            ],
+        );
+
+        let cycles = find_cycles(&graph);
+        assert_eq!(cycles.len(), 3);
+        assert_eq!(cycles[0].nodes, vec![id("a"), id("b")]);
+        assert_eq!(cycles[1].nodes, vec![id("c"), id("d")]);
+        assert_eq!(cycles[2].nodes, vec![id("leaf")]);
+    }
+
+    #[test]
+    fn test_every_node_in_one_component() {
+        let graph = graph(
+            &["main", "a", "b", "c"],
+            &[("main", "a", 1), ("a", "b", 2), ("b", "a", 3), ("b", "c", 4)],
+        );
+
+        let components = strongly_connected_components(&graph);
+        assert_eq!(components.len(), 3);
+        let total: usize = components.iter().map(Component::len).sum();
+        assert_eq!(total, graph.node_count());
+
+        let recursive: Vec<_> = components.iter().filter(|c| c.is_recursive()).collect();
+        assert_eq!(recursive.len(), 1);
+        assert!(recursive[0].contains(&id("a")));
+        assert!(recursive[0].contains(&id("b")));
+    }
+
+    #[test]
+    fn test_single_node_without_self_call_is_not_recursive() {
+        let graph = graph(&["a"], &[]);
+        let components = strongly_connected_components(&graph);
+        assert_eq!(components.len(), 1);
+        assert!(!components[0].is_recursive());
+        assert!(!has_cycles(&graph));
+    }
+
+    #[test]
+    fn test_condensation_is_topological_dag() {
+        let graph = graph(
+            &["leaf", "b", "a", "main"],
+            &[("main", "a", 1), ("a", "b", 2), ("b", "a", 3), ("b", "leaf", 4), ("a", "leaf", 5)],
+        );
+
+        let dag = condensation(&graph);
+        assert_eq!(dag.components.len(), 3);
+
+        let main = dag.component_of(&id("main")).unwrap();
+        let group = dag.component_of(&id("a")).unwrap();
+        let leaf = dag.component_of(&id("leaf")).unwrap();
+        assert_eq!(dag.component_of(&id("b")), Some(group));
+        assert!(main < group && group < leaf);
+
+        // The two a/b -> leaf edges collapse into one
+        assert_eq!(dag.edges, vec![(main, group), (group, leaf)]);
+        assert!(dag.edges.iter().all(|(from, to)| from < to));
+        assert_eq!(dag.recursive_components().count(), 1);
+    }
+
+    #[test]
+    fn test_deep_chain_does_not_overflow() {
+        let size = 100_000;
+        let mut graph = CallGraph::new();
+        let ids: Vec<FunctionId> = (0..size).map(|i| id(&format!("f{i}"))).collect();
+        for (i, node_id) in ids.iter().enumerate() {
+            let func = FunctionDef::new(format!("f{i}"), Signature::empty(), "root".to_string());
+            graph.insert_node(GraphNode::internal(node_id.clone(), func)).unwrap();
+        }
+        for pair in ids.windows(2) {
+            graph.insert_edge(GraphEdge::new(pair[0].clone(), pair[1].clone(), 1)).unwrap();
+        }
+        // Close the chain into one big loop
+        graph.insert_edge(GraphEdge::new(ids[size - 1].clone(), ids[0].clone(), 1)).unwrap();
+
+        let cycles = find_cycles(&graph);
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(cycles[0].len(), size);
+    }
 }
@@ -0,0 +1,184 @@
+use std::collections::{BTreeSet, HashSet};
+use std::fmt::Write as _;
+use serde::Serialize;
+use crate::cycles::{find_cycles, Cycle};
+use crate::function_id::FunctionId;
+use crate::graph::{CallGraph, GraphEdge};
+
+/// What changed between two versions of a call graph
+///
+/// Calls are compared by caller and callee only, since line numbers shift
+/// with any edit; each added or removed call is represented by its first
+/// call site in the graph it belongs to. External functions are not listed
+/// as added or removed functions but as external dependencies.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GraphDiff {
+    /// Internal functions only in the new graph, sorted
+    pub added_functions: Vec<FunctionId>,
+    /// Internal functions only in the old graph, sorted
+    pub removed_functions: Vec<FunctionId>,
+    /// Calls only in the new graph, sorted by caller and callee
+    pub added_calls: Vec<GraphEdge>,
+    /// Calls only in the old graph, sorted by caller and callee
+    pub removed_calls: Vec<GraphEdge>,
+    /// Recursive groups of the new graph with no identical group in the old one
+    pub new_cycles: Vec<Cycle>,
+    /// External functions the new graph calls and the old one did not, sorted
+    pub new_external_dependencies: Vec<FunctionId>,
+}
+
+impl GraphDiff {
+    /// Whether the graphs have the same functions, calls and cycles
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added_functions.is_empty()
+            && self.removed_functions.is_empty()
+            && self.added_calls.is_empty()
+            && self.removed_calls.is_empty()
+            && self.new_cycles.is_empty()
+            && self.new_external_dependencies.is_empty()
+    }
+
+    /// Render the diff as Markdown, e.g. for a pull request comment
+    ///
+    /// A summary table is followed by one collapsible section per non-empty
+    /// list.
+    #[must_use]
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::from("## Call graph changes\n\n");
+        if self.is_empty() {
+            out.push_str("No changes to functions, calls, cycles or external dependencies.\n");
+            return out;
+        }
+
+        out.push_str("| | Added | Removed |\n|---|---:|---:|\n");
+        let _ = writeln!(out, "| Functions | {} | {} |", self.added_functions.len(), self.removed_functions.len());
+        let _ = writeln!(out, "| Calls | {} | {} |", self.added_calls.len(), self.removed_calls.len());
+        let _ = writeln!(out, "| Recursive groups | {} | |", self.new_cycles.len());
+        let _ = writeln!(out, "| External dependencies | {} | |", self.new_external_dependencies.len());
+
+        let function_items = |ids: &[FunctionId]| ids.iter().map(|id| format!("`{id}`")).collect::<Vec<_>>();
+        let call_items = |edges: &[GraphEdge]| {
+            edges
+                .iter()
+                .map(|edge| {
+                    let site = match &edge.file {
+                        Some(file) => format!("{file}:{}", edge.line),
+                        None => format!("line {}", edge.line),
+                    };
+                    format!("`{}` → `{}` ({site})", edge.from, edge.to)
+                })
+                .collect::<Vec<_>>()
+        };
+        let cycle_items = self
+            .new_cycles
+            .iter()
+            .map(|cycle| cycle.nodes.iter().map(|id| format!("`{id}`")).collect::<Vec<_>>().join(", "))
+            .collect::<Vec<_>>();
+
+        for (title, items) in [
+            ("Added functions", function_items(&self.added_functions)),
+            ("Removed functions", function_items(&self.removed_functions)),
+            ("Added calls", call_items(&self.added_calls)),
+            ("Removed calls", call_items(&self.removed_calls)),
+            ("New recursive groups", cycle_items),
+            ("New external dependencies", function_items(&self.new_external_dependencies)),
+        ] {
+            if items.is_empty() {
+                continue;
+            }
+            let _ = write!(out, "\n<details>\n<summary>{title} ({})</summary>\n\n", items.len());
+            for item in items {
+                let _ = writeln!(out, "- {item}");
+            }
+            out.push_str("\n</details>\n");
+        }
+        out
+    }
+}
+
+/// IDs of the nodes of `graph` that are (or are not) external
+fn function_ids(graph: &CallGraph, external: bool) -> BTreeSet<&FunctionId> {
+    graph.nodes.values().filter(|node| node.is_external == external).map(|node| &node.id).collect()
+}
+
+/// The first call site of each (caller, callee) pair of `graph` that
+/// `other` lacks, sorted by caller and callee
+fn calls_missing_from(graph: &CallGraph, other: &CallGraph) -> Vec<GraphEdge> {
+    let other_pairs: HashSet<(&FunctionId, &FunctionId)> = other.edges.iter().map(|edge| (&edge.from, &edge.to)).collect();
+    let mut seen = HashSet::new();
+    let mut calls: Vec<GraphEdge> = graph
+        .edges
+        .iter()
+        .filter(|edge| !other_pairs.contains(&(&edge.from, &edge.to)) && seen.insert((&edge.from, &edge.to)))
+        .cloned()
+        .collect();
+    calls.sort_by(|a, b| (&a.from, &a.to).cmp(&(&b.from, &b.to)));
+    calls
+}
+
+/// Compare two versions of a call graph
+#[must_use]
+pub fn diff(old: &CallGraph, new: &CallGraph) -> GraphDiff {
+    let old_functions = function_ids(old, false);
+    let new_functions = function_ids(new, false);
+    let old_externals = function_ids(old, true);
+
+    let old_cycles: HashSet<Vec<FunctionId>> = find_cycles(old).into_iter().map(|cycle| cycle.nodes).collect();
+    let new_cycles = find_cycles(new)
+        .into_iter()
+        .filter(|cycle| !old_cycles.contains(&cycle.nodes))
+        .collect();
+
+    GraphDiff {
+        added_functions: new_functions.difference(&old_functions).map(|&id| id.clone()).collect(),
+        removed_functions: old_functions.difference(&new_functions).map(|&id| id.clone()).collect(),
+        added_calls: calls_missing_from(new, old),
+        removed_calls: calls_missing_from(old, new),
+        new_cycles,
+        new_external_dependencies: function_ids(new, true)
+            .difference(&old_externals)
+            .map(|&id| id.clone())
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::test_support::{graph_with_externals, id};
+
+    #[test]
+    fn test_diff_reports_changes() {
+        let old = graph_with_externals(&["main", "parse", "legacy"], &["print"], &[("main", "parse", 2), ("main", "legacy", 3), ("parse", "print", 8)]);
+        let new = graph_with_externals(
+            &["main", "parse", "retry"],
+            &["print", "sleep"],
+            &[("main", "parse", 4), ("parse", "print", 9), ("parse", "retry", 10), ("parse", "retry", 11), ("retry", "parse", 14), ("retry", "sleep", 15)],
+        );
+
+        let diff = diff(&old, &new);
+        assert_eq!(diff.added_functions, [id("retry")]);
+        assert_eq!(diff.removed_functions, [id("legacy")]);
+        // Moved call sites are not changes; repeated ones are reported once
+        let added: Vec<(&FunctionId, usize)> = diff.added_calls.iter().map(|edge| (&edge.to, edge.line)).collect();
+        assert_eq!(added, [(&id("retry"), 10), (&id("parse"), 14), (&id("sleep"), 15)]);
+        assert_eq!(diff.removed_calls.len(), 1);
+        assert_eq!(diff.new_cycles, [Cycle::new(vec![id("parse"), id("retry")])]);
+        assert_eq!(diff.new_external_dependencies, [id("sleep")]);
+
+        let markdown = diff.to_markdown();
+        assert!(markdown.contains("| Functions | 1 | 1 |"));
+        assert!(markdown.contains("<summary>New recursive groups (1)</summary>"));
+        assert!(markdown.contains("- `parse::()` → `retry::()` (line 10)"));
+    }
+
+    #[test]
+    fn test_diff_of_identical_graphs_is_empty() {
+        let old = graph_with_externals(&["a", "b"], &[], &[("a", "b", 1), ("b", "a", 2)]);
+        let new = graph_with_externals(&["a", "b"], &[], &[("a", "b", 5), ("b", "a", 6)]);
+        let diff = diff(&old, &new);
+        assert!(diff.is_empty());
+        assert!(diff.to_markdown().contains("No changes"));
+    }
+}
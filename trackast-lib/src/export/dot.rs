@@ -10,33 +10,48 @@ pub fn to_dot(graph: &CallGraph) -> String {
     output.push_str("    node [shape=box];\n\n");
 
     // Add nodes
-    for (id, node) in &graph.nodes {
+    for node in graph.nodes.values() {
+        let id = &node.id;
         let style = if node.is_external {
             ", style=filled, fillcolor=lightgray"
         } else {
             ", style=filled, fillcolor=lightblue"
         };
         
-        // Format label: replace :: with newline for readability
-        let label = id.as_str().replace("::", "\n");
+        // Format label: replace :: with newline for readability, and name
+        // the language so mixed-language graphs can be told apart
+        let mut label = id.as_str().replace("::", "\n");
+        if let Some(language) = &node.metadata.language {
+            let _ = write!(label, "\n[{language}]");
+        }
+        let tooltip = node
+            .metadata
+            .location()
+            .map(|location| format!(", tooltip=\"{}\"", escape(&location)))
+            .unwrap_or_default();
         let _ = writeln!(
             output,
-            "    \"{}\" [label=\"{}\"{}];",
+            "    \"{}\" [label=\"{}\"{}{}];",
             id.as_str(),
             label,
-            style
+            style,
+            tooltip
         );
     }
 
     output.push('\n');
 
-    // Add edges with line number labels
+    // Add edges with line number labels and a file:line:column tooltip
     for edge in &graph.edges {
-        let label = if edge.line > 0 {
-            format!(", label=\"L{}\"", edge.line)
-        } else {
-            String::new()
-        };
+        let mut label = String::new();
+        if edge.line > 0 {
+            let _ = write!(label, ", label=\"L{}\"", edge.line);
+            let location = match &edge.file {
+                Some(file) => format!("{}:{}:{}", file, edge.line, edge.column),
+                None => format!("{}:{}", edge.line, edge.column),
+            };
+            let _ = write!(label, ", tooltip=\"{}\"", escape(&location));
+        }
         
         let _ = writeln!(
             output,
@@ -51,6 +66,11 @@ pub fn to_dot(graph: &CallGraph) -> String {
     output
 }
 
+/// Escape a value for use inside a double-quoted DOT attribute
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
 /// Write DOT format to a file
 ///
 /// # Errors
@@ -63,7 +83,7 @@ pub fn to_dot_file(graph: &CallGraph, path: &str) -> std::io::Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::ast::{FunctionDef, Signature};
+    use crate::ast::{FunctionDef, Signature, SourceSpan};
     use crate::function_id::FunctionId;
     use crate::graph::{GraphNode, GraphEdge};
 
@@ -119,6 +139,49 @@ mod tests {
         assert!(dot.contains("L5"));
     }
 
+    #[test]
+    fn test_to_dot_node_tooltip() {
+        let mut graph = CallGraph::new();
+        let mut func = FunctionDef::new("main".to_string(), Signature::empty(), "root".to_string())
+            .with_span(SourceSpan::new(4, 12, 30, 210));
+        func.file = Some("src/main.rs".to_string());
+        graph.insert_node(GraphNode::internal(func.fn_id(), func)).unwrap();
+
+        let dot = to_dot(&graph);
+        assert!(dot.contains("tooltip=\"src/main.rs:4-12\""));
+    }
+
+    #[test]
+    fn test_to_dot_node_language() {
+        let mut graph = CallGraph::new();
+        let func = FunctionDef::new("add".to_string(), Signature::empty(), "fastmath".to_string()).with_language("Rust");
+        graph.insert_node(GraphNode::internal(func.fn_id(), func)).unwrap();
+
+        let dot = to_dot(&graph);
+        assert!(dot.contains("label=\"fastmath\nadd\n() -> ()\n[Rust]\""));
+    }
+
+    #[test]
+    fn test_to_dot_edge_tooltip() {
+        let mut graph = CallGraph::new();
+
+        let id1 = FunctionId::new("a::()".to_string());
+        let id2 = FunctionId::new("b::()".to_string());
+
+        let func1 = FunctionDef::new("a".to_string(), Signature::empty(), "root".to_string());
+        let func2 = FunctionDef::new("b".to_string(), Signature::empty(), "root".to_string());
+
+        graph.insert_node(GraphNode::internal(id1.clone(), func1)).unwrap();
+        graph.insert_node(GraphNode::internal(id2.clone(), func2)).unwrap();
+        let edge = GraphEdge::new(id1, id2, 5)
+            .with_column(9)
+            .with_file("src/main.rs".to_string());
+        graph.insert_edge(edge).unwrap();
+
+        let dot = to_dot(&graph);
+        assert!(dot.contains("tooltip=\"src/main.rs:5:9\""));
+    }
+
     #[test]
     fn test_to_dot_newline_formatting() {
         let mut graph = CallGraph::new();
@@ -0,0 +1,193 @@
+use serde::{Deserialize, Serialize};
+use crate::graph::{CallGraph, GraphEdge, GraphNode};
+
+/// Version of the JSON graph schema written by [`to_json`]
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// On-disk representation of a call graph
+///
+/// Nodes are sorted by ID and edges by (from, to, line, column) so the output is
+/// stable across runs and diffs cleanly.
+#[derive(Debug, Serialize, Deserialize)]
+struct GraphDocument {
+    version: u32,
+    nodes: Vec<GraphNode>,
+    edges: Vec<GraphEdge>,
+}
+
+/// Generate the versioned JSON representation of the call graph
+#[must_use]
+pub fn to_json(graph: &CallGraph) -> String {
+    let mut nodes: Vec<GraphNode> = graph.nodes.values().cloned().collect();
+    nodes.sort_by(|a, b| a.id.cmp(&b.id));
+
+    let mut edges = graph.edges.to_vec();
+    edges.sort_by(|a, b| (&a.from, &a.to, a.line, a.column).cmp(&(&b.from, &b.to, b.line, b.column)));
+
+    let document = GraphDocument {
+        version: SCHEMA_VERSION,
+        nodes,
+        edges,
+    };
+    // Serializing plain structs of strings and integers cannot fail
+    serde_json::to_string_pretty(&document).unwrap_or_default()
+}
+
+/// Rebuild a call graph from its JSON representation
+///
+/// # Errors
+///
+/// Returns an error if the JSON is malformed, the schema version is not
+/// supported, or an edge references a node missing from the document.
+pub fn from_json(json: &str) -> Result<CallGraph, String> {
+    let document: GraphDocument = serde_json::from_str(json)
+        .map_err(|e| format!("Invalid graph JSON: {e}"))?;
+
+    if document.version != SCHEMA_VERSION {
+        return Err(format!(
+            "Unsupported graph schema version {} (expected {SCHEMA_VERSION})",
+            document.version
+        ));
+    }
+
+    let mut graph = CallGraph::new();
+    for node in document.nodes {
+        graph.insert_node(node)?;
+    }
+    for edge in document.edges {
+        graph.insert_edge(edge)?;
+    }
+    Ok(graph)
+}
+
+/// Write the JSON representation to a file
+///
+/// # Errors
+///
+/// Returns an I/O error if writing to the file fails.
+pub fn to_json_file(graph: &CallGraph, path: &str) -> std::io::Result<()> {
+    std::fs::write(path, to_json(graph))
+}
+
+/// Read a call graph from a JSON file
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or does not contain a valid graph.
+pub fn from_json_file(path: &str) -> Result<CallGraph, String> {
+    let json = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read graph file: {e}"))?;
+    from_json(&json)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::{FunctionCall, FunctionDef, Signature};
+    use crate::function_id::FunctionId;
+
+    fn create_test_graph() -> CallGraph {
+        let mut graph = CallGraph::new();
+
+        let sig = Signature::new(
+            vec![("x".to_string(), "i32".to_string())],
+            "String".to_string(),
+        );
+        let mut main_func = FunctionDef::new("main".to_string(), Signature::empty(), "root".to_string());
+        main_func.add_call(FunctionCall::new("helper".to_string(), Some("root".to_string()), 3));
+        main_func.add_call(FunctionCall::new("println".to_string(), None, 4));
+        let helper_func = FunctionDef::new("helper".to_string(), sig, "root".to_string());
+        let ext_func = FunctionDef::new("println".to_string(), Signature::empty(), "<external>".to_string());
+
+        let main_id = main_func.fn_id();
+        let helper_id = helper_func.fn_id();
+        let ext_id = FunctionId::new("<external>::println::()".to_string());
+
+        graph.insert_node(GraphNode::internal(main_id.clone(), main_func)).unwrap();
+        graph.insert_node(GraphNode::internal(helper_id.clone(), helper_func)).unwrap();
+        graph.insert_node(GraphNode::external(ext_id.clone(), ext_func)).unwrap();
+        graph.insert_edge(GraphEdge::new(main_id.clone(), helper_id, 3)).unwrap();
+        graph.insert_edge(GraphEdge::new(main_id, ext_id, 4)).unwrap();
+
+        graph
+    }
+
+    #[test]
+    fn test_to_json_contains_version() {
+        let json = to_json(&CallGraph::new());
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["version"], SCHEMA_VERSION);
+        assert_eq!(value["nodes"].as_array().unwrap().len(), 0);
+        assert_eq!(value["edges"].as_array().unwrap().len(), 0);
+    }
+
+    #[test]
+    fn test_to_json_includes_metadata() {
+        let json = to_json(&create_test_graph());
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let nodes = value["nodes"].as_array().unwrap();
+        assert_eq!(nodes.len(), 3);
+
+        let helper = nodes
+            .iter()
+            .find(|n| n["metadata"]["name"] == "helper")
+            .unwrap();
+        assert_eq!(helper["id"], "root::helper::(x: i32) -> String");
+        assert_eq!(helper["is_external"], false);
+        assert_eq!(helper["metadata"]["signature"]["return_type"], "String");
+
+        let external = nodes.iter().find(|n| n["is_external"] == true).unwrap();
+        assert_eq!(external["id"], "<external>::println::()");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let graph = create_test_graph();
+        let restored = from_json(&to_json(&graph)).unwrap();
+
+        assert_eq!(restored.node_count(), graph.node_count());
+        assert_eq!(restored.edge_count(), graph.edge_count());
+        for node in graph.nodes.values() {
+            assert_eq!(restored.get_node(&node.id), Some(node));
+        }
+        for edge in &graph.edges {
+            assert!(restored.edges.contains(edge));
+        }
+    }
+
+    #[test]
+    fn test_output_is_deterministic() {
+        let graph = create_test_graph();
+        assert_eq!(to_json(&graph), to_json(&graph.clone()));
+    }
+
+    #[test]
+    fn test_from_json_rejects_unknown_version() {
+        let json = r#"{"version": 999, "nodes": [], "edges": []}"#;
+        let err = from_json(json).unwrap_err();
+        assert!(err.contains("Unsupported graph schema version"));
+    }
+
+    #[test]
+    fn test_from_json_rejects_dangling_edge() {
+        let json = r#"{"version": 1, "nodes": [], "edges": [{"from": "a", "to": "b", "line": 1}]}"#;
+        assert!(from_json(json).is_err());
+    }
+
+    #[test]
+    fn test_from_json_rejects_malformed() {
+        assert!(from_json("not json").is_err());
+    }
+
+    #[test]
+    fn test_json_file_round_trip() {
+        let graph = create_test_graph();
+        let dir = tempfile::tempdir().unwrap();
+        let temp_file = dir.path().join("callgraph.json");
+        let temp_file = temp_file.to_str().unwrap();
+
+        to_json_file(&graph, temp_file).unwrap();
+        let restored = from_json_file(temp_file).unwrap();
+        assert_eq!(restored.node_count(), graph.node_count());
+    }
+}
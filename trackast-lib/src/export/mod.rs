@@ -1,3 +1,5 @@
 pub mod dot;
+pub mod json;
 
 pub use dot::to_dot;
+pub use json::{to_json, from_json};
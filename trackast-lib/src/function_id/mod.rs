@@ -1,9 +1,11 @@
 use std::fmt;
 use std::hash::{Hash, Hasher};
+use serde::{Deserialize, Serialize};
 use crate::ast::Signature;
 
 /// Unique identifier for a function: `module::name::signature`
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
 pub struct FunctionId(String);
 
 impl FunctionId {
@@ -1,9 +1,13 @@
 use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
 use crate::function_id::FunctionId;
 use crate::ast::FunctionDef;
 
+#[cfg(test)]
+pub(crate) mod test_support;
+
 /// Node in the call graph representing a function
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct GraphNode {
     pub id: FunctionId,
     pub is_external: bool,
@@ -40,33 +44,149 @@ impl GraphNode {
 }
 
 /// Edge in the call graph representing a function call
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct GraphEdge {
     pub from: FunctionId,
     pub to: FunctionId,
     pub line: usize,
+    #[serde(default)]
+    pub column: usize,
+    /// Source file containing the call site
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
 }
 
 impl GraphEdge {
     #[must_use] 
     pub fn new(from: FunctionId, to: FunctionId, line: usize) -> Self {
-        GraphEdge { from, to, line }
+        GraphEdge {
+            from,
+            to,
+            line,
+            column: 0,
+            file: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_column(mut self, column: usize) -> Self {
+        self.column = column;
+        self
+    }
+
+    #[must_use]
+    pub fn with_file(mut self, file: String) -> Self {
+        self.file = Some(file);
+        self
+    }
+}
+
+/// Read-only map of a graph's nodes by ID, in insertion order
+///
+/// Exposed as [`CallGraph::nodes`] with the read API of the `HashMap` that
+/// field used to be. A node's position in insertion order is its index.
+#[derive(Debug, Clone, Default)]
+pub struct NodeMap {
+    nodes: Vec<GraphNode>,
+    index: HashMap<FunctionId, usize>,
+}
+
+impl NodeMap {
+    #[must_use]
+    pub fn get(&self, id: &FunctionId) -> Option<&GraphNode> {
+        self.index.get(id).map(|&i| &self.nodes[i])
+    }
+
+    #[must_use]
+    pub fn contains_key(&self, id: &FunctionId) -> bool {
+        self.index.contains_key(id)
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &FunctionId> {
+        self.nodes.iter().map(|n| &n.id)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &GraphNode> {
+        self.nodes.iter()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&FunctionId, &GraphNode)> {
+        self.nodes.iter().map(|n| (&n.id, n))
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+}
+
+impl<'a> IntoIterator for &'a NodeMap {
+    type Item = (&'a FunctionId, &'a GraphNode);
+    type IntoIter = std::iter::Map<std::slice::Iter<'a, GraphNode>, fn(&'a GraphNode) -> (&'a FunctionId, &'a GraphNode)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.nodes.iter().map(|n| (&n.id, n))
+    }
+}
+
+/// Read-only list of a graph's edges, in insertion order
+///
+/// Exposed as [`CallGraph::edges`]; derefs to a slice, like the `Vec` that
+/// field used to be.
+#[derive(Debug, Clone, Default)]
+pub struct EdgeList(Vec<GraphEdge>);
+
+impl std::ops::Deref for EdgeList {
+    type Target = [GraphEdge];
+
+    fn deref(&self) -> &[GraphEdge] {
+        &self.0
+    }
+}
+
+impl<'a> IntoIterator for &'a EdgeList {
+    type Item = &'a GraphEdge;
+    type IntoIter = std::slice::Iter<'a, GraphEdge>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
     }
 }
 
 /// Call dependency graph
+///
+/// Nodes are interned into dense indices on insertion and every node keeps
+/// forward and reverse adjacency lists of edge indices, so neighbour queries
+/// cost O(degree) rather than a scan over all edges. `nodes` and `edges` are
+/// public for reading; the graph only changes through
+/// [`CallGraph::insert_node`] and [`CallGraph::insert_edge`], which keep the
+/// adjacency lists in step.
 #[derive(Debug, Clone)]
 pub struct CallGraph {
-    pub nodes: HashMap<FunctionId, GraphNode>,
-    pub edges: Vec<GraphEdge>,
+    pub nodes: NodeMap,
+    pub edges: EdgeList,
+    /// (from, to) node indices for each edge, parallel to `edges`
+    endpoints: Vec<(usize, usize)>,
+    /// Edge indices leaving each node
+    outgoing: Vec<Vec<usize>>,
+    /// Edge indices arriving at each node
+    incoming: Vec<Vec<usize>>,
 }
 
 impl CallGraph {
     #[must_use] 
     pub fn new() -> Self {
         CallGraph {
-            nodes: HashMap::new(),
-            edges: vec![],
+            nodes: NodeMap::default(),
+            edges: EdgeList::default(),
+            endpoints: vec![],
+            outgoing: vec![],
+            incoming: vec![],
         }
     }
 
@@ -79,7 +199,10 @@ impl CallGraph {
         if self.nodes.contains_key(&node.id) {
             return Err(format!("Node already exists: {}", node.id));
         }
-        self.nodes.insert(node.id.clone(), node);
+        self.nodes.index.insert(node.id.clone(), self.nodes.len());
+        self.nodes.nodes.push(node);
+        self.outgoing.push(vec![]);
+        self.incoming.push(vec![]);
         Ok(())
     }
 
@@ -89,13 +212,17 @@ impl CallGraph {
     ///
     /// Returns an error if the from or to node does not exist.
     pub fn insert_edge(&mut self, edge: GraphEdge) -> Result<(), String> {
-        if !self.nodes.contains_key(&edge.from) {
+        let Some(from) = self.node_index(&edge.from) else {
             return Err(format!("From node does not exist: {}", edge.from));
-        }
-        if !self.nodes.contains_key(&edge.to) {
+        };
+        let Some(to) = self.node_index(&edge.to) else {
             return Err(format!("To node does not exist: {}", edge.to));
-        }
-        self.edges.push(edge);
+        };
+        let edge_index = self.edges.len();
+        self.edges.0.push(edge);
+        self.endpoints.push((from, to));
+        self.outgoing[from].push(edge_index);
+        self.incoming[to].push(edge_index);
         Ok(())
     }
 
@@ -105,16 +232,73 @@ impl CallGraph {
         self.nodes.get(id)
     }
 
-    /// Get all edges originating from a node
+    /// Check whether a node with the given ID exists
+    #[must_use] 
+    pub fn contains_node(&self, id: &FunctionId) -> bool {
+        self.nodes.contains_key(id)
+    }
+
+    /// The node at an interned index
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not below [`CallGraph::node_count`].
+    #[must_use]
+    pub fn node_at(&self, index: usize) -> &GraphNode {
+        &self.nodes.nodes[index]
+    }
+
+    /// All node IDs, in insertion order
+    pub fn node_ids(&self) -> impl Iterator<Item = &FunctionId> {
+        self.nodes.keys()
+    }
+
+    /// Interned index of a node
     #[must_use] 
+    pub fn node_index(&self, id: &FunctionId) -> Option<usize> {
+        self.nodes.index.get(id).copied()
+    }
+
+    /// Indices of the nodes called by the node at `index`
+    ///
+    /// A target appears once per call site, so it may be repeated.
+    pub fn successors(&self, index: usize) -> impl Iterator<Item = usize> + '_ {
+        self.outgoing[index].iter().map(|&e| self.endpoints[e].1)
+    }
+
+    /// Indices of the nodes calling the node at `index`
+    ///
+    /// A caller appears once per call site, so it may be repeated.
+    pub fn predecessors(&self, index: usize) -> impl Iterator<Item = usize> + '_ {
+        self.incoming[index].iter().map(|&e| self.endpoints[e].0)
+    }
+
+    /// Edges leaving the node at `index`, in insertion order, each with the
+    /// index of the node it calls
+    pub fn out_edges(&self, index: usize) -> impl Iterator<Item = (usize, &GraphEdge)> + '_ {
+        self.outgoing[index].iter().map(|&e| (self.endpoints[e].1, &self.edges[e]))
+    }
+
+    /// Edges arriving at the node at `index`, in insertion order, each with
+    /// the index of the calling node
+    pub fn in_edges(&self, index: usize) -> impl Iterator<Item = (usize, &GraphEdge)> + '_ {
+        self.incoming[index].iter().map(|&e| (self.endpoints[e].0, &self.edges[e]))
+    }
+
+    /// Get all edges originating from a node
+    #[must_use]
     pub fn get_edges_from(&self, id: &FunctionId) -> Vec<&GraphEdge> {
-        self.edges.iter().filter(|e| e.from == *id).collect()
+        self.node_index(id)
+            .map(|i| self.outgoing[i].iter().map(|&e| &self.edges[e]).collect())
+            .unwrap_or_default()
     }
 
     /// Get all edges pointing to a node
     #[must_use] 
     pub fn get_edges_to(&self, id: &FunctionId) -> Vec<&GraphEdge> {
-        self.edges.iter().filter(|e| e.to == *id).collect()
+        self.node_index(id)
+            .map(|i| self.incoming[i].iter().map(|&e| &self.edges[e]).collect())
+            .unwrap_or_default()
     }
 
     #[must_use] 
@@ -126,6 +310,22 @@ impl CallGraph {
     pub fn edge_count(&self) -> usize {
         self.edges.len()
     }
+
+    /// Serialize the graph to the versioned JSON schema
+    #[must_use] 
+    pub fn to_json(&self) -> String {
+        crate::export::json::to_json(self)
+    }
+
+    /// Load a graph previously written by [`CallGraph::to_json`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON is malformed, uses an unsupported schema
+    /// version, or describes edges between unknown nodes.
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        crate::export::json::from_json(json)
+    }
 }
 
 impl Default for CallGraph {
@@ -245,4 +445,52 @@ mod tests {
         let edges = graph.get_edges_from(&id1);
         assert_eq!(edges.len(), 2);
     }
+
+    #[test]
+    fn test_get_edges_to() {
+        let mut graph = CallGraph::new();
+        let (id1, node1) = create_test_node("a::()");
+        let (id2, node2) = create_test_node("b::()");
+        let (id3, node3) = create_test_node("c::()");
+
+        graph.insert_node(node1).unwrap();
+        graph.insert_node(node2).unwrap();
+        graph.insert_node(node3).unwrap();
+
+        graph.insert_edge(GraphEdge::new(id1.clone(), id3.clone(), 5)).unwrap();
+        graph.insert_edge(GraphEdge::new(id2.clone(), id3.clone(), 10)).unwrap();
+
+        let edges = graph.get_edges_to(&id3);
+        assert_eq!(edges.len(), 2);
+        assert!(graph.get_edges_to(&id1).is_empty());
+        assert!(graph.get_edges_from(&FunctionId::new("missing".to_string())).is_empty());
+    }
+
+    #[test]
+    fn test_adjacency_indices() {
+        let mut graph = CallGraph::new();
+        let (id1, node1) = create_test_node("a::()");
+        let (id2, node2) = create_test_node("b::()");
+
+        graph.insert_node(node1).unwrap();
+        graph.insert_node(node2).unwrap();
+        graph.insert_edge(GraphEdge::new(id1.clone(), id2.clone(), 5)).unwrap();
+
+        let a = graph.node_index(&id1).unwrap();
+        let b = graph.node_index(&id2).unwrap();
+        assert_eq!(graph.node_at(a).id, id1);
+        assert_eq!(graph.successors(a).collect::<Vec<_>>(), vec![b]);
+        assert_eq!(graph.predecessors(b).collect::<Vec<_>>(), vec![a]);
+        assert_eq!(graph.successors(b).count(), 0);
+    }
+
+    #[test]
+    fn test_contains_node() {
+        let mut graph = CallGraph::new();
+        let (id, node) = create_test_node("a::()");
+        assert!(!graph.contains_node(&id));
+        graph.insert_node(node).unwrap();
+        assert!(graph.contains_node(&id));
+        assert_eq!(graph.node_ids().collect::<Vec<_>>(), vec![&id]);
+    }
 }
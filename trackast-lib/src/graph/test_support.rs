@@ -0,0 +1,32 @@
+//! Small hand-built graphs for unit tests
+
+use crate::ast::{FunctionDef, Signature};
+use crate::function_id::FunctionId;
+use crate::graph::{CallGraph, GraphEdge, GraphNode};
+
+/// The ID of the parameterless test function `name`
+pub fn id(name: &str) -> FunctionId {
+    FunctionId::new(format!("{name}::()"))
+}
+
+/// A graph of internal `functions` and `(caller, callee, line)` calls
+pub fn graph(functions: &[&str], calls: &[(&str, &str, usize)]) -> CallGraph {
+    graph_with_externals(functions, &[], calls)
+}
+
+/// Like [`graph`], with `externals` added as external functions
+pub fn graph_with_externals(functions: &[&str], externals: &[&str], calls: &[(&str, &str, usize)]) -> CallGraph {
+    let mut graph = CallGraph::new();
+    for name in functions {
+        let def = FunctionDef::new((*name).to_string(), Signature::empty(), "root".to_string());
+        graph.insert_node(GraphNode::internal(id(name), def)).unwrap();
+    }
+    for name in externals {
+        let def = FunctionDef::new((*name).to_string(), Signature::empty(), "<external>".to_string());
+        graph.insert_node(GraphNode::external(id(name), def)).unwrap();
+    }
+    for (from, to, line) in calls {
+        graph.insert_edge(GraphEdge::new(id(from), id(to), *line)).unwrap();
+    }
+    graph
+}
@@ -0,0 +1,163 @@
+use std::collections::VecDeque;
+use serde::Serialize;
+use crate::cycles::condensation;
+use crate::function_id::FunctionId;
+use crate::graph::{CallGraph, GraphEdge};
+use crate::paths::CallPath;
+
+/// What a change to some functions affects
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ImpactReport {
+    /// The changed functions found in the graph, sorted
+    pub changed: Vec<FunctionId>,
+    /// Functions calling a changed function, directly or transitively, that
+    /// are not changed themselves, sorted
+    pub callers: Vec<FunctionId>,
+    /// A shortest chain from each affected entry point to the nearest
+    /// changed function, sorted by entry point
+    pub entry_points: Vec<CallPath>,
+}
+
+/// Report the transitive callers of `changed` and the entry points they reach
+///
+/// With no `entry_points`, the roots of the affected code are used: the
+/// affected functions no function outside their own recursive group calls.
+/// Entry points that reach no changed function are left out, and changed
+/// IDs missing from the graph are ignored.
+#[must_use]
+pub fn impact(graph: &CallGraph, changed: &[FunctionId], entry_points: &[FunctionId]) -> ImpactReport {
+    // Multi-source reverse BFS: for each affected node, the call it makes
+    // towards the nearest changed function
+    let mut toward: Vec<Option<&GraphEdge>> = vec![None; graph.node_count()];
+    let mut affected = vec![false; graph.node_count()];
+    let mut is_changed = vec![false; graph.node_count()];
+    let mut queue = VecDeque::new();
+    for index in changed.iter().filter_map(|id| graph.node_index(id)) {
+        if !affected[index] {
+            affected[index] = true;
+            is_changed[index] = true;
+            queue.push_back(index);
+        }
+    }
+    while let Some(current) = queue.pop_front() {
+        for (caller, edge) in graph.in_edges(current) {
+            if !affected[caller] {
+                affected[caller] = true;
+                toward[caller] = Some(edge);
+                queue.push_back(caller);
+            }
+        }
+    }
+
+    let ids_where = |flags: &[bool]| -> Vec<FunctionId> {
+        let mut ids: Vec<FunctionId> = graph
+            .nodes
+            .values()
+            .zip(flags)
+            .filter(|(_, flag)| **flag)
+            .map(|(node, _)| node.id.clone())
+            .collect();
+        ids.sort();
+        ids
+    };
+    let callers: Vec<bool> = affected.iter().zip(&is_changed).map(|(a, c)| *a && !*c).collect();
+
+    let mut entries: Vec<usize> = if entry_points.is_empty() {
+        roots(graph, &affected)
+    } else {
+        entry_points
+            .iter()
+            .filter_map(|id| graph.node_index(id))
+            .filter(|&index| affected[index])
+            .collect()
+    };
+    entries.sort_by(|a, b| graph.node_at(*a).id.cmp(&graph.node_at(*b).id));
+    entries.dedup();
+
+    let entry_points = entries
+        .into_iter()
+        .map(|entry| {
+            let mut hops = Vec::new();
+            let mut at = entry;
+            while let Some(edge) = toward[at] {
+                hops.push(edge.clone());
+                // Every edge on the way was recorded from a node in the graph
+                at = graph.node_index(&edge.to).unwrap_or(at);
+            }
+            CallPath {
+                start: graph.node_at(entry).id.clone(),
+                hops,
+            }
+        })
+        .collect();
+
+    ImpactReport {
+        changed: ids_where(&is_changed),
+        callers: ids_where(&callers),
+        entry_points,
+    }
+}
+
+/// Affected nodes that no node outside their strongly connected component calls
+fn roots(graph: &CallGraph, affected: &[bool]) -> Vec<usize> {
+    let condensed = condensation(graph);
+    let mut called = vec![false; condensed.components.len()];
+    for &(_, to) in &condensed.edges {
+        called[to] = true;
+    }
+    graph
+        .nodes
+        .values()
+        .enumerate()
+        .filter(|(index, node)| {
+            affected[*index] && condensed.component_of(&node.id).is_some_and(|component| !called[component])
+        })
+        .map(|(index, _)| index)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::test_support::{graph, id};
+
+    /// main -> handle -> parse, cli -> parse, job <-> retry -> parse, unrelated
+    fn create_test_graph() -> CallGraph {
+        graph(
+            &["main", "handle", "parse", "cli", "job", "retry", "unrelated"],
+            &[
+                ("main", "handle", 1),
+                ("handle", "parse", 2),
+                ("cli", "parse", 3),
+                ("job", "retry", 4),
+                ("retry", "job", 5),
+                ("retry", "parse", 6),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_impact_defaults_to_roots() {
+        let graph = create_test_graph();
+        let report = impact(&graph, &[id("parse"), id("missing")], &[]);
+        assert_eq!(report.changed, [id("parse")]);
+        assert_eq!(report.callers, [id("cli"), id("handle"), id("job"), id("main"), id("retry")]);
+
+        let entries: Vec<&FunctionId> = report.entry_points.iter().map(|path| &path.start).collect();
+        assert_eq!(entries, [&id("cli"), &id("job"), &id("main"), &id("retry")]);
+        let main = &report.entry_points[2];
+        assert_eq!(main.functions(), [&id("main"), &id("handle"), &id("parse")]);
+        let lines: Vec<usize> = main.hops.iter().map(|hop| hop.line).collect();
+        assert_eq!(lines, [1, 2]);
+    }
+
+    #[test]
+    fn test_impact_with_given_entry_points() {
+        let graph = create_test_graph();
+        let report = impact(&graph, &[id("handle")], &[id("main"), id("cli"), id("handle")]);
+        assert_eq!(report.callers, [id("main")]);
+        let entries: Vec<&FunctionId> = report.entry_points.iter().map(|path| &path.start).collect();
+        assert_eq!(entries, [&id("handle"), &id("main")]);
+        assert!(report.entry_points[0].is_empty());
+    }
+}
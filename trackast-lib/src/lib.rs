@@ -8,3 +8,6 @@ pub mod cycles;
 pub mod builder;
 pub mod export;
 pub mod stats;
+pub mod paths;
+pub mod impact;
+pub mod diff;
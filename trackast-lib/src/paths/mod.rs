@@ -0,0 +1,259 @@
+use std::collections::VecDeque;
+use serde::Serialize;
+use crate::function_id::FunctionId;
+use crate::graph::{CallGraph, GraphEdge};
+
+/// A chain of calls from one function to another
+///
+/// Each hop is the call site followed, so its line (and file, when known)
+/// say where the caller makes the call. Between two functions called at
+/// several sites, the first site in graph order is used.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CallPath {
+    /// Function the chain starts at
+    pub start: FunctionId,
+    /// Calls followed, in order; empty when the chain ends where it starts
+    pub hops: Vec<GraphEdge>,
+}
+
+impl CallPath {
+    /// Number of calls in the chain
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.hops.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.hops.is_empty()
+    }
+
+    /// Function the chain ends at
+    #[must_use]
+    pub fn end(&self) -> &FunctionId {
+        self.hops.last().map_or(&self.start, |hop| &hop.to)
+    }
+
+    /// Functions along the chain, both ends included
+    #[must_use]
+    pub fn functions(&self) -> Vec<&FunctionId> {
+        std::iter::once(&self.start).chain(self.hops.iter().map(|hop| &hop.to)).collect()
+    }
+}
+
+/// The first call site from the node at `index` to each function it calls
+fn first_call_sites(graph: &CallGraph, index: usize) -> Vec<(usize, &GraphEdge)> {
+    let mut sites: Vec<(usize, &GraphEdge)> = Vec::new();
+    for (to, edge) in graph.out_edges(index) {
+        if !sites.iter().any(|&(seen, _)| seen == to) {
+            sites.push((to, edge));
+        }
+    }
+    sites
+}
+
+/// Find a shortest chain of calls from `from` to `to`
+///
+/// Breadth-first, so among chains of equal length the one through earlier
+/// call sites wins. Returns `None` when either function is missing or `to`
+/// cannot be reached.
+#[must_use]
+pub fn shortest_path(graph: &CallGraph, from: &FunctionId, to: &FunctionId) -> Option<CallPath> {
+    let start = graph.node_index(from)?;
+    let target = graph.node_index(to)?;
+    let mut via: Vec<Option<&GraphEdge>> = vec![None; graph.node_count()];
+    let mut visited = vec![false; graph.node_count()];
+    let mut queue = VecDeque::from([start]);
+    visited[start] = true;
+
+    while let Some(current) = queue.pop_front() {
+        if current == target {
+            let mut hops = Vec::new();
+            let mut at = current;
+            while let Some(edge) = via[at] {
+                hops.push(edge.clone());
+                at = graph.node_index(&edge.from)?;
+            }
+            hops.reverse();
+            return Some(CallPath { start: from.clone(), hops });
+        }
+        for (next, edge) in first_call_sites(graph, current) {
+            if !visited[next] {
+                visited[next] = true;
+                via[next] = Some(edge);
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/// Find the chains of calls from `from` to `to` of at most `max_depth` calls
+///
+/// Chains never visit a function twice. At most `max_paths` are returned,
+/// shortest first; chains of equal length keep depth-first call-site order.
+/// The search deepens one call at a time, so every shorter chain is found
+/// before a longer one, and functions that cannot reach `to` within the
+/// remaining calls are pruned.
+#[must_use]
+pub fn all_paths(
+    graph: &CallGraph,
+    from: &FunctionId,
+    to: &FunctionId,
+    max_depth: usize,
+    max_paths: usize,
+) -> Vec<CallPath> {
+    let (Some(start), Some(target)) = (graph.node_index(from), graph.node_index(to)) else {
+        return Vec::new();
+    };
+
+    // Calls needed to reach the target from each node, by reverse BFS
+    let mut distance = vec![usize::MAX; graph.node_count()];
+    distance[target] = 0;
+    let mut queue = VecDeque::from([target]);
+    while let Some(current) = queue.pop_front() {
+        for caller in graph.predecessors(current) {
+            if distance[caller] == usize::MAX {
+                distance[caller] = distance[current] + 1;
+                queue.push_back(caller);
+            }
+        }
+    }
+
+    let shortest = distance[start];
+    let mut search = PathSearch {
+        graph,
+        target,
+        distance,
+        length: 0,
+        max_paths,
+        on_path: vec![false; graph.node_count()],
+        hops: Vec::new(),
+        found: Vec::new(),
+    };
+    for length in shortest..=max_depth {
+        if search.found.len() >= max_paths {
+            break;
+        }
+        search.length = length;
+        search.visit(start);
+    }
+
+    search
+        .found
+        .into_iter()
+        .map(|hops| CallPath { start: from.clone(), hops })
+        .collect()
+}
+
+/// Depth-first state for [`all_paths`]
+struct PathSearch<'a> {
+    graph: &'a CallGraph,
+    target: usize,
+    distance: Vec<usize>,
+    /// Calls in the chains the current pass looks for
+    length: usize,
+    max_paths: usize,
+    on_path: Vec<bool>,
+    hops: Vec<GraphEdge>,
+    found: Vec<Vec<GraphEdge>>,
+}
+
+impl PathSearch<'_> {
+    fn visit(&mut self, current: usize) {
+        if self.found.len() >= self.max_paths {
+            return;
+        }
+        if current == self.target {
+            if self.hops.len() == self.length {
+                self.found.push(self.hops.clone());
+            }
+            return;
+        }
+        // Calls left after the next one; recursion depth is bounded by `length`
+        let Some(remaining) = self.length.checked_sub(self.hops.len() + 1) else {
+            return;
+        };
+        self.on_path[current] = true;
+        for (next, edge) in first_call_sites(self.graph, current) {
+            if self.on_path[next] || self.distance[next] > remaining {
+                continue;
+            }
+            self.hops.push(edge.clone());
+            self.visit(next);
+            self.hops.pop();
+        }
+        self.on_path[current] = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::test_support::{graph, id};
+    use crate::ast::{FunctionDef, Signature};
+    use crate::graph::GraphNode;
+
+    /// a -> b -> d, a -> c -> d (c called twice), b -> c, d -> a
+    fn create_test_graph() -> CallGraph {
+        graph(
+            &["a", "b", "c", "d"],
+            &[("a", "b", 1), ("a", "c", 2), ("a", "c", 3), ("b", "d", 4), ("b", "c", 5), ("c", "d", 6), ("d", "a", 7)],
+        )
+    }
+
+    fn names(path: &CallPath) -> Vec<&str> {
+        path.functions().iter().map(|id| id.as_str().trim_end_matches("::()")).collect()
+    }
+
+    #[test]
+    fn test_shortest_path_records_call_sites() {
+        let graph = create_test_graph();
+        let path = shortest_path(&graph, &id("a"), &id("d")).unwrap();
+        assert_eq!(names(&path), ["a", "b", "d"]);
+        let lines: Vec<usize> = path.hops.iter().map(|hop| hop.line).collect();
+        assert_eq!(lines, [1, 4]);
+        assert_eq!(path.end(), &id("d"));
+
+        let to_c = shortest_path(&graph, &id("a"), &id("c")).unwrap();
+        assert_eq!(to_c.hops[0].line, 2);
+    }
+
+    #[test]
+    fn test_shortest_path_trivial_and_missing() {
+        let graph = create_test_graph();
+        assert!(shortest_path(&graph, &id("b"), &id("b")).unwrap().is_empty());
+        assert!(shortest_path(&graph, &id("a"), &id("missing")).is_none());
+
+        let mut graph = graph;
+        let def = FunctionDef::new("e".to_string(), Signature::empty(), "root".to_string());
+        graph.insert_node(GraphNode::internal(id("e"), def)).unwrap();
+        assert!(shortest_path(&graph, &id("a"), &id("e")).is_none());
+    }
+
+    #[test]
+    fn test_all_paths_shortest_first() {
+        let graph = create_test_graph();
+        let paths = all_paths(&graph, &id("a"), &id("d"), 10, 10);
+        let chains: Vec<Vec<&str>> = paths.iter().map(names).collect();
+        assert_eq!(chains, [vec!["a", "b", "d"], vec!["a", "c", "d"], vec!["a", "b", "c", "d"]]);
+    }
+
+    #[test]
+    fn test_all_paths_keeps_shortest_when_truncated() {
+        let graph = create_test_graph();
+        let paths = all_paths(&graph, &id("a"), &id("d"), 10, 2);
+        let chains: Vec<Vec<&str>> = paths.iter().map(names).collect();
+        assert_eq!(chains, [vec!["a", "b", "d"], vec!["a", "c", "d"]]);
+    }
+
+    #[test]
+    fn test_all_paths_bounds() {
+        let graph = create_test_graph();
+        assert_eq!(all_paths(&graph, &id("a"), &id("d"), 2, 10).len(), 2);
+        assert_eq!(all_paths(&graph, &id("a"), &id("d"), 10, 1).len(), 1);
+        assert!(all_paths(&graph, &id("a"), &id("d"), 1, 10).is_empty());
+        assert_eq!(all_paths(&graph, &id("a"), &id("a"), 0, 10).len(), 1);
+        assert!(all_paths(&graph, &id("a"), &id("b"), 0, 10).is_empty());
+    }
+}
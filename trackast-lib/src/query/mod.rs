@@ -11,6 +11,13 @@ pub trait GraphQuery {
     ///
     /// Returns an error if the function is not found in the graph.
     fn reachable_from(&self, id: &FunctionId) -> Result<HashSet<FunctionId>, String>;
+    /// Get all functions that call the given function, directly or
+    /// transitively, including the function itself
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the function is not found in the graph.
+    fn reverse_reachable(&self, id: &FunctionId) -> Result<HashSet<FunctionId>, String>;
     fn direct_callers(&self, id: &FunctionId) -> Vec<FunctionId>;
     fn direct_callees(&self, id: &FunctionId) -> Vec<FunctionId>;
     fn get_function(&self, id: &FunctionId) -> Option<&crate::graph::GraphNode>;
@@ -20,13 +27,38 @@ pub trait GraphQuery {
 impl GraphQuery for CallGraph {
     /// Get all functions reachable from the given function
     fn reachable_from(&self, id: &FunctionId) -> Result<HashSet<FunctionId>, String> {
-        if !self.nodes.contains_key(id) {
+        if !self.contains_node(id) {
             return Err(format!("Function not found: {id}"));
         }
         let result = dfs_traversal(self, id);
         Ok(result.reachable)
     }
 
+    /// Get all functions that call the given function, directly or transitively
+    fn reverse_reachable(&self, id: &FunctionId) -> Result<HashSet<FunctionId>, String> {
+        let Some(start) = self.node_index(id) else {
+            return Err(format!("Function not found: {id}"));
+        };
+        let mut visited = vec![false; self.node_count()];
+        visited[start] = true;
+        let mut stack = vec![start];
+        while let Some(current) = stack.pop() {
+            for caller in self.predecessors(current) {
+                if !visited[caller] {
+                    visited[caller] = true;
+                    stack.push(caller);
+                }
+            }
+        }
+        Ok(self
+            .nodes
+            .values()
+            .zip(visited)
+            .filter(|(_, visited)| *visited)
+            .map(|(node, _)| node.id.clone())
+            .collect())
+    }
+
     /// Get all functions that directly call the given function
     fn direct_callers(&self, id: &FunctionId) -> Vec<FunctionId> {
         self.get_edges_to(id)
@@ -57,8 +89,7 @@ impl GraphQuery for CallGraph {
         self.edges
             .iter()
             .filter(|e| {
-                self.nodes
-                    .get(&e.to)
+                self.get_node(&e.to)
                     .is_some_and(|n| n.is_external)
             })
             .collect()
@@ -133,6 +164,18 @@ mod tests {
         assert!(graph.reachable_from(&id_missing).is_err());
     }
 
+    #[test]
+    fn test_reverse_reachable() {
+        let graph = create_test_graph();
+        let id_c = FunctionId::new("c::()".to_string());
+        let callers = graph.reverse_reachable(&id_c).unwrap();
+        assert_eq!(callers.len(), 3); // c, b, a
+
+        let id_a = FunctionId::new("a::()".to_string());
+        assert_eq!(graph.reverse_reachable(&id_a).unwrap().len(), 1);
+        assert!(graph.reverse_reachable(&FunctionId::new("missing::()".to_string())).is_err());
+    }
+
     #[test]
     fn test_direct_callers() {
         let graph = create_test_graph();
@@ -1 +1,75 @@
-// Statistics module - to be implemented in Phase 4
+use std::fmt;
+use serde::Serialize;
+use crate::cycles::find_cycles;
+use crate::graph::CallGraph;
+
+/// Summary counts of a call graph
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct GraphStats {
+    /// Functions, internal and external
+    pub node_count: usize,
+    /// Call sites
+    pub edge_count: usize,
+    /// Functions called but not defined in the analyzed code
+    pub external_count: usize,
+    /// Recursive groups, as reported by [`find_cycles`]
+    pub cycle_count: usize,
+}
+
+impl fmt::Display for GraphStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Functions: {}", self.node_count)?;
+        writeln!(f, "  internal: {}", self.node_count - self.external_count)?;
+        writeln!(f, "  external: {}", self.external_count)?;
+        writeln!(f, "Calls: {}", self.edge_count)?;
+        write!(f, "Recursive groups: {}", self.cycle_count)
+    }
+}
+
+impl CallGraph {
+    /// Compute summary counts of the graph
+    #[must_use]
+    pub fn stats(&self) -> GraphStats {
+        GraphStats {
+            node_count: self.node_count(),
+            edge_count: self.edge_count(),
+            external_count: self.nodes.values().filter(|node| node.is_external).count(),
+            cycle_count: find_cycles(self).len(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::{FunctionDef, Signature};
+    use crate::function_id::FunctionId;
+    use crate::graph::{GraphEdge, GraphNode};
+
+    #[test]
+    fn test_stats_counts() {
+        let mut graph = CallGraph::new();
+        let a = FunctionId::new("a::()".to_string());
+        let b = FunctionId::new("b::()".to_string());
+        let ext = FunctionId::new("<external>::ext::()".to_string());
+        let def = |name: &str| FunctionDef::new(name.to_string(), Signature::empty(), "root".to_string());
+        graph.insert_node(GraphNode::internal(a.clone(), def("a"))).unwrap();
+        graph.insert_node(GraphNode::internal(b.clone(), def("b"))).unwrap();
+        graph.insert_node(GraphNode::external(ext.clone(), def("ext"))).unwrap();
+        graph.insert_edge(GraphEdge::new(a.clone(), b.clone(), 1)).unwrap();
+        graph.insert_edge(GraphEdge::new(b.clone(), a.clone(), 2)).unwrap();
+        graph.insert_edge(GraphEdge::new(b, ext, 3)).unwrap();
+
+        let stats = graph.stats();
+        assert_eq!(
+            stats,
+            GraphStats {
+                node_count: 3,
+                edge_count: 3,
+                external_count: 1,
+                cycle_count: 1,
+            }
+        );
+        assert!(stats.to_string().contains("  internal: 2"));
+    }
+}
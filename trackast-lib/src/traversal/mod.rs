@@ -44,21 +44,34 @@ impl Default for TraversalResult {
 /// Depth-first search traversal starting from a single node
 #[must_use] 
 pub fn dfs_traversal(graph: &CallGraph, start: &FunctionId) -> TraversalResult {
+    dfs_indexed(graph, std::slice::from_ref(start), |_| {})
+}
+
+/// Breadth-first search traversal starting from a single node
+#[must_use] 
+pub fn bfs_traversal(graph: &CallGraph, start: &FunctionId) -> TraversalResult {
     let mut result = TraversalResult::new();
-    let mut stack = vec![start.clone()];
-    let mut visited = HashSet::new();
+    let Some(start_index) = graph.node_index(start) else {
+        // An unknown start is reported as visited, with no callees
+        result.add_node(start.clone());
+        return result;
+    };
+    let mut queue = VecDeque::new();
+    let mut visited = vec![false; graph.node_count()];
+
+    queue.push_back(start_index);
 
-    while let Some(current) = stack.pop() {
-        if visited.contains(&current) {
+    while let Some(current) = queue.pop_front() {
+        if visited[current] {
             continue;
         }
-        visited.insert(current.clone());
-        result.add_node(current.clone());
+        visited[current] = true;
+        result.add_node(graph.node_at(current).id.clone());
 
-        // Add all callees to stack
-        for edge in graph.get_edges_from(&current) {
-            if !visited.contains(&edge.to) {
-                stack.push(edge.to.clone());
+        // Add all callees to queue
+        for next in graph.successors(current) {
+            if !visited[next] {
+                queue.push_back(next);
             }
         }
     }
@@ -66,26 +79,41 @@ pub fn dfs_traversal(graph: &CallGraph, start: &FunctionId) -> TraversalResult {
     result
 }
 
-/// Breadth-first search traversal starting from a single node
-#[must_use] 
-pub fn bfs_traversal(graph: &CallGraph, start: &FunctionId) -> TraversalResult {
+/// Index-based DFS shared by the public traversals, linear in graph size
+///
+/// Starts are explored in order. A start missing from the graph is
+/// reported as visited, with no callees.
+fn dfs_indexed(
+    graph: &CallGraph,
+    starts: &[FunctionId],
+    mut on_visit: impl FnMut(&FunctionId),
+) -> TraversalResult {
     let mut result = TraversalResult::new();
-    let mut queue = VecDeque::new();
-    let mut visited = HashSet::new();
+    let mut visited = vec![false; graph.node_count()];
+    let mut stack = Vec::new();
 
-    queue.push_back(start.clone());
-
-    while let Some(current) = queue.pop_front() {
-        if visited.contains(&current) {
+    for start in starts {
+        let Some(start_index) = graph.node_index(start) else {
+            result.add_node(start.clone());
+            on_visit(start);
             continue;
-        }
-        visited.insert(current.clone());
-        result.add_node(current.clone());
+        };
+        stack.push(start_index);
 
-        // Add all callees to queue
-        for edge in graph.get_edges_from(&current) {
-            if !visited.contains(&edge.to) {
-                queue.push_back(edge.to.clone());
+        while let Some(current) = stack.pop() {
+            if visited[current] {
+                continue;
+            }
+            visited[current] = true;
+            let id = &graph.node_at(current).id;
+            result.add_node(id.clone());
+            on_visit(id);
+
+            // Add all callees to stack
+            for next in graph.successors(current) {
+                if !visited[next] {
+                    stack.push(next);
+                }
             }
         }
     }
@@ -99,14 +127,7 @@ pub fn traversal_from_entries(
     graph: &CallGraph,
     entries: &[FunctionId],
 ) -> TraversalResult {
-    let mut result = TraversalResult::new();
-
-    for entry in entries {
-        let entry_result = dfs_traversal(graph, entry);
-        result.merge(entry_result);
-    }
-
-    result
+    dfs_indexed(graph, entries, |_| {})
 }
 
 /// Visitor trait for custom traversal logic
@@ -120,27 +141,7 @@ pub fn dfs_with_visitor(
     start: &FunctionId,
     visitor: &mut dyn Visitor,
 ) -> TraversalResult {
-    let mut result = TraversalResult::new();
-    let mut stack = vec![start.clone()];
-    let mut visited = HashSet::new();
-
-    while let Some(current) = stack.pop() {
-        if visited.contains(&current) {
-            continue;
-        }
-        visited.insert(current.clone());
-        result.add_node(current.clone());
-        visitor.visit(&current);
-
-        // Add all callees to stack
-        for edge in graph.get_edges_from(&current) {
-            if !visited.contains(&edge.to) {
-                stack.push(edge.to.clone());
-            }
-        }
-    }
-
-    result
+    dfs_indexed(graph, std::slice::from_ref(start), |id| visitor.visit(id))
 }
 
 #[cfg(test)]
@@ -213,6 +214,17 @@ mod tests {
         assert!(result.reachable.contains(&id_c));
     }
 
+    #[test]
+    fn test_missing_start_is_reported_as_visited() {
+        let (graph, id_a, _, _) = create_graph_with_edges();
+        let missing = FunctionId::new("missing::()".to_string());
+        assert_eq!(dfs_traversal(&graph, &missing).visited_order, std::slice::from_ref(&missing));
+        assert_eq!(bfs_traversal(&graph, &missing).visited_order, std::slice::from_ref(&missing));
+        let result = traversal_from_entries(&graph, &[missing.clone(), id_a.clone()]);
+        assert_eq!(result.visited_order.len(), 4);
+        assert_eq!(result.visited_order[..2], [missing, id_a]);
+    }
+
     #[test]
     fn test_bfs_single_node() {
         let (graph, id_a, _, _) = create_graph_with_edges();
@@ -0,0 +1,243 @@
+//! Discovery of the crates in a Cargo package or workspace
+//!
+//! Only the parts of a manifest that decide where crate roots live are read:
+//! `[package] name`, `[lib]`, `[[bin]]` and `[workspace] members`, plus the
+//! `src/lib.rs`, `src/main.rs` and `src/bin/*` conventions.
+
+use std::path::{Path, PathBuf};
+use toml::Table;
+
+/// Whether a crate is a library or a binary
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TargetKind {
+    Lib,
+    Bin,
+}
+
+/// A crate root found in a manifest
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrateTarget {
+    /// Root module name, e.g. `trackast_lib`
+    pub name: String,
+    pub kind: TargetKind,
+    /// The crate root file, e.g. `src/lib.rs`
+    pub root: PathBuf,
+}
+
+/// All crate targets of a package or workspace, libraries first
+///
+/// `manifest` is a `Cargo.toml` or a directory containing one. Workspace
+/// members are followed, including `dir/*` globs. A binary sharing its
+/// package's library name is named `<name>_bin`, so the library keeps the
+/// name other crates use for it.
+///
+/// # Errors
+///
+/// Returns an error if a manifest cannot be read or parsed.
+pub fn discover_targets(manifest: &Path) -> Result<Vec<CrateTarget>, String> {
+    let manifest = if manifest.is_dir() {
+        manifest.join("Cargo.toml")
+    } else {
+        manifest.to_path_buf()
+    };
+
+    let mut targets = Vec::new();
+    collect_targets(&manifest, &mut targets)?;
+    targets.sort_by_key(|target| target.kind == TargetKind::Bin);
+    Ok(targets)
+}
+
+fn collect_targets(manifest: &Path, targets: &mut Vec<CrateTarget>) -> Result<(), String> {
+    let table = read_manifest(manifest)?;
+    let dir = manifest.parent().unwrap_or_else(|| Path::new("."));
+
+    if table.contains_key("package") {
+        targets.extend(package_targets(&table, dir));
+    }
+
+    let members = table
+        .get("workspace")
+        .and_then(|workspace| workspace.get("members"))
+        .and_then(toml::Value::as_array);
+    for member in members.into_iter().flatten().filter_map(toml::Value::as_str) {
+        for member_dir in expand_member(dir, member) {
+            let member_manifest = member_dir.join("Cargo.toml");
+            if member_manifest.is_file() {
+                collect_targets(&member_manifest, targets)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn read_manifest(manifest: &Path) -> Result<Table, String> {
+    let source = std::fs::read_to_string(manifest)
+        .map_err(|e| format!("Failed to read {}: {e}", manifest.display()))?;
+    source
+        .parse::<Table>()
+        .map_err(|e| format!("Failed to parse {}: {e}", manifest.display()))
+}
+
+/// Workspace member directories; a trailing `/*` matches every subdirectory
+fn expand_member(dir: &Path, member: &str) -> Vec<PathBuf> {
+    let Some(parent) = member.strip_suffix("/*") else {
+        return vec![dir.join(member)];
+    };
+    let mut dirs: Vec<PathBuf> = std::fs::read_dir(dir.join(parent))
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .collect();
+    dirs.sort();
+    dirs
+}
+
+fn package_targets(table: &Table, dir: &Path) -> Vec<CrateTarget> {
+    let package_name = table
+        .get("package")
+        .and_then(|package| package.get("name"))
+        .and_then(toml::Value::as_str)
+        .unwrap_or("crate");
+    let mut targets = Vec::new();
+
+    // Library: `[lib] path`, or src/lib.rs
+    let lib = table.get("lib");
+    let lib_root = lib
+        .and_then(|lib| lib.get("path"))
+        .and_then(toml::Value::as_str)
+        .map_or_else(|| dir.join("src/lib.rs"), |path| dir.join(path));
+    if lib_root.is_file() {
+        let name = lib
+            .and_then(|lib| lib.get("name"))
+            .and_then(toml::Value::as_str)
+            .unwrap_or(package_name);
+        targets.push(CrateTarget {
+            name: crate_name(name),
+            kind: TargetKind::Lib,
+            root: lib_root,
+        });
+    }
+
+    // Binaries: explicit `[[bin]]` entries, then src/main.rs and src/bin/*
+    let mut bins: Vec<(String, PathBuf)> = table
+        .get("bin")
+        .and_then(toml::Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|bin| {
+            let name = bin.get("name").and_then(toml::Value::as_str)?;
+            let path = bin
+                .get("path")
+                .and_then(toml::Value::as_str)
+                .map_or_else(|| default_bin_path(dir, name, package_name), |path| dir.join(path));
+            Some((name.to_string(), path))
+        })
+        .collect();
+    for (name, path) in conventional_bins(dir, package_name) {
+        if !bins.iter().any(|(_, existing)| *existing == path) {
+            bins.push((name, path));
+        }
+    }
+
+    for (name, root) in bins.into_iter().filter(|(_, root)| root.is_file()) {
+        let mut name = crate_name(&name);
+        if targets.iter().any(|target| target.name == name) {
+            name.push_str("_bin");
+        }
+        targets.push(CrateTarget {
+            name,
+            kind: TargetKind::Bin,
+            root,
+        });
+    }
+    targets
+}
+
+fn default_bin_path(dir: &Path, name: &str, package_name: &str) -> PathBuf {
+    if name == package_name {
+        dir.join("src/main.rs")
+    } else {
+        let file = dir.join("src/bin").join(format!("{name}.rs"));
+        if file.is_file() {
+            file
+        } else {
+            dir.join("src/bin").join(name).join("main.rs")
+        }
+    }
+}
+
+/// src/main.rs, src/bin/<name>.rs and src/bin/<name>/main.rs
+fn conventional_bins(dir: &Path, package_name: &str) -> Vec<(String, PathBuf)> {
+    let mut bins = vec![(package_name.to_string(), dir.join("src/main.rs"))];
+
+    let mut entries: Vec<PathBuf> = std::fs::read_dir(dir.join("src/bin"))
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .collect();
+    entries.sort();
+    for path in entries {
+        let Some(stem) = path.file_stem().and_then(|s| s.to_str()).map(str::to_string) else {
+            continue;
+        };
+        if path.is_dir() {
+            bins.push((stem, path.join("main.rs")));
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            bins.push((stem, path));
+        }
+    }
+    bins
+}
+
+/// Crate names use underscores where package names may use hyphens
+fn crate_name(name: &str) -> String {
+    name.replace('-', "_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture_root(dir: &str) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).parent().unwrap().join("tests/fixtures").join(dir)
+    }
+
+    #[test]
+    fn test_discover_workspace() {
+        let targets = discover_targets(&fixture_root("cargo")).unwrap();
+        let names: Vec<(&str, TargetKind)> = targets.iter().map(|t| (t.name.as_str(), t.kind)).collect();
+        assert_eq!(
+            names,
+            vec![
+                ("core_util", TargetKind::Lib),
+                ("tool", TargetKind::Bin),
+                ("app", TargetKind::Bin),
+                ("extra", TargetKind::Bin),
+            ]
+        );
+        assert!(targets[0].root.ends_with("crates/core-util/src/lib.rs"));
+        assert!(targets[1].root.ends_with("app/src/tool.rs"));
+    }
+
+    #[test]
+    fn test_binary_named_like_its_library_is_renamed() {
+        let targets = discover_targets(&fixture_root("cargo_package")).unwrap();
+        let names: Vec<(&str, TargetKind)> = targets.iter().map(|t| (t.name.as_str(), t.kind)).collect();
+        assert_eq!(names, vec![("widget", TargetKind::Lib), ("widget_bin", TargetKind::Bin)]);
+        assert!(targets[1].root.ends_with("cargo_package/src/main.rs"));
+    }
+
+    #[test]
+    fn test_missing_manifest_is_an_error() {
+        assert!(discover_targets(Path::new("/nonexistent/Cargo.toml")).is_err());
+    }
+
+    #[test]
+    fn test_expand_member_glob() {
+        let dirs = expand_member(&fixture_root("cargo"), "crates/*");
+        assert_eq!(dirs, vec![fixture_root("cargo").join("crates/core-util")]);
+    }
+}
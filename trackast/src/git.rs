@@ -0,0 +1,118 @@
+//! The local git checkout
+//!
+//! Only the `git` command line is used, against the repository on disk:
+//! nothing is fetched, and the working tree is left as it is.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// The top-level directory of the git checkout holding `dir`
+#[must_use]
+pub fn git_toplevel(dir: &Path) -> Option<PathBuf> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(["rev-parse", "--show-toplevel"])
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| PathBuf::from(String::from_utf8_lossy(&output.stdout).trim()))
+}
+
+/// The diff of a revision range (`main..HEAD`, or a single revision to
+/// compare with the working tree) in the local checkout holding `dir`
+///
+/// # Errors
+///
+/// Returns an error if git cannot be run or rejects the range.
+pub fn git_diff(dir: &Path, range: &str) -> Result<String, String> {
+    if range.starts_with('-') {
+        return Err(format!("Invalid revision range '{range}'"));
+    }
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(["diff", "--no-color", "--no-ext-diff", "--unified=0", range, "--"])
+        .output()
+        .map_err(|e| format!("Failed to run git: {e}"))?;
+    if !output.status.success() {
+        return Err(format!("git diff {range} failed: {}", String::from_utf8_lossy(&output.stderr).trim()));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// A temporary, detached worktree of a revision of the local checkout
+///
+/// The worktree is removed again when dropped. If git cannot remove it,
+/// its directory is deleted and the checkout's stale worktree entry pruned.
+#[derive(Debug)]
+pub struct Worktree {
+    checkout: PathBuf,
+    dir: tempfile::TempDir,
+}
+
+impl Worktree {
+    /// Check `revision` out into a new, uniquely named directory under the
+    /// system temp dir
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory cannot be created, or git cannot be
+    /// run or rejects the revision.
+    pub fn add(checkout: &Path, revision: &str) -> Result<Self, String> {
+        if revision.starts_with('-') {
+            return Err(format!("Invalid revision '{revision}'"));
+        }
+        let dir = tempfile::Builder::new()
+            .prefix("trackast-")
+            .tempdir()
+            .map_err(|e| format!("Failed to create worktree directory: {e}"))?;
+        let output = Command::new("git")
+            .arg("-C")
+            .arg(checkout)
+            .args(["worktree", "add", "--detach", "--quiet"])
+            .arg(dir.path())
+            .arg(revision)
+            .output()
+            .map_err(|e| format!("Failed to run git: {e}"))?;
+        if !output.status.success() {
+            // A half-made worktree may already be registered
+            drop(dir);
+            prune_worktrees(checkout);
+            return Err(format!("git worktree add {revision} failed: {}", String::from_utf8_lossy(&output.stderr).trim()));
+        }
+        Ok(Worktree {
+            checkout: checkout.to_path_buf(),
+            dir,
+        })
+    }
+
+    /// Directory the revision is checked out in
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        self.dir.path()
+    }
+}
+
+impl Drop for Worktree {
+    fn drop(&mut self) {
+        let removed = Command::new("git")
+            .arg("-C")
+            .arg(&self.checkout)
+            .args(["worktree", "remove", "--force"])
+            .arg(self.dir.path())
+            .output()
+            .is_ok_and(|output| output.status.success());
+        if !removed {
+            let _ = std::fs::remove_dir_all(self.dir.path());
+            prune_worktrees(&self.checkout);
+        }
+    }
+}
+
+/// Drop the checkout's records of worktrees whose directory is gone
+fn prune_worktrees(checkout: &Path) {
+    let _ = Command::new("git").arg("-C").arg(checkout).args(["worktree", "prune"]).output();
+}
@@ -0,0 +1,159 @@
+//! Go module discovery from `go.mod`
+//!
+//! Only the `module` directive and `replace` directives pointing at local
+//! directories are read: together they decide which import paths name
+//! packages on disk. Everything else (the standard library, third-party
+//! modules) stays external.
+
+use std::path::{Path, PathBuf};
+
+/// A Go module and the local modules it replaces dependencies with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GoModule {
+    /// Module path, e.g. `example.com/shop`
+    pub path: String,
+    /// Directory holding `go.mod`
+    pub root: PathBuf,
+    /// `replace` directives with a local target, as (module path, directory)
+    pub replacements: Vec<(String, PathBuf)>,
+}
+
+impl GoModule {
+    /// The nearest `go.mod` in `start` or one of its ancestors
+    #[must_use]
+    pub fn find(start: &Path) -> Option<PathBuf> {
+        start
+            .ancestors()
+            .map(|dir| dir.join("go.mod"))
+            .find(|candidate| candidate.is_file())
+    }
+
+    /// Read a `go.mod` file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or has no `module` directive.
+    pub fn load(go_mod: &Path) -> Result<Self, String> {
+        let source = std::fs::read_to_string(go_mod)
+            .map_err(|e| format!("Failed to read {}: {e}", go_mod.display()))?;
+        let root = go_mod.parent().unwrap_or_else(|| Path::new("")).to_path_buf();
+        Self::parse(&source, root).ok_or_else(|| format!("No module directive in {}", go_mod.display()))
+    }
+
+    /// Parse the contents of a `go.mod` file located in `root`
+    #[must_use]
+    pub fn parse(source: &str, root: PathBuf) -> Option<Self> {
+        let mut path = None;
+        let mut replacements = Vec::new();
+        let mut in_replace_block = false;
+
+        for line in source.lines() {
+            let line = line.split("//").next().unwrap_or_default().trim();
+            let replace = if in_replace_block {
+                if line == ")" {
+                    in_replace_block = false;
+                    continue;
+                }
+                Some(line)
+            } else if let Some(module) = line.strip_prefix("module ") {
+                path = Some(module.trim().trim_matches('"').to_string());
+                None
+            } else if let Some(rest) = line.strip_prefix("replace") {
+                let rest = rest.trim();
+                in_replace_block = rest == "(";
+                (!in_replace_block).then_some(rest)
+            } else {
+                None
+            };
+
+            // `old [version] => new [version]`; only directory targets are local
+            let Some((old, new)) = replace.and_then(|replace| replace.split_once("=>")) else {
+                continue;
+            };
+            let old = old.split_whitespace().next().unwrap_or_default();
+            let new = new.split_whitespace().next().unwrap_or_default();
+            if new.starts_with("./") || new.starts_with("../") || new.starts_with('/') {
+                replacements.push((old.to_string(), crate::node::normalize(&root.join(new))));
+            }
+        }
+
+        path.map(|path| GoModule { path, root, replacements })
+    }
+
+    /// The directory of the package an import path names, if it is local
+    #[must_use]
+    pub fn package_dir(&self, import_path: &str) -> Option<PathBuf> {
+        std::iter::once((self.path.as_str(), self.root.as_path()))
+            .chain(self.replacements.iter().map(|(path, dir)| (path.as_str(), dir.as_path())))
+            .find_map(|(module, dir)| {
+                let rest = import_path.strip_prefix(module)?;
+                if rest.is_empty() {
+                    Some(dir.to_path_buf())
+                } else {
+                    rest.strip_prefix('/').map(|rest| dir.join(rest))
+                }
+            })
+            .filter(|dir| dir.is_dir())
+    }
+
+    /// The import path of a package directory inside this module or one of
+    /// its local replacements
+    #[must_use]
+    pub fn import_path(&self, dir: &Path) -> Option<String> {
+        std::iter::once((self.path.as_str(), self.root.as_path()))
+            .chain(self.replacements.iter().map(|(path, dir)| (path.as_str(), dir.as_path())))
+            .find_map(|(module, root)| {
+                let relative = dir.strip_prefix(root).ok()?;
+                let segments: Vec<&str> = relative.iter().filter_map(|s| s.to_str()).collect();
+                Some(if segments.is_empty() {
+                    module.to_string()
+                } else {
+                    format!("{module}/{}", segments.join("/"))
+                })
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GO_MOD: &str = "module example.com/shop // the shop\n\n\
+                          go 1.22\n\n\
+                          require github.com/google/uuid v1.6.0\n\n\
+                          replace example.com/billing => ../billing\n\
+                          replace (\n\
+                          \tgithub.com/google/uuid v1.6.0 => github.com/fork/uuid v1.6.1\n\
+                          \texample.com/audit => ./third_party/audit\n\
+                          )\n";
+
+    #[test]
+    fn test_parse_module_and_local_replacements() {
+        let module = GoModule::parse(GO_MOD, PathBuf::from("/src/shop")).unwrap();
+        assert_eq!(module.path, "example.com/shop");
+        assert_eq!(
+            module.replacements,
+            [
+                ("example.com/billing".to_string(), PathBuf::from("/src/billing")),
+                ("example.com/audit".to_string(), PathBuf::from("/src/shop/third_party/audit")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_import_paths_of_directories() {
+        let module = GoModule::parse(GO_MOD, PathBuf::from("/src/shop")).unwrap();
+        assert_eq!(module.import_path(Path::new("/src/shop")).as_deref(), Some("example.com/shop"));
+        assert_eq!(
+            module.import_path(Path::new("/src/shop/internal/cart")).as_deref(),
+            Some("example.com/shop/internal/cart")
+        );
+        assert_eq!(module.import_path(Path::new("/src/billing/tax")).as_deref(), Some("example.com/billing/tax"));
+        assert_eq!(module.import_path(Path::new("/elsewhere")), None);
+    }
+
+    #[test]
+    fn test_missing_module_directive() {
+        assert_eq!(GoModule::parse("go 1.22\n", PathBuf::from("/src")), None);
+    }
+}
@@ -1,10 +1,15 @@
 use std::path::Path;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Language {
     Rust,
     Python,
     JavaScript,
+    TypeScript,
+    Go,
+    Lua,
+    Cpp,
+    Java,
 }
 
 impl Language {
@@ -13,7 +18,12 @@ impl Language {
         match ext {
             "rs" => Some(Language::Rust),
             "py" => Some(Language::Python),
-            "js" | "ts" | "jsx" | "tsx" => Some(Language::JavaScript),
+            "js" | "mjs" | "cjs" | "jsx" => Some(Language::JavaScript),
+            "ts" | "mts" | "cts" | "tsx" => Some(Language::TypeScript),
+            "go" => Some(Language::Go),
+            "lua" => Some(Language::Lua),
+            "c" | "h" | "cc" | "cpp" | "cxx" | "hh" | "hpp" | "hxx" => Some(Language::Cpp),
+            "java" => Some(Language::Java),
             _ => None,
         }
     }
@@ -26,12 +36,35 @@ impl Language {
         Self::from_extension(ext)
     }
 
+    /// All supported languages
+    pub const ALL: [Language; 8] = [
+        Language::Rust,
+        Language::Python,
+        Language::JavaScript,
+        Language::TypeScript,
+        Language::Go,
+        Language::Lua,
+        Language::Cpp,
+        Language::Java,
+    ];
+
+    /// The language with the display name `name`, as [`Language::as_str`] gives it
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|language| language.as_str() == name)
+    }
+
     #[must_use] 
     pub fn as_str(&self) -> &'static str {
         match self {
             Language::Rust => "Rust",
             Language::Python => "Python",
             Language::JavaScript => "JavaScript",
+            Language::TypeScript => "TypeScript",
+            Language::Go => "Go",
+            Language::Lua => "Lua",
+            Language::Cpp => "C++",
+            Language::Java => "Java",
         }
     }
 }
@@ -53,9 +86,37 @@ mod tests {
     #[test]
     fn test_from_extension_javascript() {
         assert_eq!(Language::from_extension("js"), Some(Language::JavaScript));
-        assert_eq!(Language::from_extension("ts"), Some(Language::JavaScript));
         assert_eq!(Language::from_extension("jsx"), Some(Language::JavaScript));
-        assert_eq!(Language::from_extension("tsx"), Some(Language::JavaScript));
+        assert_eq!(Language::from_extension("mjs"), Some(Language::JavaScript));
+    }
+
+    #[test]
+    fn test_from_extension_typescript() {
+        assert_eq!(Language::from_extension("ts"), Some(Language::TypeScript));
+        assert_eq!(Language::from_extension("tsx"), Some(Language::TypeScript));
+    }
+
+    #[test]
+    fn test_from_extension_go() {
+        assert_eq!(Language::from_extension("go"), Some(Language::Go));
+    }
+
+    #[test]
+    fn test_from_extension_lua() {
+        assert_eq!(Language::from_extension("lua"), Some(Language::Lua));
+    }
+
+    #[test]
+    fn test_from_extension_cpp() {
+        assert_eq!(Language::from_extension("cpp"), Some(Language::Cpp));
+        assert_eq!(Language::from_extension("hpp"), Some(Language::Cpp));
+        assert_eq!(Language::from_extension("c"), Some(Language::Cpp));
+        assert_eq!(Language::from_extension("h"), Some(Language::Cpp));
+    }
+
+    #[test]
+    fn test_from_extension_java() {
+        assert_eq!(Language::from_extension("java"), Some(Language::Java));
     }
 
     #[test]
@@ -68,7 +129,16 @@ mod tests {
         assert_eq!(Language::from_file_path("main.rs"), Some(Language::Rust));
         assert_eq!(Language::from_file_path("script.py"), Some(Language::Python));
         assert_eq!(Language::from_file_path("app.js"), Some(Language::JavaScript));
-        assert_eq!(Language::from_file_path("utils/helpers.ts"), Some(Language::JavaScript));
+        assert_eq!(Language::from_file_path("utils/helpers.ts"), Some(Language::TypeScript));
+        assert_eq!(Language::from_file_path("cmd/server/main.go"), Some(Language::Go));
+    }
+
+    #[test]
+    fn test_from_name_round_trips() {
+        for language in Language::ALL {
+            assert_eq!(Language::from_name(language.as_str()), Some(language));
+        }
+        assert_eq!(Language::from_name("rust"), None);
     }
 
     #[test]
@@ -76,5 +146,10 @@ mod tests {
         assert_eq!(Language::Rust.as_str(), "Rust");
         assert_eq!(Language::Python.as_str(), "Python");
         assert_eq!(Language::JavaScript.as_str(), "JavaScript");
+        assert_eq!(Language::TypeScript.as_str(), "TypeScript");
+        assert_eq!(Language::Go.as_str(), "Go");
+        assert_eq!(Language::Lua.as_str(), "Lua");
+        assert_eq!(Language::Cpp.as_str(), "C++");
+        assert_eq!(Language::Java.as_str(), "Java");
     }
 }
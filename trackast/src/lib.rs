@@ -5,3 +5,10 @@ pub mod language;
 pub mod translator_trait;
 pub mod translator_factory;
 pub mod module_loader;
+pub mod cargo;
+pub mod gomod;
+pub mod node;
+pub mod tsconfig;
+pub mod scan;
+pub mod git;
+pub mod patch;
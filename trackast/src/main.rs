@@ -1,17 +1,25 @@
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use trackast_lib::builder::CallGraphBuilder;
+use trackast_lib::cycles::find_cycles;
+use trackast_lib::diff::diff;
+use trackast_lib::export::json::from_json_file;
 use trackast_lib::export::to_dot;
 use trackast_lib::function_id::FunctionId;
 use trackast_lib::ast::AbstractAST;
 use trackast_lib::graph::CallGraph;
+use trackast_lib::impact::{impact, ImpactReport};
+use trackast_lib::paths::{all_paths, shortest_path, CallPath};
+use trackast_lib::query::GraphQuery;
 use trackast::module_loader::ModuleLoader;
+use trackast::git::{git_diff, git_toplevel, Worktree};
+use trackast::patch::{changed_functions, parse_unified_diff};
+use trackast::scan::DirectoryScanner;
 use trackast::language::Language;
+use trackast::resolver::{get_resolver, MixedResolver, Resolver};
 use std::path::{PathBuf, Path};
-use serde_json;
 
 #[derive(Debug, Clone)]
 enum OutputStage {
-    Modules,
     Ast,
     Calls,
     Graph,
@@ -22,11 +30,10 @@ impl std::str::FromStr for OutputStage {
     
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
-            "modules" => Ok(OutputStage::Modules),
             "ast" => Ok(OutputStage::Ast),
             "calls" => Ok(OutputStage::Calls),
             "graph" => Ok(OutputStage::Graph),
-            _ => Err(format!("Invalid stage: {s}. Must be one of: modules, ast, calls, graph")),
+            _ => Err(format!("Invalid stage: {s}. Must be one of: ast, calls, graph")),
         }
     }
 }
@@ -34,125 +41,165 @@ impl std::str::FromStr for OutputStage {
 #[derive(Parser, Debug)]
 #[command(name = "trackast")]
 #[command(about = "Call dependency graph generator", long_about = None)]
+struct Cli {
+    #[command(flatten)]
+    args: Args,
+
+    /// What to do with the graph; `build` when omitted
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Build the call graph and print it as JSON or DOT
+    Build,
+    /// List the functions that call a function directly
+    Callers {
+        /// Function ID, or `module::name` matching any signature
+        function: String,
+        /// Also list the callers of callers, transitively
+        #[arg(long)]
+        transitive: bool,
+    },
+    /// List the functions a function calls directly
+    Callees {
+        /// Function ID, or `module::name` matching any signature
+        function: String,
+    },
+    /// Show a shortest call chain from one function to another, with the
+    /// line of each call
+    Path {
+        /// Function the chain starts at
+        from: String,
+        /// Function the chain ends at
+        to: String,
+        /// List every chain (shortest first) instead of one shortest chain
+        #[arg(long)]
+        all: bool,
+        /// With --all, the most calls a chain may take
+        #[arg(long, default_value_t = 10)]
+        max_depth: usize,
+        /// With --all, the most chains to list
+        #[arg(long, default_value_t = 20)]
+        max_paths: usize,
+    },
+    /// List the groups of functions that call each other recursively
+    Cycles,
+    /// Print node, edge, external and cycle counts
+    Stats,
+    /// List the functions no entry point reaches (needs --entry-points)
+    Unreachable,
+    /// Report the transitive callers of changed functions and the entry
+    /// points they affect, with the call chain from each entry point.
+    /// Entry points default to the roots of the affected code
+    Impact {
+        /// Changed functions: IDs, or `module::name` matching any signature
+        #[arg(required_unless_present_any = ["diff", "git_range"])]
+        functions: Vec<String>,
+        /// Also count as changed every function a hunk of this unified diff touches
+        #[arg(long, conflicts_with = "git_range")]
+        diff: Option<PathBuf>,
+        /// Like --diff, with the output of `git diff <RANGE>` (e.g. `main..HEAD`)
+        /// in the local checkout of --root or the first input
+        #[arg(long)]
+        git_range: Option<String>,
+    },
+    /// Compare two graphs, saved ones or the inputs at two git revisions:
+    /// added and removed functions and calls, new cycles and new external
+    /// dependencies, as Markdown (default) or JSON
+    Diff {
+        /// Saved graph of the old version
+        #[arg(requires = "new", required_unless_present = "base", conflicts_with = "base")]
+        old: Option<PathBuf>,
+        /// Saved graph of the new version
+        new: Option<PathBuf>,
+        /// Build the old graph from the inputs at this revision of the local checkout
+        #[arg(long)]
+        base: Option<String>,
+        /// Build the new graph at this revision too, instead of from the working tree
+        #[arg(long, requires = "base")]
+        head: Option<String>,
+    },
+}
+
+#[derive(clap::Args, Debug, Clone)]
 struct Args {
-    /// Input file path (entry point)
-    #[arg(short, long)]
-    input: PathBuf,
+    /// Input file path (entry point), or a `Cargo.toml` (or directory containing
+    /// one) to load every crate of a Rust package or workspace. Repeat it to
+    /// load several entry points; files of different languages are analyzed
+    /// together in one graph
+    #[arg(short, long, global = true)]
+    input: Vec<PathBuf>,
+
+    /// Read a graph saved by `build` instead of translating sources
+    #[arg(long, global = true, conflicts_with = "input")]
+    graph: Option<PathBuf>,
 
     /// Root directory for module resolution (defaults to input file directory)
-    #[arg(short, long)]
+    #[arg(short, long, global = true)]
     root: Option<PathBuf>,
 
     /// Module path for the input file (auto-detected if not specified)
-    #[arg(short, long)]
+    #[arg(short, long, global = true)]
     module: Option<String>,
 
     /// Output file path (optional)
-    #[arg(short, long)]
+    #[arg(short, long, global = true)]
     output: Option<PathBuf>,
 
-    /// Output format: json or dot
-    #[arg(short, long, default_value = "json")]
-    format: String,
+    /// Output format: json or dot for `build` (default json), text or json
+    /// for the other commands (default text)
+    #[arg(short, long, global = true)]
+    format: Option<String>,
 
-    /// Language (auto-detected from file extension if not specified)
-    #[arg(short, long)]
+    /// Language (auto-detected from each input's file extension if not specified)
+    #[arg(short, long, global = true)]
     language: Option<String>,
 
     /// Automatically discover and load dependencies
-    #[arg(long)]
+    #[arg(long, global = true)]
     no_discover: bool,
 
+    /// Treat the input as a directory and translate every supported file
+    /// under it instead of following imports (honours `.gitignore`)
+    #[arg(long, global = true)]
+    scan: bool,
+
+    /// With --scan, only translate files matching this glob, relative to the
+    /// scanned directory (e.g. `src/**/*.py`); may be repeated
+    #[arg(long, global = true)]
+    include: Vec<String>,
+
+    /// With --scan, skip files matching this glob; may be repeated
+    #[arg(long, global = true)]
+    exclude: Vec<String>,
+
     /// Entry point function ID(s) for graph traversal
-    /// Format: `module::function` (auto-matches signature) or `module::function::signature` (exact match)
-    /// Example: --entry-points `myapp::main` --entry-points `api::handler`
-    #[arg(long)]
+    /// Format: a function ID, or `module::function` matching any signature
+    /// Example: --entry-points `myapp::main` --entry-points `myapp::api::handler`
+    #[arg(long, global = true)]
     entry_points: Vec<String>,
 
-    /// Output stage: modules, ast, calls, or graph
-    #[arg(long, default_value = "graph")]
+    /// Output stage of `build`: ast, calls, or graph
+    #[arg(long, global = true, default_value = "graph")]
     stage: OutputStage,
 }
 
-fn resolve_entry_points(
-    entry_point_specs: &[String],
-    graph: &trackast_lib::graph::CallGraph,
-) -> Result<Vec<FunctionId>, String> {
-    let mut resolved = Vec::new();
-
-    for spec in entry_point_specs {
-        let parts: Vec<&str> = spec.splitn(3, "::").collect();
-        
-        let (module, function, signature_opt) = match parts.len() {
-            2 => (parts[0], parts[1], None),
-            3 => (parts[0], parts[1], Some(parts[2])),
-            _ => {
-                return Err(format!(
-                    "Invalid entry point format '{spec}'. Use 'module::function' or 'module::function::signature'"
-                ))
-            }
-        };
-
-        if let Some(sig) = signature_opt {
-            // Exact match with signature
-            let exact_id = FunctionId::new(format!("{module}::{function}::{sig}"));
-            if graph.nodes.contains_key(&exact_id) {
-                resolved.push(exact_id);
-            } else {
-                return Err(format!("Entry point not found: {spec}"));
-            }
-        } else {
-            // Fuzzy match: find functions matching module::function with any signature
-            let matching: Vec<FunctionId> = graph
-                .nodes
-                .keys()
-                .filter(|id| {
-                    let id_str = id.as_str();
-                    let id_parts: Vec<&str> = id_str.splitn(3, "::").collect();
-                    if id_parts.len() >= 2 {
-                        id_parts[0] == module && id_parts[1] == function
-                    } else {
-                        false
-                    }
-                })
-                .cloned()
-                .collect();
-
-            match matching.len() {
-                0 => {
-                    return Err(format!(
-                        "No matching entry point found for '{}::{}'. Available functions: {:?}",
-                        module,
-                        function,
-                        graph
-                            .nodes
-                            .keys()
-                            .take(5)
-                            .map(trackast_lib::function_id::FunctionId::as_str)
-                            .collect::<Vec<_>>()
-                    ))
-                }
-                1 => {
-                    eprintln!(
-                        "✓ Resolved entry point '{}::{}' to '{}'",
-                        module, function, matching[0]
-                    );
-                    resolved.push(matching[0].clone());
-                }
-                _ => {
-                    eprintln!(
-                        "⚠ Entry point '{module}::{function}' matches multiple signatures, using all:"
-                    );
-                    for id in &matching {
-                        eprintln!("  - {id}");
-                    }
-                    resolved.extend(matching);
-                }
-            }
+/// The languages of the inputs, in input order without repeats; more than
+/// one means a mixed-language analysis
+fn detect_languages(language: Option<String>, inputs: &[PathBuf]) -> Result<Vec<Language>, Box<dyn std::error::Error>> {
+    if language.is_some() {
+        return Ok(vec![detect_language(language, &inputs[0])?]);
+    }
+    let mut languages = Vec::new();
+    for input in inputs {
+        let language = detect_language(None, input)?;
+        if !languages.contains(&language) {
+            languages.push(language);
         }
     }
-
-    Ok(resolved)
+    Ok(languages)
 }
 
 fn detect_language(language: Option<String>, input_path: &Path) -> Result<Language, Box<dyn std::error::Error>> {
@@ -160,7 +207,12 @@ fn detect_language(language: Option<String>, input_path: &Path) -> Result<Langua
         match lang_str.to_lowercase().as_str() {
             "rust" | "rs" => Some(Language::Rust),
             "python" | "py" => Some(Language::Python),
-            "javascript" | "js" | "typescript" | "ts" => Some(Language::JavaScript),
+            "javascript" | "js" => Some(Language::JavaScript),
+            "typescript" | "ts" => Some(Language::TypeScript),
+            "go" | "golang" => Some(Language::Go),
+            "lua" => Some(Language::Lua),
+            "c" | "c++" | "cpp" | "cxx" => Some(Language::Cpp),
+            "java" => Some(Language::Java),
             _ => {
                 eprintln!("Error: Unknown language '{lang_str}'");
                 std::process::exit(1);
@@ -175,54 +227,128 @@ fn detect_language(language: Option<String>, input_path: &Path) -> Result<Langua
     })
 }
 
+/// The manifest to load when the input is a `Cargo.toml` or a directory holding one
+fn cargo_manifest(input_path: &Path) -> Option<PathBuf> {
+    let manifest = if input_path.is_dir() {
+        input_path.join("Cargo.toml")
+    } else {
+        input_path.to_path_buf()
+    };
+    (manifest.file_name()? == "Cargo.toml" && manifest.is_file()).then_some(manifest)
+}
+
 fn load_ast(
-    language: Language,
-    input_path: &Path,
+    languages: &[Language],
+    inputs: &[PathBuf],
     root_dir: &Path,
     module: Option<String>,
     no_discover: bool,
 ) -> Result<AbstractAST, Box<dyn std::error::Error>> {
-    if no_discover {
-        eprintln!("📄 Loading single file (dependencies disabled)");
-        let translator = trackast::translator_factory::get_translator(language);
-        let module = module.unwrap_or_else(|| {
-            input_path
-                .file_stem()
-                .and_then(|s| s.to_str())
-                .unwrap_or("root")
-                .to_string()
-        });
-        translator.translate_file(input_path.to_str().unwrap(), Some(&module)).map_err(Into::into)
-    } else {
+    let entry_points: Vec<&str> = inputs.iter().map(|input| input.to_str().unwrap()).collect();
+    if let Some(manifest) = cargo_manifest(&inputs[0]).filter(|_| inputs.len() == 1) {
+        eprintln!("📦 Loading Cargo project from {}", manifest.display());
+        let mut loader = ModuleLoader::new(root_dir, Language::Rust);
+        let ast = loader.load_cargo(&manifest)?;
+        report_warnings(&loader);
+        Ok(ast)
+    } else if no_discover {
+        eprintln!("📄 Loading input files only (dependencies disabled)");
+        let mut combined_ast: Option<AbstractAST> = None;
+        for input_path in inputs {
+            let language = match languages {
+                [language] => *language,
+                _ => detect_language(None, input_path)?,
+            };
+            let translator = trackast::translator_factory::get_translator(language);
+            // An explicit module names a single input only
+            let module = module.clone().filter(|_| inputs.len() == 1).unwrap_or_else(|| {
+                input_path
+                    .file_stem()
+                    .and_then(|s| s.to_str())
+                    .unwrap_or("root")
+                    .to_string()
+            });
+            let mut ast = translator.translate_file(input_path.to_str().unwrap(), Some(&module))?;
+            ast.set_language(language.as_str());
+            match &mut combined_ast {
+                Some(combined_ast) => combined_ast.merge(ast),
+                None => combined_ast = Some(ast),
+            }
+        }
+        combined_ast.ok_or_else(|| "No input file given".into())
+    } else if let [language] = languages {
         eprintln!("🔍 Auto-discovering module dependencies...");
-        let mut loader = ModuleLoader::new(root_dir, language);
-        loader.load_all(input_path.to_str().unwrap()).map_err(Into::into)
+        let mut loader = ModuleLoader::new(root_dir, *language);
+        let ast = loader.load_entries(&entry_points)?;
+        report_warnings(&loader);
+        Ok(ast)
+    } else {
+        eprintln!("🔍 Auto-discovering module dependencies across languages...");
+        let mut loader = ModuleLoader::new_mixed(root_dir);
+        let ast = loader.load_entries(&entry_points)?;
+        report_warnings(&loader);
+        Ok(ast)
+    }
+}
+
+/// Print why the loader skipped each dependency it could not load
+fn report_warnings(loader: &ModuleLoader) {
+    for warning in loader.warnings() {
+        eprintln!("⚠ {warning}");
+    }
+}
+
+/// Translate every supported file under `dir`, one AST per file
+fn scan_asts(dir: &Path, language: Option<Language>, includes: &[String], excludes: &[String]) -> Result<Vec<AbstractAST>, String> {
+    eprintln!("🗂 Scanning {} for supported files", dir.display());
+    let mut scanner = DirectoryScanner::new(dir);
+    if let Some(language) = language {
+        scanner = scanner.with_language(language);
+    }
+    for glob in includes {
+        scanner = scanner.with_include(glob);
+    }
+    for glob in excludes {
+        scanner = scanner.with_exclude(glob);
     }
+    let asts = scanner.scan()?;
+    eprintln!("📄 Translated {} files", asts.len());
+    Ok(asts)
+}
+
+/// The languages the functions of `asts` are tagged with, in a stable order
+fn ast_languages(asts: &[AbstractAST]) -> Vec<Language> {
+    let tagged: Vec<&str> = asts
+        .iter()
+        .flat_map(|ast| &ast.functions)
+        .filter_map(|func| func.language.as_deref())
+        .collect();
+    Language::ALL
+        .into_iter()
+        .filter(|language| tagged.contains(&language.as_str()))
+        .collect()
+}
+
+/// Display names of the languages, comma-separated
+fn language_names(languages: &[Language]) -> String {
+    languages.iter().map(Language::as_str).collect::<Vec<_>>().join(", ")
 }
 
 fn build_output(
     format: &str,
     graph: &CallGraph,
-    language: Language,
+    languages: &[Language],
     entry_points: &[String],
 ) -> Result<String, Box<dyn std::error::Error>> {
     if entry_points.is_empty() {
         match format {
-            "json" => {
-                Ok(serde_json::json!({
-                    "language": language.as_str(),
-                    "nodes": graph.node_count(),
-                    "edges": graph.edge_count(),
-                    "message": "Call graph built successfully"
-                })
-                .to_string())
-            }
+            "json" => Ok(graph.to_json()),
             "dot" => Ok(to_dot(graph)),
             _ => unreachable!(),
         }
     } else {
         eprintln!("🔍 Resolving entry points...");
-        let entry_ids = resolve_entry_points(entry_points, graph)?;
+        let entry_ids = lookup_entry_points(entry_points, graph)?;
         eprintln!("📍 Using {} entry point(s)", entry_ids.len());
 
         let traversal_result = trackast_lib::traversal::traversal_from_entries(graph, &entry_ids);
@@ -232,7 +358,8 @@ fn build_output(
         match format {
             "json" => {
                 Ok(serde_json::json!({
-                    "language": language.as_str(),
+                    "language": language_names(languages),
+                    "languages": languages.iter().map(Language::as_str).collect::<Vec<_>>(),
                     "total_nodes": graph.node_count(),
                     "total_edges": graph.edge_count(),
                     "entry_points": entry_points,
@@ -262,99 +389,461 @@ fn build_output(
     }
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let args = Args::parse();
+/// Write command output to `--output`, or print it
+fn write_output(output_path: Option<&PathBuf>, output: &str, what: &str) -> std::io::Result<()> {
+    if let Some(output_path) = output_path {
+        std::fs::write(output_path, output)?;
+        eprintln!("✅ {what} written to {}", output_path.display());
+    } else {
+        println!("{output}");
+    }
+    Ok(())
+}
 
-    // Check if file exists
-    if !args.input.exists() {
-        eprintln!("Error: Input file does not exist: {}", args.input.display());
+/// Translate and resolve the inputs, returning their ASTs and languages
+fn load_asts(args: &Args) -> Result<(Vec<AbstractAST>, Vec<Language>), Box<dyn std::error::Error>> {
+    if args.input.is_empty() {
+        eprintln!("Error: Give the sources with --input, or a saved graph with --graph");
         std::process::exit(1);
     }
 
-    // Validate format
-    if args.format != "json" && args.format != "dot" {
-        eprintln!("Error: Unknown format '{}'. Use 'json' or 'dot'", args.format);
+    // Check if the input files exist
+    for input in &args.input {
+        if !input.exists() {
+            eprintln!("Error: Input file does not exist: {}", input.display());
+            std::process::exit(1);
+        }
+    }
+
+    if args.scan && (args.input.len() != 1 || !args.input[0].is_dir()) {
+        eprintln!("Error: --scan takes a single input directory");
         std::process::exit(1);
     }
 
-    // Detect language
-    let language = detect_language(args.language, &args.input)?;
-    eprintln!("📝 Detected language: {}", language.as_str());
+    // Detect languages; a scan finds them from the files it translates
+    let mut languages = if args.scan {
+        let filter = args.language.clone().map(|language| detect_language(Some(language), &args.input[0])).transpose()?;
+        filter.into_iter().collect()
+    } else if args.input.len() == 1 && cargo_manifest(&args.input[0]).is_some() {
+        vec![Language::Rust]
+    } else {
+        detect_languages(args.language.clone(), &args.input)?
+    };
+    if !args.scan {
+        eprintln!("📝 Detected language: {}", language_names(&languages));
+    }
 
     // Determine root directory for module resolution
-    let root_dir = args.root.unwrap_or_else(|| {
-        args.input
+    let root_dir = args.root.clone().unwrap_or_else(|| {
+        if args.scan {
+            return args.input[0].clone();
+        }
+        args.input[0]
             .parent().map_or_else(|| PathBuf::from("."), std::path::Path::to_path_buf)
     });
     eprintln!("📂 Using root directory: {}", root_dir.display());
 
-    // Handle modules stage early (before AST loading)
-    if matches!(args.stage, OutputStage::Modules) {
-        let output = serde_json::json!({
-            "message": "Module discovery info not yet exposed",
-            "entry_file": args.input.to_str().unwrap_or("")
-        }).to_string();
-        
-        if let Some(output_path) = &args.output {
-            std::fs::write(output_path, &output)?;
-            eprintln!("✅ Module info written to {}", output_path.display());
-        } else {
-            println!("{output}");
-        }
-        return Ok(());
+    // Load ASTs: one per file when scanning, otherwise one for the inputs and their imports
+    let mut asts = if args.scan {
+        let asts = scan_asts(&root_dir, languages.first().copied(), &args.include, &args.exclude)?;
+        languages = ast_languages(&asts);
+        eprintln!("📝 Detected language: {}", language_names(&languages));
+        asts
+    } else {
+        vec![load_ast(&languages, &args.input, &root_dir, args.module.clone(), args.no_discover)?]
+    };
+    let functions: usize = asts.iter().map(|ast| ast.functions.len()).sum();
+    eprintln!("📦 Found {functions} functions");
+
+    // Bind calls to definitions before any call-level output
+    let bound = match languages.as_slice() {
+        [language] => get_resolver(*language).resolve_all(&mut asts),
+        _ => MixedResolver::new(&asts).resolve_all(&mut asts),
+    };
+    let total: usize = asts.iter().flat_map(|ast| &ast.functions).map(|f| f.calls.len()).sum();
+    eprintln!("🧭 Resolved {bound} of {total} calls");
+
+    Ok((asts, languages))
+}
+
+fn build_graph(asts: Vec<AbstractAST>) -> Result<CallGraph, String> {
+    let mut builder = CallGraphBuilder::new();
+    for ast in asts {
+        builder.add_ast(ast)?;
+    }
+    let graph = builder.build()?;
+    eprintln!("🔗 Built graph with {} nodes and {} edges", graph.node_count(), graph.edge_count());
+    Ok(graph)
+}
+
+/// The graph a command runs on: a saved graph, or one built from the inputs
+fn load_graph(args: &Args) -> Result<CallGraph, Box<dyn std::error::Error>> {
+    if let Some(path) = &args.graph {
+        let graph = from_json_file(path.to_str().unwrap())?;
+        eprintln!("📂 Loaded graph with {} nodes and {} edges", graph.node_count(), graph.edge_count());
+        return Ok(graph);
+    }
+    let (asts, _) = load_asts(args)?;
+    Ok(build_graph(asts)?)
+}
+
+/// The `build` command: print the graph, or an earlier stage of the pipeline
+fn run_build(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
+    // Validate format
+    let format = args.format.as_deref().unwrap_or("json");
+    if format != "json" && format != "dot" {
+        eprintln!("Error: Unknown format '{format}'. Use 'json' or 'dot'");
+        std::process::exit(1);
     }
 
-    // Load AST
-    let ast = load_ast(language, &args.input, &root_dir, args.module, args.no_discover)?;
-    eprintln!("📦 Found {} functions", ast.functions.len());
+    if let Some(path) = &args.graph {
+        let graph = from_json_file(path.to_str().unwrap())?;
+        let languages: Vec<Language> = Language::ALL
+            .into_iter()
+            .filter(|language| graph.nodes.values().any(|node| node.metadata.language.as_deref() == Some(language.as_str())))
+            .collect();
+        let output = build_output(format, &graph, &languages, &args.entry_points)?;
+        return Ok(write_output(args.output.as_ref(), &output, "Output")?);
+    }
+
+    let (asts, languages) = load_asts(args)?;
 
-    // Handle AST stage (before graph building)  
+    // Handle AST stage (before graph building)
     if matches!(args.stage, OutputStage::Ast) {
-        let output = serde_json::to_string_pretty(&ast)
-            .map_err(|e| format!("JSON serialization error: {e}"))?;
-            
-        if let Some(output_path) = &args.output {
-            std::fs::write(output_path, &output)?;
-            eprintln!("✅ AST written to {}", output_path.display());
-        } else {
-            println!("{output}");
+        let output = match asts.as_slice() {
+            [ast] if !args.scan => serde_json::to_string_pretty(ast),
+            _ => serde_json::to_string_pretty(&asts),
         }
-        return Ok(());
+        .map_err(|e| format!("JSON serialization error: {e}"))?;
+        return Ok(write_output(args.output.as_ref(), &output, "AST")?);
     }
 
     // Handle calls stage (before graph building)
     if matches!(args.stage, OutputStage::Calls) {
-        let calls: Vec<_> = ast.functions.iter()
+        let calls: Vec<_> = asts.iter()
+            .flat_map(|ast| &ast.functions)
             .flat_map(|f| f.calls.iter().map(move |c| (f, c)))
             .collect();
         let output = serde_json::to_string_pretty(&calls)
             .map_err(|e| format!("JSON serialization error: {e}"))?;
-            
-        if let Some(output_path) = &args.output {
-            std::fs::write(output_path, &output)?;
-            eprintln!("✅ Function calls written to {}", output_path.display());
+        return Ok(write_output(args.output.as_ref(), &output, "Function calls")?);
+    }
+
+    // Build call graph (only for Graph stage)
+    let graph = build_graph(asts)?;
+    let output = build_output(format, &graph, &languages, &args.entry_points)?;
+    Ok(write_output(args.output.as_ref(), &output, "Output")?)
+}
+
+/// The functions a command-line spec names: an exact ID, or `module::name`
+/// (a bare name for functions without a module) matching any signature
+fn lookup_functions(spec: &str, graph: &CallGraph) -> Result<Vec<FunctionId>, String> {
+    let exact = FunctionId::new(spec.to_string());
+    if graph.contains_node(&exact) {
+        return Ok(vec![exact]);
+    }
+    let mut matching: Vec<FunctionId> = graph
+        .nodes
+        .values()
+        .filter(|node| {
+            let function = &node.metadata;
+            if function.module.is_empty() {
+                function.name == spec
+            } else {
+                spec.strip_prefix(function.module.as_str())
+                    .and_then(|rest| rest.strip_prefix("::"))
+                    .is_some_and(|name| name == function.name)
+            }
+        })
+        .map(|node| node.id.clone())
+        .collect();
+    matching.sort();
+    if matching.is_empty() {
+        return Err(format!("No function matches '{spec}'"));
+    }
+    Ok(matching)
+}
+
+/// The single function a command-line spec names
+fn lookup_function(spec: &str, graph: &CallGraph) -> Result<FunctionId, String> {
+    let mut matching = lookup_functions(spec, graph)?;
+    if matching.len() > 1 {
+        let ids: Vec<String> = matching.iter().map(ToString::to_string).collect();
+        return Err(format!("'{spec}' matches several functions, give a full ID:\n  {}", ids.join("\n  ")));
+    }
+    Ok(matching.remove(0))
+}
+
+/// The functions the `--entry-points` specs name, each matched like the
+/// function arguments of the query commands
+fn lookup_entry_points(specs: &[String], graph: &CallGraph) -> Result<Vec<FunctionId>, String> {
+    let mut entry_ids = Vec::new();
+    for spec in specs {
+        entry_ids.extend(lookup_functions(spec, graph)?);
+    }
+    Ok(entry_ids)
+}
+
+/// A call chain as text, one function per line with the call site leading to it
+fn format_path(path: &CallPath) -> String {
+    let mut text = path.start.to_string();
+    for hop in &path.hops {
+        let site = match &hop.file {
+            Some(file) => format!("{file}:{}", hop.line),
+            None => format!("line {}", hop.line),
+        };
+        text.push_str(&format!("\n  -> {} ({site})", hop.to));
+    }
+    text
+}
+
+/// The directory whose git checkout a patch applies to: the root, or the
+/// first input (its directory for a file), or the working directory
+fn checkout_dir(args: &Args) -> PathBuf {
+    let dir = args.root.clone().or_else(|| {
+        let input = args.input.first()?;
+        if input.is_dir() {
+            Some(input.clone())
         } else {
-            println!("{output}");
+            input.parent().map(Path::to_path_buf)
         }
-        return Ok(());
+    });
+    dir.filter(|dir| !dir.as_os_str().is_empty()).unwrap_or_else(|| PathBuf::from("."))
+}
+
+/// An impact report as text: the changed functions, their callers, and the
+/// chain from each affected entry point
+fn format_impact(report: &ImpactReport) -> String {
+    let mut text = vec![format!("Changed functions ({}):", report.changed.len())];
+    text.extend(report.changed.iter().map(|id| format!("  {id}")));
+    text.push(format!("Transitive callers ({}):", report.callers.len()));
+    text.extend(report.callers.iter().map(|id| format!("  {id}")));
+    text.push(format!("Affected entry points ({}):", report.entry_points.len()));
+    for path in &report.entry_points {
+        text.push(String::new());
+        text.push(format_path(path));
     }
+    text.join("\n")
+}
 
-    // Build call graph (only for Graph stage)
-    let mut builder = CallGraphBuilder::new();
-    builder.add_ast(ast)?;
-    let graph = builder.build()?;
-    eprintln!("🔗 Built graph with {} nodes and {} edges", graph.node_count(), graph.edge_count());
+/// Run a query command and render its result as text or JSON
+fn run_query(command: &Command, args: &Args) -> Result<(), Box<dyn std::error::Error>> {
+    let format = args.format.as_deref().unwrap_or("text");
+    if format != "text" && format != "json" {
+        eprintln!("Error: Unknown format '{format}'. Use 'text' or 'json'");
+        std::process::exit(1);
+    }
+    let json = format == "json";
+    let graph = load_graph(args)?;
+
+    let output = match command {
+        Command::Build | Command::Diff { .. } => unreachable!(),
+        Command::Callers { function, .. } | Command::Callees { function } => {
+            let callers = matches!(command, Command::Callers { .. });
+            let transitive = matches!(command, Command::Callers { transitive: true, .. });
+            let mut results = Vec::new();
+            for id in lookup_functions(function, &graph)? {
+                let mut related = if transitive {
+                    let mut all = graph.reverse_reachable(&id)?;
+                    all.remove(&id);
+                    all.into_iter().collect()
+                } else if callers {
+                    graph.direct_callers(&id)
+                } else {
+                    graph.direct_callees(&id)
+                };
+                related.sort();
+                results.push((id, related));
+            }
+            let key = if callers { "callers" } else { "callees" };
+            if json {
+                let results: Vec<_> = results
+                    .iter()
+                    .map(|(id, related)| serde_json::json!({ "function": id, key: related }))
+                    .collect();
+                serde_json::to_string_pretty(&results)?
+            } else {
+                let mut text = Vec::new();
+                for (id, related) in &results {
+                    text.push(format!("{} of {id} ({}):", if callers { "Callers" } else { "Callees" }, related.len()));
+                    text.extend(related.iter().map(|related| format!("  {related}")));
+                }
+                text.join("\n")
+            }
+        }
+        Command::Path { from, to, all, max_depth, max_paths } => {
+            let from = lookup_function(from, &graph)?;
+            let to = lookup_function(to, &graph)?;
+            let paths = if *all {
+                all_paths(&graph, &from, &to, *max_depth, *max_paths)
+            } else {
+                shortest_path(&graph, &from, &to).into_iter().collect()
+            };
+            if json {
+                serde_json::to_string_pretty(&serde_json::json!({ "from": from, "to": to, "paths": paths }))?
+            } else if paths.is_empty() {
+                format!("No call path from {from} to {to}")
+            } else {
+                paths.iter().map(format_path).collect::<Vec<_>>().join("\n\n")
+            }
+        }
+        Command::Cycles => {
+            let cycles = find_cycles(&graph);
+            if json {
+                let cycles: Vec<&Vec<FunctionId>> = cycles.iter().map(|cycle| &cycle.nodes).collect();
+                serde_json::to_string_pretty(&cycles)?
+            } else {
+                let mut text = vec![format!("{} recursive group(s)", cycles.len())];
+                for (index, cycle) in cycles.iter().enumerate() {
+                    text.push(format!("{}. {} function(s):", index + 1, cycle.len()));
+                    text.extend(cycle.nodes.iter().map(|id| format!("  {id}")));
+                }
+                text.join("\n")
+            }
+        }
+        Command::Stats => {
+            let stats = graph.stats();
+            if json {
+                serde_json::to_string_pretty(&stats)?
+            } else {
+                stats.to_string()
+            }
+        }
+        Command::Unreachable => {
+            if args.entry_points.is_empty() {
+                eprintln!("Error: unreachable needs at least one --entry-points");
+                std::process::exit(1);
+            }
+            let entry_ids = lookup_entry_points(&args.entry_points, &graph)?;
+            let reachable = trackast_lib::traversal::traversal_from_entries(&graph, &entry_ids).reachable;
+            let mut unreachable: Vec<&FunctionId> = graph
+                .nodes
+                .values()
+                .filter(|node| !node.is_external && !reachable.contains(&node.id))
+                .map(|node| &node.id)
+                .collect();
+            unreachable.sort();
+            if json {
+                serde_json::to_string_pretty(&serde_json::json!({
+                    "entry_points": entry_ids,
+                    "unreachable": unreachable,
+                }))?
+            } else {
+                let mut text = vec![format!("{} function(s) unreachable from the entry points:", unreachable.len())];
+                text.extend(unreachable.iter().map(|id| format!("  {id}")));
+                text.join("\n")
+            }
+        }
+        Command::Impact { functions, diff, git_range } => {
+            let mut changed = Vec::new();
+            for spec in functions {
+                changed.extend(lookup_functions(spec, &graph)?);
+            }
+            if diff.is_some() || git_range.is_some() {
+                let dir = checkout_dir(args);
+                let patch = match (diff, git_range) {
+                    (Some(path), _) => std::fs::read_to_string(path)
+                        .map_err(|e| format!("Failed to read {}: {e}", path.display()))?,
+                    (None, Some(range)) => git_diff(&dir, range)?,
+                    (None, None) => unreachable!(),
+                };
+                let changes = parse_unified_diff(&patch)?;
+                let base = git_toplevel(&dir).unwrap_or_else(|| PathBuf::from("."));
+                let touched = changed_functions(&graph, &changes, &base);
+                eprintln!("🩹 Patch changes {} file(s), touching {} function(s)", changes.len(), touched.len());
+                changed.extend(touched);
+            }
+            let entry_ids = lookup_entry_points(&args.entry_points, &graph)?;
+            let report = impact(&graph, &changed, &entry_ids);
+            if json {
+                serde_json::to_string_pretty(&report)?
+            } else {
+                format_impact(&report)
+            }
+        }
+    };
+    Ok(write_output(args.output.as_ref(), &output, "Output")?)
+}
 
-    // Generate output
-    let output = build_output(&args.format, &graph, language, &args.entry_points)?;
+/// Build the graph of the inputs as they are at `revision` of the local
+/// checkout, from a temporary worktree
+fn load_graph_at(args: &Args, revision: &str) -> Result<CallGraph, Box<dyn std::error::Error>> {
+    let toplevel = git_toplevel(&checkout_dir(args))
+        .and_then(|toplevel| toplevel.canonicalize().ok())
+        .ok_or("--base and --head need the inputs to be inside a git checkout")?;
+    let worktree = Worktree::add(&toplevel, revision)?;
+    eprintln!("🌿 Checked out {revision} into {}", worktree.path().display());
+
+    // The same paths, inside the worktree
+    let rebase = |path: &PathBuf| -> Result<PathBuf, String> {
+        let absolute = path.canonicalize().map_err(|e| format!("{}: {e}", path.display()))?;
+        let relative = absolute
+            .strip_prefix(&toplevel)
+            .map_err(|_| format!("{} is outside the git checkout", path.display()))?;
+        let rebased = worktree.path().join(relative);
+        if !rebased.exists() {
+            return Err(format!("{} does not exist at {revision}", relative.display()));
+        }
+        Ok(rebased)
+    };
+    let mut at_revision = args.clone();
+    at_revision.input = args.input.iter().map(rebase).collect::<Result<_, _>>()?;
+    at_revision.root = args.root.as_ref().map(rebase).transpose()?;
+    let (mut asts, _) = load_asts(&at_revision)?;
+
+    // Name files by their place in the checkout, not in the worktree
+    let in_checkout = |file: &mut Option<String>| {
+        if let Some(relative) = file.as_deref().and_then(|file| Path::new(file).strip_prefix(worktree.path()).ok()) {
+            *file = Some(relative.display().to_string());
+        }
+    };
+    for func in asts.iter_mut().flat_map(|ast| &mut ast.functions) {
+        in_checkout(&mut func.file);
+        for call in &mut func.calls {
+            in_checkout(&mut call.file);
+        }
+    }
+    Ok(build_graph(asts)?)
+}
 
-    // Write output
-    if let Some(output_path) = &args.output {
-        std::fs::write(output_path, &output)?;
-        eprintln!("✅ Output written to {}", output_path.display());
-    } else {
-        println!("{output}");
+/// The `diff` command: compare two versions of the graph
+fn run_diff(command: &Command, args: &Args) -> Result<(), Box<dyn std::error::Error>> {
+    let Command::Diff { old, new, base, head } = command else {
+        unreachable!()
+    };
+    let format = args.format.as_deref().unwrap_or("markdown");
+    if format != "markdown" && format != "json" {
+        eprintln!("Error: Unknown format '{format}'. Use 'markdown' or 'json'");
+        std::process::exit(1);
     }
 
-    Ok(())
+    let (old_graph, new_graph) = match (old, new, base) {
+        (Some(old), Some(new), _) => (from_json_file(old.to_str().unwrap())?, from_json_file(new.to_str().unwrap())?),
+        (_, _, Some(base)) => {
+            let old_graph = load_graph_at(args, base)?;
+            let new_graph = match head {
+                Some(head) => load_graph_at(args, head)?,
+                None => load_graph(args)?,
+            };
+            (old_graph, new_graph)
+        }
+        _ => unreachable!(),
+    };
+
+    let changes = diff(&old_graph, &new_graph);
+    let output = if format == "json" {
+        serde_json::to_string_pretty(&changes)?
+    } else {
+        changes.to_markdown()
+    };
+    Ok(write_output(args.output.as_ref(), &output, "Diff")?)
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let cli = Cli::parse();
+    match cli.command.unwrap_or(Command::Build) {
+        Command::Build => run_build(&cli.args),
+        command @ Command::Diff { .. } => run_diff(&command, &cli.args),
+        command => run_query(&command, &cli.args),
+    }
 }
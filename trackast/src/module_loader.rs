@@ -1,4 +1,10 @@
 use crate::translator_factory::get_translator;
+use crate::cargo::discover_targets;
+use crate::gomod::GoModule;
+use crate::node::{normalize, NodePackages};
+use crate::resolver::python::anchor_relative;
+use crate::translators::cpp::{HEADER_EXTENSIONS, SOURCE_EXTENSIONS};
+use crate::translators::{GoTranslator, JavaTranslator, RustTranslator};
 use crate::language::Language;
 use trackast_lib::ast::AbstractAST;
 use std::collections::HashSet;
@@ -9,18 +15,97 @@ pub struct ModuleLoader {
     root_path: PathBuf,
     language: Language,
     loaded_modules: HashSet<PathBuf>,
+    /// Follow `use`/`import` lines to files under the root; off when the
+    /// module tree comes from a Cargo manifest
+    follow_imports: bool,
+    /// Local packages bare JavaScript specifiers can name
+    node_packages: NodePackages,
+    /// The Go module enclosing the root, which decides the import paths of
+    /// local packages
+    go_module: Option<GoModule>,
+    /// Pick each file's language from its extension instead of using
+    /// `language` throughout
+    mixed: bool,
+    /// Why each dependency that could not be loaded was skipped
+    warnings: Vec<String>,
 }
 
 impl ModuleLoader {
     /// Create a new module loader for a given root path and language
     pub fn new(root_path: impl AsRef<Path>, language: Language) -> Self {
+        let root_path = root_path.as_ref().to_path_buf();
+        let node_packages = if matches!(language, Language::JavaScript | Language::TypeScript) {
+            NodePackages::discover(&root_path)
+        } else {
+            NodePackages::default()
+        };
+        let go_module = if language == Language::Go {
+            GoModule::find(&root_path).and_then(|go_mod| GoModule::load(&go_mod).ok())
+        } else {
+            None
+        };
         ModuleLoader {
-            root_path: root_path.as_ref().to_path_buf(),
+            root_path,
             language,
             loaded_modules: HashSet::new(),
+            follow_imports: true,
+            node_packages,
+            go_module,
+            mixed: false,
+            warnings: Vec::new(),
+        }
+    }
+
+    /// Create a loader for a project mixing languages
+    ///
+    /// Each file is translated, and its imports followed, by the rules of
+    /// the language its extension names, so one load can cover Python
+    /// calling into Rust or JavaScript next to Python. Every function is
+    /// tagged with its language either way.
+    pub fn new_mixed(root_path: impl AsRef<Path>) -> Self {
+        let root_path = root_path.as_ref().to_path_buf();
+        let node_packages = NodePackages::discover(&root_path);
+        let go_module = GoModule::find(&root_path).and_then(|go_mod| GoModule::load(&go_mod).ok());
+        ModuleLoader {
+            // Replaced by each file's own language while it loads
+            language: Language::Rust,
+            mixed: true,
+            node_packages,
+            go_module,
+            ..Self::new(root_path, Language::Rust)
         }
     }
 
+    /// Load every crate of a Cargo package or workspace
+    ///
+    /// `manifest` is a `Cargo.toml` or a directory containing one. Each crate
+    /// root is loaded with its crate name as module path and only `mod`
+    /// declarations are followed, so modules are named
+    /// `crate_name::module::path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the manifest cannot be read, names no crate, or
+    /// if translation fails.
+    pub fn load_cargo(&mut self, manifest: &Path) -> Result<AbstractAST, String> {
+        let targets = discover_targets(manifest)?;
+        if targets.is_empty() {
+            return Err(format!("No library or binary targets found in {}", manifest.display()));
+        }
+
+        self.language = Language::Rust;
+        self.follow_imports = false;
+        let mut combined_ast = AbstractAST::new(targets[0].name.clone());
+        for target in targets {
+            // Targets of a package may declare the same module file, and each
+            // crate gets its own copy
+            self.loaded_modules.clear();
+            let ast = self.load_recursively(&target.root, Some(target.name), true)?;
+            combined_ast.merge(ast);
+        }
+        Ok(combined_ast)
+    }
+
     /// Load all modules recursively starting from entry point
     ///
     /// # Errors
@@ -48,73 +133,542 @@ impl ModuleLoader {
             ));
         }
 
-        self.load_recursively(&entry_path)
+        self.load_recursively(&entry_path, None, true)
+    }
+
+    /// Load several entry points, with their dependencies, into one AST
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no entry point is given, one does not exist, or
+    /// if translation fails.
+    pub fn load_entries(&mut self, entry_points: &[&str]) -> Result<AbstractAST, String> {
+        let (first, rest) = entry_points.split_first().ok_or("No entry point given")?;
+        let mut combined_ast = self.load_all(first)?;
+        for entry_point in rest {
+            combined_ast.merge(self.load_all(entry_point)?);
+        }
+        Ok(combined_ast)
     }
 
     /// Recursively load a file and all its dependencies
-    fn load_recursively(&mut self, path: &PathBuf) -> Result<AbstractAST, String> {
+    ///
+    /// `module_name` overrides the root-relative name, e.g. for a Rust module
+    /// whose path comes from its `mod` declaration. `crate_root` marks an
+    /// entry point or Cargo target root, whose Rust child modules sit beside
+    /// it rather than in a directory named after it.
+    fn load_recursively(&mut self, path: &PathBuf, module_name: Option<String>, crate_root: bool) -> Result<AbstractAST, String> {
+        if self.mixed && path.to_str().and_then(Language::from_file_path) != Some(self.language) {
+            return self.in_file_language(path, |loader| loader.load_recursively(path, module_name, crate_root));
+        }
+
         if self.loaded_modules.contains(path) {
             return Ok(AbstractAST::new("already_loaded".to_string()));
         }
 
         self.loaded_modules.insert(path.clone());
 
-        let translator = get_translator(self.language);
-        let module_name = path
-            .file_stem()
-            .and_then(|s| s.to_str())
-            .unwrap_or("unknown");
-
-        let ast = translator.translate_file(path.to_str().unwrap(), Some(module_name))?;
-
-        // Extract imports from this file
-        let imports = self.extract_imports(path)?;
-
-        // Recursively load each imported module
-        let mut combined_ast = ast.clone();
-        for import_path in imports {
-            if let Ok(resolved_path) = self.resolve_path(&import_path) {
-                if !self.loaded_modules.contains(&resolved_path) {
-                    if let Ok(imported_ast) = self.load_recursively(&resolved_path) {
-                        // Merge ASTs
-                        for func in imported_ast.functions {
-                            combined_ast.add_function(func);
-                        }
-                    } else {
-                        // External or non-existent module, skip silently
-                    }
-                }
+        let module_name = self.file_module(path, module_name);
+        let mut ast = self.translate_file(path, &module_name)?;
+
+        let dependencies = self.dependencies(path, &module_name, crate_root, &mut ast)?;
+        let mut combined_ast = ast;
+        for (dependency_path, dependency_module) in dependencies {
+            if self.loaded_modules.contains(&dependency_path) {
+                continue;
+            }
+            // Dependencies that cannot be loaded are external, and skipped
+            match self.load_recursively(&dependency_path, dependency_module, false) {
+                Ok(dependency_ast) => self.merge_dependency(&mut combined_ast, dependency_ast),
+                Err(error) => self.warnings.push(format!("Skipped {}: {error}", dependency_path.display())),
             }
         }
 
         Ok(combined_ast)
     }
 
-    /// Extract import statements from a source file
+    /// Why each dependency that could not be loaded so far was skipped, in
+    /// load order
+    #[must_use]
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
+    /// Add the functions and imports of a loaded dependency to `ast`
+    ///
+    /// Each function is added by the translator of the language it is
+    /// tagged with, so a mixed load folds repeated definitions by the rules
+    /// of the file that holds them rather than of the file importing it.
+    fn merge_dependency(&self, ast: &mut AbstractAST, dependency: AbstractAST) {
+        for func in dependency.functions {
+            let language = func.language.as_deref().and_then(Language::from_name).unwrap_or(self.language);
+            get_translator(language).add_function(ast, func);
+        }
+        ast.imports.extend(dependency.imports);
+    }
+
+    /// Translate one file without loading anything it imports
+    ///
+    /// The file gets the module path [`ModuleLoader::load_all`] would give
+    /// it; a mixed loader picks its language from the extension.
     ///
     /// # Errors
     ///
-    /// Returns an error if the file cannot be read or if extraction fails.
-    pub fn extract_imports_from_file(&self, path: &Path) -> Result<Vec<String>, String> {
+    /// Returns an error if the file's language is not supported or if
+    /// translation fails.
+    pub fn load_file(&mut self, path: &Path) -> Result<AbstractAST, String> {
+        if self.mixed {
+            return self.in_file_language(path, |loader| {
+                let module_name = loader.file_module(path, None);
+                loader.translate_file(path, &module_name)
+            });
+        }
+        let module_name = self.file_module(path, None);
+        self.translate_file(path, &module_name)
+    }
+
+    /// Run `load` with the loader switched to the language of `path`'s
+    /// extension, for mixed-language loading
+    fn in_file_language<T>(&mut self, path: &Path, load: impl FnOnce(&mut Self) -> Result<T, String>) -> Result<T, String> {
+        let language = path
+            .to_str()
+            .and_then(Language::from_file_path)
+            .ok_or_else(|| format!("Unsupported file type: {}", path.display()))?;
+        let previous = std::mem::replace(&mut self.language, language);
+        let result = load(self);
+        self.language = previous;
+        result
+    }
+
+    /// Module path of a file: `module_name` if given, else its Go or Java
+    /// package, else its path relative to the root
+    fn file_module(&self, path: &Path, module_name: Option<String>) -> String {
+        module_name
+            .or_else(|| self.go_package_module(path))
+            .or_else(|| self.java_package_module(path))
+            .unwrap_or_else(|| self.module_name(path))
+    }
+
+    /// Translate a file under `module_name`, tagging its functions with
+    /// the loader's language
+    fn translate_file(&self, path: &Path, module_name: &str) -> Result<AbstractAST, String> {
+        let path = path.to_str().ok_or_else(|| format!("Invalid path: {}", path.display()))?;
+        let mut ast = get_translator(self.language).translate_file(path, Some(module_name))?;
+        ast.set_language(self.language.as_str());
+        Ok(ast)
+    }
+
+    /// The files a file depends on, by the rules of the loader's language,
+    /// each with the module path to load it under (`None` for the one
+    /// [`ModuleLoader::file_module`] gives it)
+    ///
+    /// Import sources in `ast` are pointed at the modules they load where
+    /// the language's imports name files or packages.
+    fn dependencies(&mut self, path: &Path, module: &str, crate_root: bool, ast: &mut AbstractAST) -> Result<Vec<(PathBuf, Option<String>)>, String> {
+        let named = |dependencies: Vec<(PathBuf, String)>| -> Vec<(PathBuf, Option<String>)> {
+            dependencies.into_iter().map(|(file, module)| (file, Some(module))).collect()
+        };
+        let unnamed = |files: Vec<PathBuf>| -> Vec<(PathBuf, Option<String>)> {
+            files.into_iter().map(|file| (file, None)).collect()
+        };
+        Ok(match self.language {
+            // Python imports name packages and modules, not files
+            Language::Python => named(self.python_dependencies(path, module, ast)),
+            // A Go package is every file of its directory; imports name packages
+            Language::Go => named(self.go_dependencies(path, module, ast)),
+            // `require("a.b")` names a module on Lua's default search path
+            Language::Lua => named(self.lua_dependencies(ast)),
+            // Definitions in a header's source file fold into its declarations
+            Language::Cpp => unnamed(self.cpp_dependencies(path)?),
+            // A Java class sees the rest of its package; imports name classes
+            Language::Java => unnamed(self.java_dependencies(path, module)?),
+            // JavaScript and TypeScript specifiers are resolved from the importing file
+            Language::JavaScript | Language::TypeScript => unnamed(self.javascript_dependencies(path, ast)?),
+            Language::Rust => self.rust_dependencies(path, module, crate_root)?,
+        })
+    }
+
+    /// Files backing a Rust file's `mod name;` declarations, then, unless
+    /// the module tree comes from a Cargo manifest, the files its `use`
+    /// lines name under the root
+    ///
+    /// `use` paths name modules, not files; the resolver binds them.
+    fn rust_dependencies(&self, path: &Path, module: &str, crate_root: bool) -> Result<Vec<(PathBuf, Option<String>)>, String> {
+        let mut dependencies: Vec<(PathBuf, Option<String>)> = self
+            .rust_child_modules(path, module, crate_root)?
+            .into_iter()
+            .map(|(file, module)| (file, Some(module)))
+            .collect();
+        if self.follow_imports {
+            for import_path in self.extract_imports_from_file(path)? {
+                if let Ok(resolved_path) = self.resolve_path(&import_path) {
+                    dependencies.push((resolved_path, None));
+                }
+            }
+        }
+        Ok(dependencies)
+    }
+
+    /// Anchor the Python imports of a module and find the files they load
+    ///
+    /// Relative sources (`..pkg::mod`) are rewritten from the importing
+    /// file's package, which is the module itself for an `__init__.py`. An
+    /// import loads its module, every enclosing package's `__init__.py` and,
+    /// for `from pkg import name`, the submodule `pkg.name` if there is one.
+    /// Directories without `__init__.py` are namespace packages and
+    /// contribute no file of their own. Finally `import a.b` is pointed at
+    /// `a`, the name it binds.
+    fn python_dependencies(&self, path: &Path, module: &str, ast: &mut AbstractAST) -> Vec<(PathBuf, String)> {
+        let is_package = path.file_stem().is_some_and(|stem| stem == "__init__");
+        let mut package: Vec<&str> = module.split("::").collect();
+        if !is_package {
+            package.pop();
+        }
+
+        let mut dependencies = Vec::new();
+        for import in &mut ast.imports {
+            if import.source.starts_with('.') {
+                import.source = anchor_relative(&import.source, &package);
+            }
+
+            let mut targets = vec![import.source.clone()];
+            if let Some(item) = import.name.as_deref().filter(|_| !import.is_glob()) {
+                targets.push(format!("{}::{item}", import.source));
+            }
+            for target in targets {
+                let segments: Vec<&str> = target.split("::").collect();
+                for end in 1..=segments.len() {
+                    let dotted = segments[..end].join("::");
+                    if let Some(file) = self.python_module_file(&dotted) {
+                        dependencies.push((file, dotted));
+                    }
+                }
+            }
+
+            if import.name.is_none() && import.source.starts_with(&format!("{}::", import.alias)) {
+                import.source.clone_from(&import.alias);
+            }
+        }
+        dependencies
+    }
+
+    /// Resolve the JavaScript imports of a file and find the files they load
+    ///
+    /// Import sources that resolve to a local file are pointed at its module
+    /// name. Side-effect imports and `require` calls outside declarations
+    /// are picked up from the source text. Specifiers that resolve through
+    /// `node_modules`, or not at all, are left for the resolver.
+    fn javascript_dependencies(&mut self, path: &Path, ast: &mut AbstractAST) -> Result<Vec<PathBuf>, String> {
+        let mut dependencies = Vec::new();
+        for import in &mut ast.imports {
+            if let Some(file) = self.node_packages.resolve(&import.source, path) {
+                import.source = self.module_name(&file);
+                dependencies.push(file);
+            }
+        }
+
+        if self.follow_imports {
+            for specifier in self.extract_imports_from_file(path)? {
+                if let Some(file) = self.node_packages.resolve(&specifier, path) {
+                    dependencies.push(file);
+                }
+            }
+        }
+        Ok(dependencies)
+    }
+
+    /// Find the files of a Go file's package and of the local packages it imports
+    ///
+    /// The other non-test files of the directory share the package's module
+    /// path. An import path inside the Go module, or one of its local
+    /// `replace` targets, loads every file of that package. An import bound
+    /// to its default name is rebound to the package's declared name, which
+    /// may differ from its directory's.
+    fn go_dependencies(&self, path: &Path, module: &str, ast: &mut AbstractAST) -> Vec<(PathBuf, String)> {
+        let dir = path.parent().unwrap_or_else(|| Path::new(""));
+        let mut dependencies: Vec<(PathBuf, String)> = go_package_files(dir)
+            .into_iter()
+            .map(|file| (file, module.to_string()))
+            .collect();
+
+        let Some(go_module) = &self.go_module else {
+            return dependencies;
+        };
+        for import in &mut ast.imports {
+            let Some(package_dir) = go_module.package_dir(&import.source) else {
+                continue;
+            };
+            let files = go_package_files(&package_dir);
+            if import.alias == GoTranslator::default_package_name(&import.source) {
+                let declared = files
+                    .first()
+                    .and_then(|file| std::fs::read_to_string(file).ok())
+                    .and_then(|source| GoTranslator::new().package_name(&source).ok());
+                if let Some(declared) = declared {
+                    import.alias = declared;
+                }
+            }
+            let package_module = import.source.replace('/', "::");
+            dependencies.extend(files.into_iter().map(|file| (file, package_module.clone())));
+        }
+        dependencies
+    }
+
+    /// Module path of the Go package holding `path`: its import path with
+    /// `::` separators, else its directory relative to the root, else the
+    /// name in its `package` clause. `None` for other languages.
+    fn go_package_module(&self, path: &Path) -> Option<String> {
+        if self.language != Language::Go {
+            return None;
+        }
+        let dir = path.parent()?;
+        if let Some(import_path) = self.go_module.as_ref().and_then(|m| m.import_path(dir)) {
+            return Some(import_path.replace('/', "::"));
+        }
+        let relative: Vec<&str> = dir
+            .strip_prefix(&self.root_path)
+            .ok()?
+            .iter()
+            .filter_map(|segment| segment.to_str())
+            .collect();
+        if relative.is_empty() {
+            let source = std::fs::read_to_string(path).ok()?;
+            GoTranslator::new().package_name(&source).ok()
+        } else {
+            Some(relative.join("::"))
+        }
+    }
+
+    /// Files of the modules a Lua chunk requires, with their module paths
+    ///
+    /// `require("a.b")` is looked up as `a/b.lua`, then `a/b/init.lua`,
+    /// under the root, as Lua's default `./?.lua;./?/init.lua` path does.
+    fn lua_dependencies(&self, ast: &AbstractAST) -> Vec<(PathBuf, String)> {
+        ast.imports
+            .iter()
+            .filter_map(|import| {
+                let base = self.root_path.join(import.source.replace("::", "/"));
+                [base.with_extension("lua"), base.join("init.lua")]
+                    .into_iter()
+                    .find(|candidate| candidate.is_file())
+                    .map(|file| (file, import.source.clone()))
+            })
+            .collect()
+    }
+
+    /// Headers a C or C++ file includes, and the source files implementing
+    /// a header
+    ///
+    /// `#include "x.h"` is looked up beside the including file, then under
+    /// the root and its `include/` directory. A header's definitions are looked for in a source file of
+    /// the same name beside it, or at the same place under `src/` when the
+    /// header sits under `include/`.
+    fn cpp_dependencies(&self, path: &Path) -> Result<Vec<PathBuf>, String> {
+        let dir = path.parent().unwrap_or_else(|| Path::new(""));
+        let mut dependencies: Vec<PathBuf> = self
+            .extract_imports_from_file(path)?
+            .into_iter()
+            .filter_map(|include| {
+                [dir.join(&include), self.root_path.join(&include), self.root_path.join("include").join(&include)]
+                    .into_iter()
+                    .find(|candidate| candidate.is_file())
+                    .map(|file| normalize(&file))
+            })
+            .collect();
+
+        let is_header = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| HEADER_EXTENSIONS.contains(&ext));
+        if is_header {
+            let mut bases = vec![path.to_path_buf()];
+            if let Ok(relative) = path.strip_prefix(&self.root_path) {
+                let mut components: Vec<_> = relative.iter().collect();
+                if let Some(include) = components.iter().position(|c| *c == "include") {
+                    components[include] = "src".as_ref();
+                    bases.push(self.root_path.join(components.iter().collect::<PathBuf>()));
+                }
+            }
+            dependencies.extend(bases.iter().flat_map(|base| {
+                SOURCE_EXTENSIONS
+                    .iter()
+                    .map(move |ext| base.with_extension(ext))
+                    .filter(|candidate| candidate.is_file())
+            }));
+        }
+        Ok(dependencies)
+    }
+
+    /// The other files of a Java file's package, and the files of the
+    /// classes and packages it imports
+    ///
+    /// Imports are looked up under the source root the file sits in (its
+    /// directory without the package's), then under the root's
+    /// `src/main/java` and `src/test/java`, then the root itself.
+    /// `import a.b.C;` loads `a/b/C.java`, or the file of the outermost
+    /// class when `C` is nested; `import a.b.*;` loads every file of
+    /// `a/b`. Static imports load the class their member belongs to.
+    fn java_dependencies(&self, path: &Path, package: &str) -> Result<Vec<PathBuf>, String> {
+        let dir = path.parent().unwrap_or_else(|| Path::new(""));
+        let mut dependencies: Vec<PathBuf> = java_package_files(dir).into_iter().filter(|file| file != path).collect();
+
+        let package: Vec<&str> = package.split("::").filter(|segment| !segment.is_empty()).collect();
+        let own_root = package.iter().rev().try_fold(dir.to_path_buf(), |mut root, segment| {
+            (root.file_name()? == *segment && root.pop()).then_some(root)
+        });
+        let mut roots: Vec<PathBuf> = own_root.into_iter().collect();
+        for root in [self.root_path.join("src/main/java"), self.root_path.join("src/test/java"), self.root_path.clone()] {
+            if root.is_dir() && !roots.contains(&root) {
+                roots.push(root);
+            }
+        }
+
+        for import in self.extract_imports_from_file(path)? {
+            let (name, is_wildcard) = match import.strip_suffix(".*") {
+                Some(name) => (name, true),
+                None => (import.as_str(), false),
+            };
+            let segments: Vec<&str> = name.split('.').collect();
+            for root in &roots {
+                let package_dir = root.join(segments.join("/"));
+                if is_wildcard && package_dir.is_dir() {
+                    dependencies.extend(java_package_files(&package_dir));
+                    break;
+                }
+                let class_file = (1..=segments.len())
+                    .rev()
+                    .map(|len| root.join(format!("{}.java", segments[..len].join("/"))))
+                    .find(|candidate| candidate.is_file());
+                if let Some(class_file) = class_file {
+                    dependencies.push(class_file);
+                    break;
+                }
+            }
+        }
+        Ok(dependencies)
+    }
+
+    /// Module path of the package a Java file declares, empty for the
+    /// default package. `None` for other languages.
+    fn java_package_module(&self, path: &Path) -> Option<String> {
+        if self.language != Language::Java {
+            return None;
+        }
+        let source = std::fs::read_to_string(path).ok()?;
+        JavaTranslator::new().package(&source).ok().map(|package| package.replace('.', "::"))
+    }
+
+    /// The file defining a dotted Python module under the root, if any
+    fn python_module_file(&self, module: &str) -> Option<PathBuf> {
+        if module.is_empty() {
+            return None;
+        }
+        let base = self.root_path.join(module.replace("::", "/"));
+        [base.with_extension("py"), base.join("__init__.py")]
+            .into_iter()
+            .find(|candidate| candidate.is_file())
+    }
+
+    /// Files backing the out-of-line `mod` declarations of a Rust file, with
+    /// their module paths
+    ///
+    /// A crate root (`crate_root`, whatever its module path) or `mod.rs`
+    /// keeps its child modules beside it; any other file keeps them in a
+    /// directory named after it (`net.rs` declares `net/client.rs`). A `#[path]`
+    /// attribute is read relative to the declaring file's directory, or to
+    /// the inline module's directory when nested in one. Declarations
+    /// without a file are skipped.
+    fn rust_child_modules(&self, path: &Path, module: &str, crate_root: bool) -> Result<Vec<(PathBuf, String)>, String> {
         let source = std::fs::read_to_string(path)
             .map_err(|e| format!("Failed to read file: {e}"))?;
+        let modules = RustTranslator::new().module_tree(&source, module)?;
 
-        match self.language {
-            Language::Rust => self.extract_rust_imports(&source),
-            Language::Python => self.extract_python_imports(&source),
-            Language::JavaScript => self.extract_js_imports(&source),
+        let parent = path.parent().unwrap_or_else(|| Path::new(""));
+        let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or_default();
+        let dir = if crate_root || stem == "mod" {
+            parent.to_path_buf()
+        } else {
+            parent.join(stem)
+        };
+
+        let children = modules
+            .into_iter()
+            .filter(|decl| !decl.inline)
+            .filter_map(|decl| {
+                let relative = decl.path.strip_prefix(module)?.strip_prefix("::")?;
+                let candidates = match (&decl.file, relative.rsplit_once("::")) {
+                    (Some(file), None) => vec![parent.join(file)],
+                    (Some(file), Some((inline, _))) => vec![dir.join(inline.replace("::", "/")).join(file)],
+                    (None, _) => {
+                        let base = dir.join(relative.replace("::", "/"));
+                        vec![base.with_extension("rs"), base.join("mod.rs")]
+                    }
+                };
+                candidates
+                    .into_iter()
+                    .find(|candidate| candidate.is_file())
+                    .map(|file| (file, decl.path))
+            })
+            .collect();
+        Ok(children)
+    }
+
+    /// Module name for a file: its path relative to the root, without the
+    /// extension, joined with `::` (`utils/strings.py` becomes `utils::strings`)
+    ///
+    /// Package entry files (`mod.rs`, `__init__.py`, `index.js`, and
+    /// `init.lua` for Lua) are named after their directory.
+    #[must_use]
+    pub fn module_name(&self, path: &Path) -> String {
+        let relative = path.strip_prefix(&self.root_path).unwrap_or(path);
+        let relative = if relative.is_absolute() {
+            // Outside the root: fall back to the file name alone
+            Path::new(relative.file_name().unwrap_or_default())
+        } else {
+            relative
+        };
+
+        let mut segments: Vec<String> = relative
+            .with_extension("")
+            .components()
+            .filter_map(|c| match c {
+                std::path::Component::Normal(segment) => segment.to_str().map(str::to_string),
+                _ => None,
+            })
+            .collect();
+        let is_entry = match segments.last().map(String::as_str) {
+            Some("mod" | "__init__" | "index") => true,
+            Some("init") => self.language == Language::Lua,
+            _ => false,
+        };
+        if segments.len() > 1 && is_entry {
+            segments.pop();
+        }
+
+        if segments.is_empty() {
+            "unknown".to_string()
+        } else {
+            segments.join("::")
         }
     }
 
-    /// Extract import statements from a source file (internal)
-    fn extract_imports(&self, path: &Path) -> Result<Vec<String>, String> {
+    /// Extract import statements from a source file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or if extraction fails.
+    pub fn extract_imports_from_file(&self, path: &Path) -> Result<Vec<String>, String> {
         let source = std::fs::read_to_string(path)
             .map_err(|e| format!("Failed to read file: {e}"))?;
 
         match self.language {
             Language::Rust => self.extract_rust_imports(&source),
             Language::Python => self.extract_python_imports(&source),
-            Language::JavaScript => self.extract_js_imports(&source),
+            Language::JavaScript | Language::TypeScript => self.extract_js_imports(&source),
+            Language::Go => self.extract_go_imports(&source),
+            Language::Lua => self.extract_lua_imports(&source),
+            Language::Cpp => self.extract_cpp_imports(&source),
+            Language::Java => self.extract_java_imports(&source),
         }
     }
 
@@ -156,14 +710,15 @@ impl ModuleLoader {
             if trimmed.starts_with("import ") {
                 let after_import = trimmed.strip_prefix("import ").unwrap_or("");
                 let module = after_import.split(',').next().unwrap_or("").trim();
-                if !module.is_empty() && !module.starts_with('.') {
+                if !module.is_empty() {
                     imports.push(module.to_string());
                 }
             } else if trimmed.starts_with("from ") && trimmed.contains(" import ") {
                 if let Some(module) = trimmed.strip_prefix("from ") {
                     if let Some(module) = module.split(" import ").next() {
+                        // Relative imports keep their dots: `.utils`, `..`
                         let module = module.trim();
-                        if !module.is_empty() && !module.starts_with('.') {
+                        if !module.is_empty() {
                             imports.push(module.to_string());
                         }
                     }
@@ -176,6 +731,10 @@ impl ModuleLoader {
 
     /// Extract JavaScript imports
     ///
+    /// Specifiers are returned as written, relative ones included, from
+    /// `import`/`export ... from`, side-effect `import 'x'`, `require('x')`
+    /// and dynamic `import('x')`.
+    ///
     /// # Errors
     ///
     /// This function currently always succeeds, but returns Result for consistency.
@@ -184,52 +743,25 @@ impl ModuleLoader {
 
         for line in source.lines() {
             let trimmed = line.trim();
-            
-            // Handle: import x from 'path' or import x from "path"
-            if trimmed.starts_with("import ") {
-                if let Some(from_idx) = trimmed.find(" from ") {
-                    let rest = &trimmed[from_idx + 6..];
-                    if let Some(start) = rest.find('\'') {
-                        if let Some(end) = rest[start + 1..].find('\'') {
-                            let path = &rest[start + 1..start + 1 + end];
-                            if !path.starts_with('.') {
-                                imports.push(path.to_string());
-                            } else if path.starts_with("./") {
-                                // Local import like './helper.js'
-                                imports.push(path.strip_prefix("./").unwrap_or(path).to_string());
-                            }
-                        }
-                    } else if let Some(start) = rest.find('"') {
-                        if let Some(end) = rest[start + 1..].find('"') {
-                            let path = &rest[start + 1..start + 1 + end];
-                            if !path.starts_with('.') {
-                                imports.push(path.to_string());
-                            } else if path.starts_with("./") {
-                                imports.push(path.strip_prefix("./").unwrap_or(path).to_string());
-                            }
-                        }
-                    }
+
+            // Handle: import x from 'path', export { x } from "path", import 'path',
+            // and the closing `} from 'path'` of a multi-line import
+            let statement = trimmed.starts_with("import ") || trimmed.starts_with("export ") || trimmed.starts_with('}');
+            if statement {
+                let rest = match trimmed.find(" from ") {
+                    Some(from_idx) => &trimmed[from_idx + 6..],
+                    None => trimmed.strip_prefix("import ").unwrap_or(""),
+                };
+                if let Some(path) = Self::quoted(rest) {
+                    imports.push(path.to_string());
                 }
             }
-            
-            // Handle: require('path') or require("path") - anywhere in the line
-            if trimmed.contains("require(") {
-                if let Some(start) = trimmed.find("require(") {
-                    let rest = &trimmed[start + 8..];
-                    if let Some(quote_start) = rest.find('\'') {
-                        if let Some(quote_end) = rest[quote_start + 1..].find('\'') {
-                            let path = &rest[quote_start + 1..quote_start + 1 + quote_end];
-                            if !path.starts_with('.') {
-                                imports.push(path.to_string());
-                            }
-                        }
-                    } else if let Some(quote_start) = rest.find('"') {
-                        if let Some(quote_end) = rest[quote_start + 1..].find('"') {
-                            let path = &rest[quote_start + 1..quote_start + 1 + quote_end];
-                            if !path.starts_with('.') {
-                                imports.push(path.to_string());
-                            }
-                        }
+
+            // Handle: require('path') and import('path') - anywhere in the line
+            for marker in ["require(", "import("] {
+                for (start, _) in trimmed.match_indices(marker) {
+                    if let Some(path) = Self::quoted(&trimmed[start + marker.len()..]) {
+                        imports.push(path.to_string());
                     }
                 }
             }
@@ -238,12 +770,143 @@ impl ModuleLoader {
         Ok(imports)
     }
 
+    /// Extract Go imports
+    ///
+    /// Import paths are returned as written, from single `import` lines and
+    /// from `import ( ... )` blocks.
+    ///
+    /// # Errors
+    ///
+    /// This function currently always succeeds, but returns Result for consistency.
+    pub fn extract_go_imports(&self, source: &str) -> Result<Vec<String>, String> {
+        let mut imports = Vec::new();
+        let mut in_block = false;
+
+        for line in source.lines() {
+            let trimmed = line.trim();
+            let spec = if in_block {
+                if trimmed.starts_with(')') {
+                    in_block = false;
+                    continue;
+                }
+                trimmed
+            } else if let Some(rest) = trimmed.strip_prefix("import") {
+                let rest = rest.trim_start();
+                if let Some(block) = rest.strip_prefix('(') {
+                    in_block = true;
+                    block.trim_start()
+                } else {
+                    rest
+                }
+            } else {
+                continue;
+            };
+
+            // An optional name (`str`, `.`, `_`) may precede the path
+            let path = spec.find(['"', '`']).and_then(|start| Self::quoted(&spec[start..]));
+            if let Some(path) = path {
+                imports.push(path.to_string());
+            }
+        }
+
+        Ok(imports)
+    }
+
+    /// Extract Lua imports
+    ///
+    /// Module names are returned as written (`a.b`) from `require("a.b")`,
+    /// `require "a.b"` and `require 'a.b'`, anywhere in a line.
+    ///
+    /// # Errors
+    ///
+    /// This function currently always succeeds, but returns Result for consistency.
+    pub fn extract_lua_imports(&self, source: &str) -> Result<Vec<String>, String> {
+        let mut imports = Vec::new();
+
+        for line in source.lines() {
+            let code = line.split("--").next().unwrap_or_default();
+            for (start, _) in code.match_indices("require") {
+                let rest = code[start + "require".len()..].trim_start();
+                let rest = rest.strip_prefix('(').unwrap_or(rest);
+                if let Some(module) = Self::quoted(rest).filter(|module| !module.is_empty()) {
+                    imports.push(module.to_string());
+                }
+            }
+        }
+
+        Ok(imports)
+    }
+
+    /// Extract C and C++ includes
+    ///
+    /// Only `#include "path"` is returned, as written; `#include <path>`
+    /// names a system or third-party header.
+    ///
+    /// # Errors
+    ///
+    /// This function currently always succeeds, but returns Result for consistency.
+    pub fn extract_cpp_imports(&self, source: &str) -> Result<Vec<String>, String> {
+        let mut imports = Vec::new();
+
+        for line in source.lines() {
+            let Some(directive) = line.trim_start().strip_prefix('#') else {
+                continue;
+            };
+            let Some(rest) = directive.trim_start().strip_prefix("include") else {
+                continue;
+            };
+            if let Some(path) = Self::quoted(rest).filter(|_| rest.trim_start().starts_with('"')) {
+                imports.push(path.to_string());
+            }
+        }
+
+        Ok(imports)
+    }
+
+    /// Extract Java imports
+    ///
+    /// Names are returned dotted, as written, with `static` dropped:
+    /// `a.b.C`, `a.b.*`, or `a.b.C.member` for a static import.
+    ///
+    /// # Errors
+    ///
+    /// This function currently always succeeds, but returns Result for consistency.
+    pub fn extract_java_imports(&self, source: &str) -> Result<Vec<String>, String> {
+        let mut imports = Vec::new();
+
+        for line in source.lines() {
+            let Some(rest) = line.trim_start().strip_prefix("import ") else {
+                continue;
+            };
+            let rest = rest.trim_start();
+            let rest = rest.strip_prefix("static ").unwrap_or(rest);
+            if let Some((name, _)) = rest.split_once(';') {
+                imports.push(name.split_whitespace().collect());
+            }
+        }
+
+        Ok(imports)
+    }
+
+    /// The string literal at the start of `text`, without its quotes
+    fn quoted(text: &str) -> Option<&str> {
+        let text = text.trim_start();
+        let quote = text.chars().next().filter(|c| matches!(c, '\'' | '"' | '`'))?;
+        let rest = &text[1..];
+        rest.find(quote).map(|end| &rest[..end])
+    }
+
     /// Resolve an import path to an actual file
     fn resolve_path(&self, import_path: &str) -> Result<PathBuf, String> {
         let extensions = match self.language {
             Language::Rust => vec!["rs"],
             Language::Python => vec!["py"],
             Language::JavaScript => vec!["js", "ts", "jsx", "tsx"],
+            Language::TypeScript => vec!["ts", "tsx", "js", "jsx"],
+            Language::Go => vec!["go"],
+            Language::Lua => vec!["lua"],
+            Language::Cpp => HEADER_EXTENSIONS.iter().chain(&SOURCE_EXTENSIONS).copied().collect(),
+            Language::Java => vec!["java"],
         };
 
         // Try different resolution strategies
@@ -256,13 +919,19 @@ impl ModuleLoader {
 
             // Strategy 2: Module directory with __init__.py or mod.rs
             let init_file = match self.language {
-                Language::Rust => "mod.rs",
-                Language::Python => "__init__.py",
-                Language::JavaScript => "index.js",
+                Language::Rust => Some("mod.rs"),
+                Language::Python => Some("__init__.py"),
+                Language::JavaScript => Some("index.js"),
+                Language::TypeScript => Some("index.ts"),
+                Language::Go => Some("doc.go"),
+                Language::Lua => Some("init.lua"),
+                Language::Cpp | Language::Java => None,
             };
-            let path2 = self.root_path.join(import_path).join(init_file);
-            if path2.exists() {
-                return Ok(path2);
+            if let Some(init_file) = init_file {
+                let path2 = self.root_path.join(import_path).join(init_file);
+                if path2.exists() {
+                    return Ok(path2);
+                }
             }
 
             // Strategy 3: Sibling directory
@@ -276,9 +945,40 @@ impl ModuleLoader {
     }
 }
 
+/// The `.go` files of a package directory, without tests, in name order
+fn go_package_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.is_file()
+                && path.extension().is_some_and(|ext| ext == "go")
+                && !path.to_str().is_some_and(|p| p.ends_with("_test.go"))
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+/// The `.java` files of a package directory, in name order
+fn java_package_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "java"))
+        .collect();
+    files.sort();
+    files
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use trackast_lib::ast::{FunctionCall, FunctionDef, Signature};
 
     #[test]
     fn test_module_loader_new() {
@@ -295,6 +995,15 @@ mod tests {
         assert!(imports.contains(&"mymodule".to_string()));
     }
 
+    #[test]
+    fn test_module_name_is_root_relative() {
+        let loader = ModuleLoader::new("/project", Language::Python);
+        assert_eq!(loader.module_name(Path::new("/project/main.py")), "main");
+        assert_eq!(loader.module_name(Path::new("/project/pkg/util.py")), "pkg::util");
+        assert_eq!(loader.module_name(Path::new("/project/pkg/__init__.py")), "pkg");
+        assert_eq!(loader.module_name(Path::new("/elsewhere/other.py")), "other");
+    }
+
     #[test]
     fn test_extract_python_imports() {
         let loader = ModuleLoader::new(".", Language::Python);
@@ -303,6 +1012,14 @@ mod tests {
         assert!(imports.contains(&"mymodule".to_string()));
     }
 
+    #[test]
+    fn test_extract_python_relative_imports() {
+        let loader = ModuleLoader::new(".", Language::Python);
+        let source = "from . import views\nfrom ..models import User\nfrom .utils.text import slug";
+        let imports = loader.extract_python_imports(source).unwrap();
+        assert_eq!(imports, vec![".", "..models", ".utils.text"]);
+    }
+
     #[test]
     fn test_extract_js_imports() {
         let loader = ModuleLoader::new(".", Language::JavaScript);
@@ -311,6 +1028,89 @@ mod tests {
         assert!(imports.contains(&"mymodule".to_string()));
     }
 
+    #[test]
+    fn test_extract_js_relative_imports() {
+        let loader = ModuleLoader::new(".", Language::JavaScript);
+        let source = "import './polyfill';\nimport { a } from '../lib/a.js';\napp.use(require('./routes'));\nconst m = await import(\"./lazy\");";
+        let imports = loader.extract_js_imports(source).unwrap();
+        assert_eq!(imports, vec!["./polyfill", "../lib/a.js", "./routes", "./lazy"]);
+    }
+
+    #[test]
+    fn test_extract_go_imports() {
+        let loader = ModuleLoader::new(".", Language::Go);
+        let source = "package main\n\nimport \"fmt\"\nimport (\n\tstr \"strings\"\n\t_ \"embed\"\n\t\"example.com/shop/cart\" // carts\n)\n\nfunc main() {}\n";
+        let imports = loader.extract_go_imports(source).unwrap();
+        assert_eq!(imports, vec!["fmt", "strings", "embed", "example.com/shop/cart"]);
+    }
+
+    #[test]
+    fn test_extract_lua_imports() {
+        let loader = ModuleLoader::new(".", Language::Lua);
+        let source = "local json = require(\"lib.json\")\nrequire 'globals'\nlocal m = require \"net.http\" -- require('not.this')";
+        let imports = loader.extract_lua_imports(source).unwrap();
+        assert_eq!(imports, vec!["lib.json", "globals", "net.http"]);
+    }
+
+    #[test]
+    fn test_extract_cpp_imports() {
+        let loader = ModuleLoader::new(".", Language::Cpp);
+        let source = "#include <vector>\n#include \"geo/shapes.h\"\n  #  include \"../util.h\" // helpers\n#define X \"not.h\"\n";
+        let imports = loader.extract_cpp_imports(source).unwrap();
+        assert_eq!(imports, vec!["geo/shapes.h", "../util.h"]);
+    }
+
+    #[test]
+    fn test_extract_java_imports() {
+        let loader = ModuleLoader::new(".", Language::Java);
+        let source = "package com.acme;\nimport java.util.List;\nimport com.acme.util.*;\n  import static com.acme.util.Strings.capitalize;\n// import a.b.C;\n";
+        let imports = loader.extract_java_imports(source).unwrap();
+        assert_eq!(imports, vec!["java.util.List", "com.acme.util.*", "com.acme.util.Strings.capitalize"]);
+    }
+
+    #[test]
+    fn test_lua_init_files_are_named_after_their_directory() {
+        let loader = ModuleLoader::new("/project", Language::Lua);
+        assert_eq!(loader.module_name(Path::new("/project/net/init.lua")), "net");
+        assert_eq!(loader.module_name(Path::new("/project/net/http.lua")), "net::http");
+        let python = ModuleLoader::new("/project", Language::Python);
+        assert_eq!(python.module_name(Path::new("/project/net/init.py")), "net::init");
+    }
+
+    #[test]
+    fn test_dependencies_fold_by_their_own_language() {
+        let loader = ModuleLoader::new(".", Language::Python);
+        let init = |file: &str| {
+            let mut func = FunctionDef::new("init".into(), Signature::empty(), "pkg".into());
+            func.language = Some(Language::Go.as_str().to_string());
+            func.file = Some(file.to_string());
+            func.add_call(FunctionCall::new(format!("setup_{file}"), None, 2));
+            func
+        };
+        let mut ast = AbstractAST::new("app".into());
+        let mut dependency = AbstractAST::new("pkg".into());
+        dependency.add_function(init("a"));
+        dependency.add_function(init("b"));
+        loader.merge_dependency(&mut ast, dependency);
+
+        // Go folds repeated `init` functions, where Python would keep both
+        assert_eq!(ast.functions.len(), 1);
+        assert_eq!(ast.functions[0].calls.len(), 2);
+    }
+
+    #[test]
+    fn test_skipped_dependencies_are_reported() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("main.rs"), "mod bad;\n\nfn main() {}\n").unwrap();
+        std::fs::write(dir.path().join("bad.rs"), [0xff, 0xfe]).unwrap();
+
+        let mut loader = ModuleLoader::new(dir.path(), Language::Rust);
+        let ast = loader.load_all(dir.path().join("main.rs").to_str().unwrap()).unwrap();
+        assert!(ast.get_function("main").is_some());
+        assert_eq!(loader.warnings().len(), 1);
+        assert!(loader.warnings()[0].starts_with(&format!("Skipped {}", dir.path().join("bad.rs").display())));
+    }
+
     #[test]
     fn test_language_specific_loaders() {
         let _rust = ModuleLoader::new(".", Language::Rust);
@@ -0,0 +1,300 @@
+//! Node.js module resolution
+//!
+//! Follows Node's algorithm for the cases that decide which local file an
+//! import names: relative paths with extension probing, `index.*` files,
+//! `package.json` `main` and `exports`, and packages of an npm/yarn/pnpm
+//! workspace, plus `tsconfig.json` path aliases. Anything found through
+//! `node_modules` is third-party code and resolves to nothing, so it stays
+//! external.
+
+use crate::tsconfig::TsConfig;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+
+/// Extensions probed, in order, for a specifier without one
+pub const EXTENSIONS: [&str; 6] = ["js", "mjs", "cjs", "jsx", "ts", "tsx"];
+
+/// Conditions matched in `exports`, in order of preference
+const CONDITIONS: [&str; 4] = ["import", "require", "node", "default"];
+
+/// The local packages and path aliases a bare specifier can name
+#[derive(Debug, Default, Clone)]
+pub struct NodePackages {
+    root: PathBuf,
+    /// Package name to package directory
+    packages: HashMap<String, PathBuf>,
+    /// Parsed `tsconfig.json`/`jsconfig.json` files by path; `None` when
+    /// one cannot be read
+    configs: HashMap<PathBuf, Option<TsConfig>>,
+}
+
+impl NodePackages {
+    /// Find the root package and its workspace packages
+    ///
+    /// `root` is the directory holding the top-level `package.json`.
+    /// Workspaces are read from `workspaces` (a list, or `{ "packages": [...] }`),
+    /// with `dir/*` globs. A missing or unreadable manifest gives no packages.
+    #[must_use]
+    pub fn discover(root: &Path) -> Self {
+        let mut packages = HashMap::new();
+        let Some(manifest) = read_package_json(root) else {
+            return NodePackages {
+                root: root.to_path_buf(),
+                packages,
+                configs: HashMap::new(),
+            };
+        };
+        if let Some(name) = manifest.get("name").and_then(Value::as_str) {
+            packages.insert(name.to_string(), root.to_path_buf());
+        }
+
+        let workspaces = manifest.get("workspaces").map(|workspaces| {
+            workspaces.get("packages").unwrap_or(workspaces)
+        });
+        let patterns = workspaces.and_then(Value::as_array).into_iter().flatten().filter_map(Value::as_str);
+        for dir in patterns.flat_map(|pattern| expand_workspace(root, pattern)) {
+            if let Some(name) = read_package_json(&dir).as_ref().and_then(|m| m.get("name")).and_then(Value::as_str) {
+                packages.insert(name.to_string(), dir);
+            }
+        }
+        NodePackages {
+            root: root.to_path_buf(),
+            packages,
+            configs: HashMap::new(),
+        }
+    }
+
+    /// Directory of a local package
+    #[must_use]
+    pub fn package_dir(&self, name: &str) -> Option<&Path> {
+        self.packages.get(name).map(PathBuf::as_path)
+    }
+
+    /// The file a specifier imported from `importer` resolves to
+    ///
+    /// Relative and absolute specifiers are resolved from the importer's
+    /// directory. Bare specifiers go through the `paths` and `baseUrl` of
+    /// the nearest `tsconfig.json` (or `jsconfig.json`) under the root, then
+    /// local packages. Returns `None` for third-party packages, files under
+    /// `node_modules` and specifiers that name no file.
+    pub fn resolve(&mut self, specifier: &str, importer: &Path) -> Option<PathBuf> {
+        let dir = importer.parent().unwrap_or_else(|| Path::new(""));
+        let file = if is_path_specifier(specifier) {
+            resolve_file_or_directory(&dir.join(specifier))
+        } else {
+            let aliased = self
+                .tsconfig_for(dir)
+                .and_then(|config| config.candidates(specifier).iter().find_map(|c| resolve_file_or_directory(c)));
+            match aliased {
+                Some(file) => Some(file),
+                None => {
+                    let (name, subpath) = split_package_specifier(specifier);
+                    package_entry(self.package_dir(name)?, &subpath)
+                }
+            }
+        }?;
+
+        let file = normalize(&file);
+        let in_node_modules = file
+            .components()
+            .any(|component| component == Component::Normal("node_modules".as_ref()));
+        (!in_node_modules).then_some(file)
+    }
+}
+
+impl NodePackages {
+    /// The config of the nearest directory from `dir` up to the root with a
+    /// `tsconfig.json` or `jsconfig.json`
+    fn tsconfig_for(&mut self, dir: &Path) -> Option<&TsConfig> {
+        let path = dir
+            .ancestors()
+            .take_while(|ancestor| ancestor.starts_with(&self.root))
+            .find_map(TsConfig::find)?;
+        self.configs
+            .entry(path)
+            .or_insert_with_key(|path| TsConfig::load(path).ok())
+            .as_ref()
+    }
+}
+
+/// Whether a specifier is a path (`./x`, `../x`, `/x`) rather than a package
+#[must_use]
+pub fn is_path_specifier(specifier: &str) -> bool {
+    specifier == "." || specifier == ".." || specifier.starts_with("./") || specifier.starts_with("../") || specifier.starts_with('/')
+}
+
+/// Fold `.` and `..` out of a path without touching the file system
+pub(crate) fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            _ => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+/// Split `@scope/pkg/sub/path` into the package name and `./sub/path`
+fn split_package_specifier(specifier: &str) -> (&str, String) {
+    let name_segments = if specifier.starts_with('@') { 2 } else { 1 };
+    let split = specifier
+        .match_indices('/')
+        .nth(name_segments - 1)
+        .map_or(specifier.len(), |(index, _)| index);
+    let (name, rest) = specifier.split_at(split);
+    (name, format!(".{rest}"))
+}
+
+fn read_package_json(dir: &Path) -> Option<Value> {
+    let source = std::fs::read_to_string(dir.join("package.json")).ok()?;
+    serde_json::from_str(&source).ok()
+}
+
+/// Workspace package directories; a trailing `/*` matches every subdirectory
+fn expand_workspace(root: &Path, pattern: &str) -> Vec<PathBuf> {
+    let pattern = pattern.trim_start_matches("./");
+    let Some(parent) = pattern.strip_suffix("/*").or_else(|| pattern.strip_suffix("/**")) else {
+        return vec![root.join(pattern)];
+    };
+    let mut dirs: Vec<PathBuf> = std::fs::read_dir(root.join(parent))
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .collect();
+    dirs.sort();
+    dirs
+}
+
+/// `path` as a file (as is, then with each extension), then as a directory
+fn resolve_file_or_directory(path: &Path) -> Option<PathBuf> {
+    resolve_file(path).or_else(|| resolve_directory(path))
+}
+
+fn resolve_file(path: &Path) -> Option<PathBuf> {
+    if path.is_file() {
+        return Some(path.to_path_buf());
+    }
+    let file_name = path.file_name()?.to_str()?;
+    EXTENSIONS
+        .iter()
+        .map(|ext| path.with_file_name(format!("{file_name}.{ext}")))
+        .find(|candidate| candidate.is_file())
+}
+
+/// A directory's `package.json` `main`, or its `index.*`
+fn resolve_directory(dir: &Path) -> Option<PathBuf> {
+    let main = read_package_json(dir)
+        .as_ref()
+        .and_then(|manifest| manifest.get("main"))
+        .and_then(Value::as_str)
+        .map(|main| dir.join(main));
+    main.and_then(|main| resolve_file(&main).or_else(|| resolve_index(&main)))
+        .or_else(|| resolve_index(dir))
+}
+
+fn resolve_index(dir: &Path) -> Option<PathBuf> {
+    resolve_file(&dir.join("index"))
+}
+
+/// The file `./subpath` of a package names
+///
+/// With an `exports` field only the subpaths it lists are reachable, as in
+/// Node; otherwise `.` is the package's `main` and anything else a path
+/// inside the package.
+fn package_entry(dir: &Path, subpath: &str) -> Option<PathBuf> {
+    let manifest = read_package_json(dir);
+    if let Some(exports) = manifest.as_ref().and_then(|manifest| manifest.get("exports")) {
+        let target = export_target(exports, subpath)?;
+        return resolve_file(&dir.join(target));
+    }
+    if subpath == "." {
+        resolve_directory(dir)
+    } else {
+        resolve_file_or_directory(&dir.join(subpath))
+    }
+}
+
+/// The target `exports` maps a subpath to
+///
+/// `exports` is a single target for `.`, a map of conditions for `.`, or a
+/// map of subpaths (keys starting with `.`, possibly with one `*`) to
+/// targets.
+fn export_target(exports: &Value, subpath: &str) -> Option<String> {
+    let Some(map) = exports.as_object().filter(|map| map.keys().all(|key| key.starts_with('.'))) else {
+        return (subpath == ".").then(|| conditional_target(exports)).flatten();
+    };
+
+    if let Some(target) = map.get(subpath) {
+        return conditional_target(target);
+    }
+    map.iter().find_map(|(key, target)| {
+        let (prefix, suffix) = key.split_once('*')?;
+        let matched = subpath.strip_prefix(prefix)?.strip_suffix(suffix)?;
+        conditional_target(target).map(|target| target.replace('*', matched))
+    })
+}
+
+/// A target string, choosing among conditions and fallback arrays
+fn conditional_target(target: &Value) -> Option<String> {
+    match target {
+        Value::String(path) => Some(path.clone()),
+        Value::Array(targets) => targets.iter().find_map(conditional_target),
+        Value::Object(conditions) => CONDITIONS
+            .iter()
+            .filter_map(|condition| conditions.get(*condition))
+            .find_map(conditional_target),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_split_package_specifier() {
+        assert_eq!(split_package_specifier("lodash"), ("lodash", ".".to_string()));
+        assert_eq!(split_package_specifier("lodash/fp"), ("lodash", "./fp".to_string()));
+        assert_eq!(split_package_specifier("@acme/ui"), ("@acme/ui", ".".to_string()));
+        assert_eq!(split_package_specifier("@acme/ui/button"), ("@acme/ui", "./button".to_string()));
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize(Path::new("/app/src/lib/../util/./pad.js")), PathBuf::from("/app/src/util/pad.js"));
+    }
+
+    #[test]
+    fn test_is_path_specifier() {
+        assert!(is_path_specifier("./a"));
+        assert!(is_path_specifier("../a"));
+        assert!(is_path_specifier(".."));
+        assert!(!is_path_specifier("a"));
+        assert!(!is_path_specifier(".bin"));
+    }
+
+    #[test]
+    fn test_export_targets() {
+        let exports = json!({
+            ".": { "types": "./index.d.ts", "import": "./esm/index.js", "default": "./cjs/index.js" },
+            "./utils": "./src/utils.js",
+            "./feature/*": { "require": "./src/feature/*.js" }
+        });
+        assert_eq!(export_target(&exports, ".").as_deref(), Some("./esm/index.js"));
+        assert_eq!(export_target(&exports, "./utils").as_deref(), Some("./src/utils.js"));
+        assert_eq!(export_target(&exports, "./feature/a").as_deref(), Some("./src/feature/a.js"));
+        assert_eq!(export_target(&exports, "./internal"), None);
+
+        assert_eq!(export_target(&json!("./main.js"), ".").as_deref(), Some("./main.js"));
+        assert_eq!(export_target(&json!({ "default": "./main.js" }), ".").as_deref(), Some("./main.js"));
+        assert_eq!(export_target(&json!("./main.js"), "./other"), None);
+    }
+}
@@ -0,0 +1,202 @@
+//! Mapping patches onto the functions they change
+//!
+//! A unified diff, read from a file or produced by
+//! [`git_diff`](crate::git::git_diff) in the local checkout, is reduced to
+//! the lines it changes in the new version of each file. Those lines are
+//! then matched against the spans of the graph's `FunctionDef`s, so a
+//! change impact report can start from the functions a patch touches.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use trackast_lib::function_id::FunctionId;
+use trackast_lib::graph::CallGraph;
+
+pub use crate::git::{git_diff, git_toplevel};
+
+/// The lines a patch changes in one file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileChange {
+    /// Path of the new version, as the patch names it
+    pub path: PathBuf,
+    /// Changed line numbers of the new version, 1-based, sorted
+    ///
+    /// An added line counts itself; a deleted one counts the lines on either
+    /// side of the gap it leaves, so a removal at the end of a body still
+    /// touches the function.
+    pub lines: Vec<usize>,
+}
+
+/// Parse a unified diff, as written by `git diff` or `diff -u`
+///
+/// Deleted files are skipped: their functions are not in the new version.
+///
+/// # Errors
+///
+/// Returns an error if a hunk header is malformed.
+pub fn parse_unified_diff(patch: &str) -> Result<Vec<FileChange>, String> {
+    let mut changes: Vec<FileChange> = Vec::new();
+    let mut old_path: Option<&str> = None;
+    let mut current: Option<usize> = None;
+    // Lines left in the current hunk, old and new side
+    let (mut old_left, mut new_left) = (0_usize, 0_usize);
+    let mut new_line = 0;
+
+    for line in patch.lines() {
+        if old_left > 0 || new_left > 0 {
+            // Hunks of a deleted file are counted through but not recorded
+            let mut deleted_file = Vec::new();
+            let lines = match current {
+                Some(index) => &mut changes[index].lines,
+                None => &mut deleted_file,
+            };
+            match line.as_bytes().first() {
+                Some(b'+') => {
+                    lines.push(new_line);
+                    new_line += 1;
+                    new_left = new_left.saturating_sub(1);
+                }
+                Some(b'-') => {
+                    lines.extend([new_line.saturating_sub(1), new_line].into_iter().filter(|&line| line > 0));
+                    old_left = old_left.saturating_sub(1);
+                }
+                Some(b'\\') => {}
+                _ => {
+                    new_line += 1;
+                    old_left = old_left.saturating_sub(1);
+                    new_left = new_left.saturating_sub(1);
+                }
+            }
+        } else if let Some(path) = line.strip_prefix("--- ") {
+            old_path = Some(path);
+        } else if let Some(path) = line.strip_prefix("+++ ") {
+            let path = path.split('\t').next().unwrap_or(path);
+            current = None;
+            if path != "/dev/null" {
+                // git prefixes the old side with a/ and the new one with b/
+                let prefixed = old_path.is_some_and(|old| old.starts_with("a/") || old.starts_with("/dev/null"));
+                let path = if prefixed { path.strip_prefix("b/").unwrap_or(path) } else { path };
+                changes.push(FileChange {
+                    path: PathBuf::from(path),
+                    lines: Vec::new(),
+                });
+                current = Some(changes.len() - 1);
+            }
+        } else if let Some(header) = line.strip_prefix("@@ ") {
+            let (old, new) = parse_hunk_header(header).ok_or_else(|| format!("Malformed hunk header: {line}"))?;
+            (old_left, new_left) = (old.1, new.1);
+            // An empty side names the line before the hunk
+            new_line = if new.1 == 0 { new.0 + 1 } else { new.0 };
+            if current.is_none() && new.1 > 0 {
+                return Err(format!("Hunk without a file header: {line}"));
+            }
+        }
+    }
+
+    for change in &mut changes {
+        change.lines.sort_unstable();
+        change.lines.dedup();
+    }
+    changes.retain(|change| !change.lines.is_empty());
+    Ok(changes)
+}
+
+/// Parse `-start[,count] +start[,count] @@`
+fn parse_hunk_header(header: &str) -> Option<((usize, usize), (usize, usize))> {
+    let mut ranges = header.split_whitespace();
+    let old = parse_range(ranges.next()?.strip_prefix('-')?)?;
+    let new = parse_range(ranges.next()?.strip_prefix('+')?)?;
+    Some((old, new))
+}
+
+fn parse_range(range: &str) -> Option<(usize, usize)> {
+    match range.split_once(',') {
+        Some((start, count)) => Some((start.parse().ok()?, count.parse().ok()?)),
+        None => Some((range.parse().ok()?, 1)),
+    }
+}
+
+/// The functions whose definitions contain a changed line
+///
+/// Patch paths are taken relative to `base`; definition files relative to
+/// the working directory, as the loader records them. Both are
+/// canonicalized before comparing. Functions without a span (module-level
+/// code, external functions) are never matched.
+#[must_use]
+pub fn changed_functions(graph: &CallGraph, changes: &[FileChange], base: &Path) -> Vec<FunctionId> {
+    let changed_lines: HashMap<PathBuf, &[usize]> = changes
+        .iter()
+        .filter_map(|change| Some((base.join(&change.path).canonicalize().ok()?, change.lines.as_slice())))
+        .collect();
+
+    let mut canonical: HashMap<&str, Option<PathBuf>> = HashMap::new();
+    let mut functions: Vec<FunctionId> = graph
+        .nodes
+        .values()
+        .filter(|node| {
+            let (Some(file), Some(span)) = (&node.metadata.file, &node.metadata.span) else {
+                return false;
+            };
+            let path = canonical
+                .entry(file.as_str())
+                .or_insert_with(|| Path::new(file).canonicalize().ok());
+            path.as_ref()
+                .and_then(|path| changed_lines.get(path))
+                .is_some_and(|lines| lines.iter().any(|line| (span.start_line..=span.end_line).contains(line)))
+        })
+        .map(|node| node.id.clone())
+        .collect();
+    functions.sort();
+    functions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_git_diff() {
+        let patch = "\
+diff --git a/src/app.py b/src/app.py
+index 1111111..2222222 100644
+--- a/src/app.py
++++ b/src/app.py
+@@ -3,2 +3,3 @@ def main():
+ context
+-old
++new
++added
+@@ -20,0 +22,1 @@
++inserted
+diff --git a/gone.py b/gone.py
+deleted file mode 100644
+--- a/gone.py
++++ /dev/null
+@@ -1,2 +0,0 @@
+-def gone():
+-    pass
+diff --git a/lib.rs b/lib.rs
+--- a/lib.rs
++++ b/lib.rs
+@@ -10,1 +9,0 @@
+-    removed();
+";
+        let changes = parse_unified_diff(patch).unwrap();
+        assert_eq!(
+            changes,
+            [
+                FileChange { path: PathBuf::from("src/app.py"), lines: vec![3, 4, 5, 22] },
+                FileChange { path: PathBuf::from("lib.rs"), lines: vec![9, 10] },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_plain_diff_keeps_paths() {
+        let patch = "--- old/b/x.py\t2024-01-01\n+++ b/x.py\t2024-01-02\n@@ -1 +1 @@\n-a\n+b\n";
+        let changes = parse_unified_diff(patch).unwrap();
+        assert_eq!(changes[0].path, PathBuf::from("b/x.py"));
+        assert_eq!(changes[0].lines, [1]);
+        assert!(parse_unified_diff("--- a/x\n+++ b/x\n@@ -x +1 @@\n").is_err());
+    }
+}
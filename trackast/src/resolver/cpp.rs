@@ -0,0 +1,79 @@
+use trackast_lib::ast::{FunctionCall, FunctionDef};
+use trackast_lib::builder::{enclosing, join, SymbolTable};
+
+use super::{bind_in_enclosing_scopes, Resolver};
+
+/// Resolver for C and C++
+///
+/// Modules are namespaces and classes, and names are looked up from the
+/// caller's scope outwards: a method sees its class, then the enclosing
+/// namespaces, then the global namespace, along with the `using`
+/// directives and base classes of each. Paths in `using` directives and
+/// base class lists are resolved the same way; a leading `::` makes them
+/// absolute.
+pub struct CppResolver;
+
+impl CppResolver {
+    #[must_use]
+    pub fn new() -> Self {
+        CppResolver
+    }
+}
+
+impl Default for CppResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Resolver for CppResolver {
+    fn normalize_path(&self, path: &str, module: &str, symbols: &SymbolTable) -> String {
+        if let Some(absolute) = path.strip_prefix("::") {
+            return absolute.to_string();
+        }
+        let mut scope = Some(module);
+        while let Some(prefix) = scope {
+            let candidate = join(prefix, path);
+            if symbols.has_module(&candidate) {
+                return candidate;
+            }
+            scope = enclosing(prefix);
+        }
+        path.to_string()
+    }
+
+    fn bind_call(&self, caller: &FunctionDef, call: &FunctionCall, symbols: &SymbolTable) -> Option<(String, String)> {
+        bind_in_enclosing_scopes(caller, call, symbols)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use trackast_lib::ast::{AbstractAST, Import, Signature};
+
+    #[test]
+    fn test_names_are_looked_up_through_enclosing_scopes_and_bases() {
+        let mut ast = AbstractAST::new("main".to_string());
+        let mut area = FunctionDef::new("area".into(), Signature::empty(), "geo::Circle".into());
+        area.add_call(FunctionCall::new("pi".into(), None, 2));
+        area.add_call(FunctionCall::new("describe".into(), None, 3));
+        ast.add_function(area);
+        let mut main = FunctionDef::new("main".into(), Signature::empty(), String::new());
+        main.add_call(FunctionCall::new("describe".into(), None, 5).with_qualifier("Circle".into()));
+        main.add_call(FunctionCall::new("sort".into(), None, 6).with_qualifier("std".into()));
+        ast.add_function(main);
+        ast.add_function(FunctionDef::new("pi".into(), Signature::empty(), "geo".into()));
+        ast.add_function(FunctionDef::new("describe".into(), Signature::empty(), "geo::Shape".into()));
+        ast.add_import(Import::glob("geo::Circle".into(), "Shape".into()));
+        ast.add_import(Import::glob(String::new(), "geo".into()));
+
+        assert_eq!(CppResolver::new().resolve(&mut ast), 3);
+        assert_eq!(ast.imports[0].source, "geo::Shape");
+        let target = |func: usize, call: usize| ast.functions[func].calls[call].target_module.clone();
+        assert_eq!(target(0, 0).as_deref(), Some("geo"));
+        assert_eq!(target(0, 1).as_deref(), Some("geo::Shape"));
+        assert_eq!(target(1, 0).as_deref(), Some("geo::Shape"));
+        assert_eq!(target(1, 1), None);
+    }
+}
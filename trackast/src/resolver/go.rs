@@ -0,0 +1,114 @@
+use trackast_lib::ast::{FunctionCall, FunctionDef};
+use trackast_lib::builder::SymbolTable;
+
+use super::Resolver;
+use crate::translators::go::GoTranslator;
+
+/// Resolver for Go packages
+///
+/// Import paths (`example.com/shop/cart`) become module paths
+/// (`example.com::shop::cart`), which is how the module loader names the
+/// packages it finds through `go.mod`. Calls are then bound by the shared
+/// rules: a package's own functions, `pkg.Func()` through the import, and
+/// `recv.Method()` through the receiver's type. A local initialized from a
+/// call (`c := cart.New()`) takes its type from the callee's return type.
+pub struct GoResolver;
+
+impl GoResolver {
+    #[must_use]
+    pub fn new() -> Self {
+        GoResolver
+    }
+}
+
+impl Default for GoResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Resolver for GoResolver {
+    fn normalize_path(&self, path: &str, _module: &str, _symbols: &SymbolTable) -> String {
+        path.replace('/', "::")
+    }
+
+    fn bind_call(&self, caller: &FunctionDef, call: &FunctionCall, symbols: &SymbolTable) -> Option<(String, String)> {
+        let qualifier = call.qualifier.as_deref().and_then(|q| q.strip_suffix("()"));
+        let Some(producer) = qualifier.filter(|_| call.target_module.is_none()) else {
+            return symbols
+                .resolve_target(&caller.module, call)
+                .map(|(module, name)| (module.to_string(), name.to_string()));
+        };
+
+        // The local's type is named relative to the package defining the
+        // function it came from
+        let (path, name) = producer.rsplit_once("::").map_or((None, producer), |(path, name)| (Some(path), name));
+        let mut producer = FunctionCall::new(name.to_string(), None, call.line);
+        producer.qualifier = path.map(str::to_string);
+        let (module, name) = symbols.resolve_target(&caller.module, &producer)?;
+        let ty = GoTranslator::named_type(first_result(symbols.return_type(module, name)?))?;
+        let method = FunctionCall {
+            qualifier: Some(ty),
+            ..call.clone()
+        };
+        symbols
+            .resolve_target(module, &method)
+            .map(|(module, name)| (module.to_string(), name.to_string()))
+    }
+}
+
+/// The type of the first result in a Go result list: `(*Cart, error)` and
+/// `(c *Cart, err error)` give `*Cart`
+fn first_result(results: &str) -> &str {
+    let inner = results.strip_prefix('(').and_then(|r| r.strip_suffix(')')).unwrap_or(results);
+    let first = inner.split(',').next().unwrap_or(inner).trim();
+    first.rsplit(' ').next().unwrap_or(first)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use trackast_lib::ast::{AbstractAST, FunctionCall, FunctionDef, Import, Signature};
+
+    #[test]
+    fn test_calls_through_import_paths_bind() {
+        let mut ast = AbstractAST::new("example.com::shop".to_string());
+        let mut main = FunctionDef::new("main".into(), Signature::empty(), "example.com::shop".into());
+        main.add_call(FunctionCall::new("New".into(), None, 2).with_qualifier("cart".into()));
+        main.add_call(FunctionCall::new("Println".into(), None, 3).with_qualifier("fmt".into()));
+        ast.add_function(main);
+        ast.add_function(FunctionDef::new("New".into(), Signature::empty(), "example.com::shop::cart".into()));
+        ast.add_import(Import::module("example.com::shop".into(), "example.com/shop/cart".into(), "cart".into()));
+        ast.add_import(Import::module("example.com::shop".into(), "fmt".into(), "fmt".into()));
+
+        assert_eq!(GoResolver::new().resolve(&mut ast), 1);
+        let calls = &ast.get_function("main").unwrap().calls;
+        assert_eq!(calls[0].target_module.as_deref(), Some("example.com::shop::cart"));
+        assert_eq!(calls[1].target_module, None);
+    }
+
+    #[test]
+    fn test_methods_on_locals_bind_through_return_types() {
+        let mut ast = AbstractAST::new("example.com::shop".to_string());
+        let mut main = FunctionDef::new("main".into(), Signature::empty(), "example.com::shop".into());
+        main.add_call(FunctionCall::new("Add".into(), None, 3).with_qualifier("cart::New()".into()));
+        main.add_call(FunctionCall::new("Add".into(), None, 4).with_qualifier("cart::Load()".into()));
+        ast.add_function(main);
+        let cart = || "example.com::shop::cart".to_string();
+        ast.add_function(FunctionDef::new("New".into(), Signature::new(vec![], "*Cart".into()), cart()));
+        ast.add_function(FunctionDef::new("Load".into(), Signature::new(vec![], "(c *Cart, err error)".into()), cart()));
+        ast.add_function(FunctionDef::new("Cart::Add".into(), Signature::empty(), cart()));
+        ast.add_import(Import::module("example.com::shop".into(), "example.com/shop/cart".into(), "cart".into()));
+
+        assert_eq!(GoResolver::new().resolve(&mut ast), 2);
+        let calls = &ast.get_function("main").unwrap().calls;
+        assert!(calls.iter().all(|call| call.target_name == "Cart::Add"));
+    }
+
+    #[test]
+    fn test_first_result() {
+        assert_eq!(first_result("*Cart"), "*Cart");
+        assert_eq!(first_result("(*Cart, error)"), "*Cart");
+        assert_eq!(first_result("(c *Cart, err error)"), "*Cart");
+    }
+}
@@ -0,0 +1,82 @@
+use trackast_lib::ast::{FunctionCall, FunctionDef};
+use trackast_lib::builder::{enclosing, join, SymbolTable};
+
+use super::{bind_in_enclosing_scopes, Resolver};
+
+/// Resolver for Java
+///
+/// Modules are packages and classes. A method sees the members of its
+/// class, then those of the classes enclosing it, each with the classes it
+/// extends or implements; a class name is looked up in the enclosing
+/// classes, the package, and the packages imported with `.*`. Supertypes
+/// the translator left as simple names are resolved the same way.
+pub struct JavaResolver;
+
+impl JavaResolver {
+    #[must_use]
+    pub fn new() -> Self {
+        JavaResolver
+    }
+}
+
+impl Default for JavaResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Resolver for JavaResolver {
+    fn normalize_path(&self, path: &str, module: &str, symbols: &SymbolTable) -> String {
+        let mut scope = Some(module);
+        while let Some(prefix) = scope {
+            let candidate = join(prefix, path);
+            if symbols.has_module(&candidate) {
+                return candidate;
+            }
+            // Wildcard imports come before the classes of a file, so they
+            // are in the table by the time its supertypes are normalized
+            let wildcard = symbols
+                .imports_of(prefix)
+                .iter()
+                .filter(|import| import.is_glob())
+                .map(|import| join(&import.source, path))
+                .find(|candidate| symbols.has_module(candidate));
+            if let Some(candidate) = wildcard {
+                return candidate;
+            }
+            scope = enclosing(prefix);
+        }
+        path.to_string()
+    }
+
+    fn bind_call(&self, caller: &FunctionDef, call: &FunctionCall, symbols: &SymbolTable) -> Option<(String, String)> {
+        bind_in_enclosing_scopes(caller, call, symbols)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use trackast_lib::ast::{AbstractAST, Import, Signature};
+
+    #[test]
+    fn test_supertypes_and_receivers_are_found_through_package_and_wildcards() {
+        let mut ast = AbstractAST::new("shop".to_string());
+        let mut checkout = FunctionDef::new("checkout".into(), Signature::empty(), "shop::App".into());
+        checkout.add_call(FunctionCall::new("total".into(), None, 2).with_qualifier("Cart".into()));
+        checkout.add_call(FunctionCall::new("log".into(), None, 3));
+        checkout.add_call(FunctionCall::new("trim".into(), None, 4).with_qualifier("Strings".into()));
+        ast.add_function(checkout);
+        ast.add_function(FunctionDef::new("total".into(), Signature::empty(), "shop::Cart".into()));
+        ast.add_function(FunctionDef::new("log".into(), Signature::empty(), "util::Base".into()));
+        ast.add_function(FunctionDef::new("trim".into(), Signature::empty(), "util::Strings".into()));
+        ast.add_import(Import::glob("shop::App".into(), "util".into()));
+        ast.add_import(Import::glob("shop::App".into(), "Base".into()));
+
+        let bound = JavaResolver::new().resolve(&mut ast);
+        assert_eq!(bound, 3);
+        assert_eq!(ast.imports[1].source, "util::Base");
+        let targets: Vec<Option<&str>> = ast.functions[0].calls.iter().map(|c| c.target_module.as_deref()).collect();
+        assert_eq!(targets, [Some("shop::Cart"), Some("util::Base"), Some("util::Strings")]);
+    }
+}
@@ -0,0 +1,108 @@
+use trackast_lib::ast::{FunctionCall, FunctionDef};
+use trackast_lib::builder::SymbolTable;
+
+use super::Resolver;
+use crate::node::{is_path_specifier, EXTENSIONS};
+
+/// Resolver for JavaScript modules
+///
+/// On top of the shared import rules it binds default imports and maps
+/// specifiers the module loader could not resolve to module paths. Exports
+/// are not tracked, so `import greet from './greeter'` is assumed to name a
+/// function `greet` defined in `greeter`.
+pub struct JavaScriptResolver;
+
+impl JavaScriptResolver {
+    #[must_use]
+    pub fn new() -> Self {
+        JavaScriptResolver
+    }
+}
+
+impl Default for JavaScriptResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Resolver for JavaScriptResolver {
+    fn normalize_path(&self, path: &str, module: &str, _symbols: &SymbolTable) -> String {
+        specifier_module(path, module)
+    }
+
+    fn bind_call(&self, caller: &FunctionDef, call: &FunctionCall, symbols: &SymbolTable) -> Option<(String, String)> {
+        symbols
+            .resolve_target(&caller.module, call)
+            .map(|(module, name)| (module.to_string(), name.to_string()))
+            .or_else(|| {
+                if call.qualifier.is_some() || call.target_module.is_some() {
+                    return None;
+                }
+                symbols
+                    .imports_of(&caller.module)
+                    .iter()
+                    .filter(|import| import.name.as_deref() == Some("default") && import.alias == call.target_name)
+                    .find(|import| symbols.contains(&import.source, &import.alias))
+                    .map(|import| (import.source.clone(), import.alias.clone()))
+            })
+    }
+}
+
+/// Module path for an import specifier written in `module`
+///
+/// Relative specifiers are read from the importer's directory, assuming it
+/// is not an `index` file: in `app::main`, `../lib/util.js` becomes
+/// `lib::util`. Package specifiers keep their path (`lodash/fp` becomes
+/// `lodash::fp`) and module paths are returned unchanged.
+#[must_use]
+pub fn specifier_module(specifier: &str, module: &str) -> String {
+    let path = EXTENSIONS
+        .iter()
+        .find_map(|ext| specifier.strip_suffix(ext).and_then(|rest| rest.strip_suffix('.')))
+        .unwrap_or(specifier);
+    if !is_path_specifier(path) {
+        return path.replace('/', "::");
+    }
+
+    let mut segments: Vec<&str> = module.split("::").collect();
+    segments.pop();
+    for segment in path.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop();
+            }
+            _ => segments.push(segment),
+        }
+    }
+    segments.join("::")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use trackast_lib::ast::{Import, Signature};
+
+    #[test]
+    fn test_default_import_binds_to_named_function() {
+        let mut symbols = SymbolTable::new();
+        symbols.add_function(&FunctionDef::new("greet".into(), Signature::empty(), "greeter".into()));
+        symbols.add_import(Import::item("app".into(), "greeter".into(), "default".into(), "greet".into()));
+        let caller = FunctionDef::new("main".into(), Signature::empty(), "app".into());
+        let call = FunctionCall::new("greet".into(), None, 2);
+
+        assert_eq!(
+            JavaScriptResolver::new().bind_call(&caller, &call, &symbols),
+            Some(("greeter".into(), "greet".into()))
+        );
+    }
+
+    #[test]
+    fn test_specifier_module() {
+        assert_eq!(specifier_module("./greeter.js", "main"), "greeter");
+        assert_eq!(specifier_module("./lib/util", "app::main"), "app::lib::util");
+        assert_eq!(specifier_module("../lib/util.mjs", "app::main"), "lib::util");
+        assert_eq!(specifier_module("lodash/fp", "main"), "lodash::fp");
+        assert_eq!(specifier_module("lib::util", "main"), "lib::util");
+    }
+}
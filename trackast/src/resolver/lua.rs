@@ -0,0 +1,23 @@
+use super::Resolver;
+
+/// Resolver for Lua modules
+///
+/// The translator already names functions on a module's returned table
+/// plainly and binds `self` calls, and `require` gives module paths, so the
+/// shared import rules are all Lua needs.
+pub struct LuaResolver;
+
+impl LuaResolver {
+    #[must_use]
+    pub fn new() -> Self {
+        LuaResolver
+    }
+}
+
+impl Default for LuaResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Resolver for LuaResolver {}
@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+use trackast_lib::ast::{AbstractAST, FunctionCall, FunctionDef};
+use trackast_lib::builder::SymbolTable;
+
+use super::{get_resolver, Resolver};
+use crate::language::Language;
+
+/// Resolver for an AST whose functions are written in several languages
+///
+/// Every function shares one symbol table, so a call binds across languages
+/// when its target's module path matches, as with a Python module importing
+/// a Rust extension of the same name. Each call is bound by the resolver of
+/// its caller's language, and each import source is normalized by the
+/// resolver of its module's language. Functions without a language tag,
+/// and imports of modules without functions, fall back to the
+/// language-agnostic rules.
+pub struct MixedResolver {
+    resolvers: HashMap<Language, Box<dyn Resolver>>,
+    /// Language of each module, from the functions it defines
+    module_languages: HashMap<String, Language>,
+}
+
+impl MixedResolver {
+    /// Create a resolver for the languages the functions of `asts` are
+    /// tagged with
+    #[must_use]
+    pub fn new(asts: &[AbstractAST]) -> Self {
+        let mut resolvers = HashMap::new();
+        let mut module_languages = HashMap::new();
+        for func in asts.iter().flat_map(|ast| &ast.functions) {
+            if let Some(language) = func.language.as_deref().and_then(Language::from_name) {
+                resolvers.entry(language).or_insert_with(|| get_resolver(language));
+                module_languages.entry(func.module.clone()).or_insert(language);
+            }
+        }
+        MixedResolver {
+            resolvers,
+            module_languages,
+        }
+    }
+
+    /// The languages the AST mixes, in a stable order
+    #[must_use]
+    pub fn languages(&self) -> Vec<Language> {
+        Language::ALL
+            .into_iter()
+            .filter(|language| self.resolvers.contains_key(language))
+            .collect()
+    }
+}
+
+impl Resolver for MixedResolver {
+    fn normalize_path(&self, path: &str, module: &str, symbols: &SymbolTable) -> String {
+        match self.module_languages.get(module).and_then(|language| self.resolvers.get(language)) {
+            Some(resolver) => resolver.normalize_path(path, module, symbols),
+            None => path.to_string(),
+        }
+    }
+
+    fn bind_call(&self, caller: &FunctionDef, call: &FunctionCall, symbols: &SymbolTable) -> Option<(String, String)> {
+        let language = caller.language.as_deref().and_then(Language::from_name);
+        match language.and_then(|language| self.resolvers.get(&language)) {
+            Some(resolver) => resolver.bind_call(caller, call, symbols),
+            None => symbols
+                .resolve_target(&caller.module, call)
+                .map(|(module, name)| (module.to_string(), name.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use trackast_lib::ast::{Import, Signature};
+
+    #[test]
+    fn test_calls_bind_by_their_callers_language() {
+        let mut ast = AbstractAST::new("app".to_string());
+        let mut main = FunctionDef::new("main".into(), Signature::empty(), "app".into()).with_language("Python");
+        main.add_call(FunctionCall::new("add".into(), None, 3).with_qualifier("fastmath".into()));
+        ast.add_function(main);
+        let mut add = FunctionDef::new("add".into(), Signature::empty(), "fastmath".into()).with_language("Rust");
+        add.add_call(FunctionCall::new("checked".into(), None, 2));
+        ast.add_function(add);
+        ast.add_function(FunctionDef::new("checked".into(), Signature::empty(), "fastmath".into()).with_language("Rust"));
+        ast.add_import(Import::module("app".into(), "fastmath".into(), "fastmath".into()));
+
+        let resolver = MixedResolver::new(std::slice::from_ref(&ast));
+        assert_eq!(resolver.languages(), [Language::Rust, Language::Python]);
+        assert_eq!(resolver.resolve(&mut ast), 2);
+        assert_eq!(ast.functions[0].calls[0].target_module.as_deref(), Some("fastmath"));
+        assert_eq!(ast.functions[1].calls[0].target_module.as_deref(), Some("fastmath"));
+    }
+}
@@ -1,3 +1,193 @@
+//! Language-specific call resolution
+//!
+//! A [`Resolver`] runs between translation and graph construction. It binds
+//! each call to the definition it refers to by setting the call's
+//! `target_module` and `target_name`, using the module's imports and the
+//! language's scoping rules. Calls it cannot bind are left untouched and
+//! become external nodes when the graph is built.
+
+pub mod cpp;
+pub mod go;
+pub mod java;
+pub mod javascript;
+pub mod lua;
+pub mod mixed;
+pub mod python;
 pub mod rust;
 
-pub use rust::resolve_call;
+pub use cpp::CppResolver;
+pub use go::GoResolver;
+pub use java::JavaResolver;
+pub use javascript::JavaScriptResolver;
+pub use lua::LuaResolver;
+pub use mixed::MixedResolver;
+pub use python::PythonResolver;
+pub use rust::{resolve_call, RustResolver};
+
+use crate::language::Language;
+use trackast_lib::ast::{AbstractAST, FunctionCall, FunctionDef};
+use trackast_lib::builder::{enclosing, join, SymbolTable};
+
+/// Trait for language-specific call resolvers
+pub trait Resolver {
+    /// Rewrite an import source, as written in `module`, into a module path
+    ///
+    /// `symbols` holds every definition but no imports yet. The default
+    /// keeps the path unchanged.
+    fn normalize_path(&self, path: &str, _module: &str, _symbols: &SymbolTable) -> String {
+        path.to_string()
+    }
+
+    /// Bind a call made by `caller`, returning the definition's `(module, name)`
+    ///
+    /// The default applies the language-agnostic rules of
+    /// [`SymbolTable::resolve_target`].
+    fn bind_call(&self, caller: &FunctionDef, call: &FunctionCall, symbols: &SymbolTable) -> Option<(String, String)> {
+        symbols
+            .resolve_target(&caller.module, call)
+            .map(|(module, name)| (module.to_string(), name.to_string()))
+    }
+
+    /// Bind every call in `ast` and return how many were bound
+    fn resolve(&self, ast: &mut AbstractAST) -> usize {
+        self.resolve_all(std::slice::from_mut(ast))
+    }
+
+    /// Bind every call in `asts` against the definitions and imports of all
+    /// of them, as when each file of a scanned tree is its own AST, and
+    /// return how many were bound
+    fn resolve_all(&self, asts: &mut [AbstractAST]) -> usize {
+        let mut symbols = SymbolTable::new();
+        for func in asts.iter().flat_map(|ast| &ast.functions) {
+            symbols.add_function(func);
+        }
+        for import in asts.iter_mut().flat_map(|ast| &mut ast.imports) {
+            import.source = self.normalize_path(&import.source, &import.module, &symbols);
+            symbols.add_import(import.clone());
+        }
+
+        let bindings: Vec<Vec<Option<(String, String)>>> = asts
+            .iter()
+            .flat_map(|ast| &ast.functions)
+            .map(|func| {
+                func.calls
+                    .iter()
+                    .map(|call| self.bind_call(func, call, &symbols))
+                    .collect()
+            })
+            .collect();
+
+        let mut bound = 0;
+        let functions = asts.iter_mut().flat_map(|ast| &mut ast.functions);
+        for (func, func_bindings) in functions.zip(bindings) {
+            for (call, binding) in func.calls.iter_mut().zip(func_bindings) {
+                if let Some((module, name)) = binding {
+                    call.target_module = Some(module);
+                    call.target_name = name;
+                    bound += 1;
+                }
+            }
+        }
+        bound
+    }
+}
+
+/// Bind a call from the caller's module outwards through the modules
+/// enclosing it, for languages whose classes and namespaces are modules
+///
+/// The shared rules apply at each scope. A qualified call (`Class::f()`,
+/// `value.f()` with a typed `value`) is also looked up as a bare call in the
+/// class the qualifier names, relative to the scope and to each of its glob
+/// imports, so members inherited through base-class globs and classes of
+/// wildcard-imported packages are found.
+pub(crate) fn bind_in_enclosing_scopes(
+    caller: &FunctionDef,
+    call: &FunctionCall,
+    symbols: &SymbolTable,
+) -> Option<(String, String)> {
+    let unqualified = call.qualifier.as_ref().map(|_| FunctionCall {
+        qualifier: None,
+        ..call.clone()
+    });
+
+    let mut scope = Some(caller.module.as_str());
+    while let Some(module) = scope {
+        if let Some((module, name)) = symbols.resolve_target(module, call) {
+            return Some((module.to_string(), name.to_string()));
+        }
+        if let (Some(qualifier), Some(unqualified)) = (&call.qualifier, &unqualified) {
+            let globs = symbols.imports_of(module).iter().filter(|i| i.is_glob()).map(|i| i.source.as_str());
+            for base in std::iter::once(module).chain(globs) {
+                if let Some((module, name)) = symbols.resolve_target(&join(base, qualifier), unqualified) {
+                    return Some((module.to_string(), name.to_string()));
+                }
+            }
+        }
+        scope = enclosing(module);
+    }
+    None
+}
+
+/// Factory for creating resolvers based on language
+#[must_use]
+pub fn get_resolver(language: Language) -> Box<dyn Resolver> {
+    match language {
+        Language::Rust => Box::new(RustResolver::new()),
+        Language::Python => Box::new(PythonResolver::new()),
+        Language::JavaScript | Language::TypeScript => Box::new(JavaScriptResolver::new()),
+        Language::Go => Box::new(GoResolver::new()),
+        Language::Lua => Box::new(LuaResolver::new()),
+        Language::Cpp => Box::new(CppResolver::new()),
+        Language::Java => Box::new(JavaResolver::new()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use trackast_lib::ast::{Import, Signature};
+
+    struct DefaultResolver;
+
+    impl Resolver for DefaultResolver {}
+
+    #[test]
+    fn test_resolve_binds_imported_calls() {
+        let mut ast = AbstractAST::new("app".to_string());
+        let mut main = FunctionDef::new("main".to_string(), Signature::empty(), "app".to_string());
+        main.add_call(FunctionCall::new("helper".to_string(), None, 2));
+        main.add_call(FunctionCall::new("missing".to_string(), None, 3));
+        ast.add_function(main);
+        ast.add_function(FunctionDef::new("helper".to_string(), Signature::empty(), "utils".to_string()));
+        ast.add_import(Import::item("app".into(), "utils".into(), "helper".into(), "helper".into()));
+
+        assert_eq!(DefaultResolver.resolve(&mut ast), 1);
+
+        let calls = &ast.get_function("main").unwrap().calls;
+        assert_eq!(calls[0].target_module.as_deref(), Some("utils"));
+        assert_eq!(calls[1].target_module, None);
+    }
+
+    #[test]
+    fn test_resolve_all_binds_across_asts() {
+        let mut app = AbstractAST::new("app".to_string());
+        let mut main = FunctionDef::new("main".to_string(), Signature::empty(), "app".to_string());
+        main.add_call(FunctionCall::new("helper".to_string(), None, 2));
+        app.add_function(main);
+        app.add_import(Import::item("app".into(), "utils".into(), "helper".into(), "helper".into()));
+        let mut utils = AbstractAST::new("utils".to_string());
+        utils.add_function(FunctionDef::new("helper".to_string(), Signature::empty(), "utils".to_string()));
+
+        let mut asts = [app, utils];
+        assert_eq!(DefaultResolver.resolve_all(&mut asts), 1);
+        assert_eq!(asts[0].functions[0].calls[0].target_module.as_deref(), Some("utils"));
+    }
+
+    #[test]
+    fn test_get_resolver() {
+        for language in [Language::Rust, Language::Python, Language::JavaScript, Language::TypeScript, Language::Go, Language::Lua, Language::Cpp, Language::Java] {
+            let resolver = get_resolver(language);
+            let _: &dyn Resolver = &*resolver;
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use trackast_lib::ast::{FunctionCall, FunctionDef};
+use trackast_lib::builder::SymbolTable;
+
+use super::Resolver;
+
+/// Resolver for Python modules
+///
+/// On top of the shared import rules it binds `cls.method()` inside a
+/// classmethod to the enclosing class and anchors relative imports the
+/// module loader left alone.
+pub struct PythonResolver;
+
+impl PythonResolver {
+    #[must_use]
+    pub fn new() -> Self {
+        PythonResolver
+    }
+}
+
+impl Default for PythonResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Resolver for PythonResolver {
+    fn normalize_path(&self, path: &str, module: &str, _symbols: &SymbolTable) -> String {
+        // The module loader anchors relative imports knowing which files are
+        // packages; without it, assume the importer is a plain module
+        if path.starts_with('.') {
+            let package: Vec<&str> = module.split("::").collect();
+            anchor_relative(path, &package[..package.len() - 1])
+        } else {
+            path.to_string()
+        }
+    }
+
+    fn bind_call(&self, caller: &FunctionDef, call: &FunctionCall, symbols: &SymbolTable) -> Option<(String, String)> {
+        if call.target_module.is_none() && call.qualifier.as_deref() == Some("cls") {
+            let (class, _) = caller.name.rsplit_once('.')?;
+            return symbols
+                .lookup_member(&caller.module, class, &call.target_name)
+                .map(|(module, name)| (module.to_string(), name.to_string()));
+        }
+
+        symbols
+            .resolve_target(&caller.module, call)
+            .map(|(module, name)| (module.to_string(), name.to_string()))
+    }
+}
+
+/// Resolve a relative Python import (`.mod`, `..pkg::mod`) inside `package`
+///
+/// One dot is the package itself and each further dot goes up one level.
+/// A source without a leading dot is already absolute and is returned as is.
+#[must_use]
+pub fn anchor_relative(source: &str, package: &[&str]) -> String {
+    let rest = source.trim_start_matches('.');
+    let Some(levels) = (source.len() - rest.len()).checked_sub(1) else {
+        return source.to_string();
+    };
+    let mut segments: Vec<&str> = package[..package.len().saturating_sub(levels)].to_vec();
+    segments.extend(rest.split("::").filter(|segment| !segment.is_empty()));
+    segments.join("::")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use trackast_lib::ast::Signature;
+
+    #[test]
+    fn test_cls_calls_bind_to_enclosing_class() {
+        let mut symbols = SymbolTable::new();
+        symbols.add_function(&FunctionDef::new("Model.validate".into(), Signature::empty(), "models".into()));
+        let caller = FunctionDef::new("Model.create".into(), Signature::empty(), "models".into());
+        let call = FunctionCall::new("validate".into(), None, 3).with_qualifier("cls".into());
+
+        assert_eq!(
+            PythonResolver::new().bind_call(&caller, &call, &symbols),
+            Some(("models".into(), "Model.validate".into()))
+        );
+    }
+
+    #[test]
+    fn test_anchor_relative() {
+        let package = ["svc", "api"];
+        assert_eq!(anchor_relative(".", &package), "svc::api");
+        assert_eq!(anchor_relative(".routes", &package), "svc::api::routes");
+        assert_eq!(anchor_relative("..helpers::text", &package), "svc::helpers::text");
+        assert_eq!(anchor_relative("....too_far", &package), "too_far");
+    }
+
+    #[test]
+    fn test_anchor_relative_leaves_absolute_sources() {
+        assert_eq!(anchor_relative("svc::models", &["svc", "api"]), "svc::models");
+    }
+}
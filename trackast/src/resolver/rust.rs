@@ -1,4 +1,78 @@
-use trackast_lib::ast::FunctionDef;
+use trackast_lib::ast::{FunctionCall, FunctionDef};
+use trackast_lib::builder::SymbolTable;
+
+use super::Resolver;
+
+/// Resolver for Rust paths
+///
+/// On top of the shared import rules it understands `crate::`, `self::` and
+/// `super::` prefixes, reads other `use` paths relative to the importing
+/// module or as paths into another loaded crate, and falls back to
+/// [`resolve_call`] for bare calls.
+/// The first segment of a module path names the crate root, so `crate::`
+/// in `app::net` refers to `app`.
+pub struct RustResolver;
+
+impl RustResolver {
+    #[must_use]
+    pub fn new() -> Self {
+        RustResolver
+    }
+}
+
+impl Default for RustResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Resolver for RustResolver {
+    fn normalize_path(&self, path: &str, module: &str, symbols: &SymbolTable) -> String {
+        if let Some(path) = absolute_path(path, module) {
+            return path;
+        }
+        // `use a::b` names `a` relative to the importing module unless `a` is
+        // another crate that was loaded alongside it
+        let head = path.split("::").next().unwrap_or(path);
+        let relative = format!("{module}::{path}");
+        if symbols.has_module(head) && !symbols.has_module(&format!("{module}::{head}")) {
+            path.to_string()
+        } else {
+            relative
+        }
+    }
+
+    fn bind_call(&self, caller: &FunctionDef, call: &FunctionCall, symbols: &SymbolTable) -> Option<(String, String)> {
+        let name = call.target_name.as_str();
+
+        // `crate::a::f()`, `super::f()`, `self::Type::f()`
+        if let Some(path) = call
+            .qualifier
+            .as_deref()
+            .filter(|_| call.target_module.is_none())
+            .and_then(|qualifier| absolute_path(qualifier, &caller.module))
+        {
+            if symbols.contains(&path, name) {
+                return Some((path, name.to_string()));
+            }
+            let (module, owner) = path.rsplit_once("::").unwrap_or(("", path.as_str()));
+            return symbols
+                .lookup_member(module, owner, name)
+                .map(|(module, name)| (module.to_string(), name.to_string()));
+        }
+
+        symbols
+            .resolve_target(&caller.module, call)
+            .map(|(module, name)| (module.to_string(), name.to_string()))
+            .or_else(|| {
+                if call.qualifier.is_none() && call.target_module.is_none() {
+                    resolve_call(name, &caller.module, symbols)
+                } else {
+                    None
+                }
+            })
+    }
+}
 
 /// Try to resolve a function call to a local function definition
 /// Returns (module, name) if found
@@ -6,40 +80,57 @@ use trackast_lib::ast::FunctionDef;
 pub fn resolve_call(
     call_name: &str,
     current_module: &str,
-    all_functions: &[FunctionDef],
+    symbols: &SymbolTable,
 ) -> Option<(String, String)> {
     // First, try to find in current module
-    for func in all_functions {
-        if func.name == call_name && func.module == current_module {
-            return Some((func.module.clone(), func.name.clone()));
-        }
+    if symbols.contains(current_module, call_name) {
+        return Some((current_module.to_string(), call_name.to_string()));
     }
 
     // Then try parent modules
     let parts: Vec<&str> = current_module.split("::").collect();
     for i in (1..parts.len()).rev() {
         let parent_module = parts[0..i].join("::");
-        for func in all_functions {
-            if func.name == call_name && func.module == parent_module {
-                return Some((func.module.clone(), func.name.clone()));
-            }
+        if symbols.contains(&parent_module, call_name) {
+            return Some((parent_module, call_name.to_string()));
         }
     }
 
     // Try root module
-    for func in all_functions {
-        if func.name == call_name && func.module.is_empty() {
-            return Some((func.module.clone(), func.name.clone()));
-        }
+    if symbols.contains("", call_name) {
+        return Some((String::new(), call_name.to_string()));
     }
 
     None
 }
 
+/// Turn a `crate::`, `self::` or `super::` path written in `module` into a
+/// full module path; other paths are relative and yield `None`
+///
+/// `super` never climbs above the crate root.
+fn absolute_path(path: &str, module: &str) -> Option<String> {
+    let mut segments = path.split("::").peekable();
+    let mut base: Vec<&str> = module.split("::").collect();
+    match segments.next()? {
+        "crate" => base.truncate(1),
+        "self" => {}
+        "super" => {
+            base.truncate((base.len() - 1).max(1));
+        }
+        _ => return None,
+    }
+    while segments.peek() == Some(&"super") {
+        segments.next();
+        base.truncate((base.len() - 1).max(1));
+    }
+    base.extend(segments);
+    Some(base.join("::"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use trackast_lib::ast::Signature;
+    use trackast_lib::ast::{Import, Signature};
 
     fn create_test_function(name: &str, module: &str) -> FunctionDef {
         FunctionDef::new(
@@ -49,6 +140,14 @@ mod tests {
         )
     }
 
+    fn symbols(funcs: &[FunctionDef]) -> SymbolTable {
+        let mut symbols = SymbolTable::new();
+        for func in funcs {
+            symbols.add_function(func);
+        }
+        symbols
+    }
+
     #[test]
     fn test_resolve_in_current_module() {
         let funcs = vec![
@@ -56,7 +155,7 @@ mod tests {
             create_test_function("main", "root"),
         ];
 
-        let result = resolve_call("helper", "root", &funcs);
+        let result = resolve_call("helper", "root", &symbols(&funcs));
         assert!(result.is_some());
         assert_eq!(result.unwrap(), ("root".to_string(), "helper".to_string()));
     }
@@ -68,7 +167,7 @@ mod tests {
             create_test_function("main", "root::utils"),
         ];
 
-        let result = resolve_call("helper", "root::utils", &funcs);
+        let result = resolve_call("helper", "root::utils", &symbols(&funcs));
         assert!(result.is_some());
         assert_eq!(result.unwrap(), ("root".to_string(), "helper".to_string()));
     }
@@ -77,7 +176,7 @@ mod tests {
     fn test_resolve_not_found() {
         let funcs = vec![create_test_function("main", "root")];
 
-        let result = resolve_call("missing", "root", &funcs);
+        let result = resolve_call("missing", "root", &symbols(&funcs));
         assert!(result.is_none());
     }
 
@@ -88,7 +187,56 @@ mod tests {
             create_test_function("main", "root::nested::deep::deeper"),
         ];
 
-        let result = resolve_call("util", "root::nested::deep::deeper", &funcs);
+        let result = resolve_call("util", "root::nested::deep::deeper", &symbols(&funcs));
         assert!(result.is_some());
     }
+
+    #[test]
+    fn test_absolute_path() {
+        assert_eq!(absolute_path("crate::net::client", "app::util").as_deref(), Some("app::net::client"));
+        assert_eq!(absolute_path("self::client", "app::net").as_deref(), Some("app::net::client"));
+        assert_eq!(absolute_path("self::util", "app").as_deref(), Some("app::util"));
+        assert_eq!(absolute_path("super::util", "app::net").as_deref(), Some("app::util"));
+        assert_eq!(absolute_path("super::super::util", "app::b::c").as_deref(), Some("app::util"));
+        assert_eq!(absolute_path("super", "app::tests").as_deref(), Some("app"));
+        assert_eq!(absolute_path("super::util", "app").as_deref(), Some("app::util"));
+        assert_eq!(absolute_path("util", "app::net"), None);
+    }
+
+    #[test]
+    fn test_bind_prefixed_paths() {
+        let funcs = vec![
+            create_test_function("log", "app::util"),
+            create_test_function("Client::new", "app::net"),
+            create_test_function("fetch", "app::net"),
+        ];
+        let symbols = symbols(&funcs);
+        let caller = create_test_function("fetch", "app::net");
+        let resolver = RustResolver::new();
+
+        let log = FunctionCall::new("log".into(), None, 1).with_qualifier("super::util".into());
+        assert_eq!(resolver.bind_call(&caller, &log, &symbols), Some(("app::util".into(), "log".into())));
+
+        let new = FunctionCall::new("new".into(), None, 1).with_qualifier("self::Client".into());
+        assert_eq!(resolver.bind_call(&caller, &new, &symbols), Some(("app::net".into(), "Client::new".into())));
+
+        let missing = FunctionCall::new("log".into(), None, 1).with_qualifier("crate::net".into());
+        assert_eq!(resolver.bind_call(&caller, &missing, &symbols), None);
+    }
+
+    #[test]
+    fn test_normalize_use_paths() {
+        let mut ast = trackast_lib::ast::AbstractAST::new("app::net".to_string());
+        ast.add_import(Import::item("app::net".into(), "super::util".into(), "log".into(), "log".into()));
+        ast.add_import(Import::glob("app::net".into(), "crate::util".into()));
+        ast.add_import(Import::item("app::net".into(), "client".into(), "connect".into(), "connect".into()));
+        ast.add_import(Import::item("app::net".into(), "core_lib::io".into(), "read".into(), "read".into()));
+        ast.add_function(create_test_function("read", "core_lib::io"));
+        RustResolver::new().resolve(&mut ast);
+
+        assert_eq!(ast.imports[0].source, "app::util");
+        assert_eq!(ast.imports[1].source, "app::util");
+        assert_eq!(ast.imports[2].source, "app::net::client");
+        assert_eq!(ast.imports[3].source, "core_lib::io");
+    }
 }
@@ -0,0 +1,206 @@
+//! Directory-scan loading
+//!
+//! Instead of following imports from an entry point, a [`DirectoryScanner`]
+//! walks a tree and translates every file of a supported language, so
+//! plugins and scripts that nothing imports statically are analyzed too.
+//! Each file becomes its own `AbstractAST`; the ASTs are resolved together
+//! with [`Resolver::resolve_all`](crate::resolver::Resolver::resolve_all)
+//! and added to the `CallGraphBuilder` one by one.
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use ignore::WalkBuilder;
+use trackast_lib::ast::{AbstractAST, FunctionCall, FunctionDef};
+
+use crate::language::Language;
+use crate::module_loader::ModuleLoader;
+use crate::translator_factory::get_translator;
+
+/// Walks a directory tree and translates every supported file
+///
+/// `.gitignore`, `.ignore` and `.git/info/exclude` files are honoured, also
+/// outside a git checkout, and hidden files and directories are skipped.
+/// Include and exclude globs match paths relative to the root
+/// (`src/**/*.py`); with no include glob every file is a candidate.
+#[derive(Debug, Clone)]
+pub struct DirectoryScanner {
+    root: PathBuf,
+    includes: Vec<String>,
+    excludes: Vec<String>,
+    language: Option<Language>,
+}
+
+impl DirectoryScanner {
+    /// Create a scanner for the tree under `root`
+    pub fn new(root: impl AsRef<Path>) -> Self {
+        DirectoryScanner {
+            root: root.as_ref().to_path_buf(),
+            includes: Vec::new(),
+            excludes: Vec::new(),
+            language: None,
+        }
+    }
+
+    /// Only scan files matching `glob`; may be given several times
+    #[must_use]
+    pub fn with_include(mut self, glob: impl Into<String>) -> Self {
+        self.includes.push(glob.into());
+        self
+    }
+
+    /// Skip files matching `glob`; may be given several times
+    #[must_use]
+    pub fn with_exclude(mut self, glob: impl Into<String>) -> Self {
+        self.excludes.push(glob.into());
+        self
+    }
+
+    /// Only scan files of `language`
+    #[must_use]
+    pub fn with_language(mut self, language: Language) -> Self {
+        self.language = Some(language);
+        self
+    }
+
+    /// The files to translate, in path order
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a glob is invalid or the tree cannot be walked.
+    pub fn files(&self) -> Result<Vec<PathBuf>, String> {
+        let includes = glob_set(&self.includes)?;
+        let excludes = glob_set(&self.excludes)?;
+
+        let mut files = Vec::new();
+        for entry in WalkBuilder::new(&self.root).require_git(false).build() {
+            let entry = entry.map_err(|e| format!("Failed to walk {}: {e}", self.root.display()))?;
+            let path = entry.path();
+            if !entry.file_type().is_some_and(|file_type| file_type.is_file()) {
+                continue;
+            }
+            let Some(language) = path.to_str().and_then(Language::from_file_path) else {
+                continue;
+            };
+            if self.language.is_some_and(|only| only != language) {
+                continue;
+            }
+            let relative = path.strip_prefix(&self.root).unwrap_or(path);
+            if (!self.includes.is_empty() && !includes.is_match(relative)) || excludes.is_match(relative) {
+                continue;
+            }
+            files.push(path.to_path_buf());
+        }
+        files.sort();
+        Ok(files)
+    }
+
+    /// Translate every file, one AST per file, with module paths given as
+    /// [`ModuleLoader`] gives them
+    ///
+    /// Files that cannot be read or translated are skipped, as the loader
+    /// skips dependencies it cannot load. A declaration without a body is
+    /// dropped when another file defines it (a C++ header and its source
+    /// file), and a definition repeated across files (Go `init` functions)
+    /// is folded into the first, so the ASTs never repeat a function ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a glob is invalid or the tree cannot be walked.
+    pub fn scan(&self) -> Result<Vec<AbstractAST>, String> {
+        let mut loader = ModuleLoader::new_mixed(&self.root);
+        let mut asts: Vec<AbstractAST> = self
+            .files()?
+            .iter()
+            .filter_map(|path| loader.load_file(path).ok())
+            .collect();
+        fold_duplicates(&mut asts);
+        Ok(asts)
+    }
+}
+
+/// Compile globs into one set
+fn glob_set(globs: &[String]) -> Result<GlobSet, String> {
+    let mut builder = GlobSetBuilder::new();
+    for glob in globs {
+        builder.add(Glob::new(glob).map_err(|e| format!("Invalid glob '{glob}': {e}"))?);
+    }
+    builder.build().map_err(|e| format!("Invalid globs: {e}"))
+}
+
+/// Drop declarations other files define and fold repeated definitions
+///
+/// Whether a declaration without a body belongs to a definition is up to
+/// the translator of its language; functions with the same ID are folded
+/// whatever their language.
+fn fold_duplicates(asts: &mut [AbstractAST]) {
+    let definitions: Vec<FunctionDef> = asts
+        .iter()
+        .flat_map(|ast| &ast.functions)
+        .filter(|func| func.span.is_some())
+        .cloned()
+        .collect();
+    for ast in asts.iter_mut() {
+        ast.functions.retain(|func| {
+            let language = func.language.as_deref().and_then(Language::from_name);
+            let (None, Some(language)) = (func.span, language) else {
+                return true;
+            };
+            let translator = get_translator(language);
+            !definitions.iter().any(|definition| translator.same_definition(definition, func))
+        });
+    }
+
+    let mut first = HashMap::new();
+    let mut repeated_calls: Vec<((usize, usize), Vec<FunctionCall>)> = Vec::new();
+    for (ast_index, ast) in asts.iter_mut().enumerate() {
+        let mut kept = 0;
+        ast.functions.retain_mut(|func| match first.entry(func.fn_id()) {
+            Entry::Occupied(entry) => {
+                repeated_calls.push((*entry.get(), std::mem::take(&mut func.calls)));
+                false
+            }
+            Entry::Vacant(entry) => {
+                entry.insert((ast_index, kept));
+                kept += 1;
+                true
+            }
+        });
+    }
+    for ((ast_index, func_index), calls) in repeated_calls {
+        asts[ast_index].functions[func_index].calls.extend(calls);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use trackast_lib::ast::{FunctionDef, Signature, SourceSpan};
+
+    #[test]
+    fn test_declarations_and_repeated_definitions_are_folded() {
+        let params = |name: &str| Signature::new(vec![(name.to_string(), "int".to_string())], "void".to_string());
+        let mut header = AbstractAST::new("util".to_string());
+        header.add_function(FunctionDef::new("log".into(), params("_"), "".into()));
+        let mut source = AbstractAST::new("util".to_string());
+        source.add_function(FunctionDef::new("log".into(), params("value"), "".into()).with_span(SourceSpan::new(1, 3, 0, 40)));
+        let mut init = FunctionDef::new("init".into(), Signature::empty(), "shop".into()).with_span(SourceSpan::new(5, 7, 50, 90));
+        init.add_call(FunctionCall::new("a".into(), None, 6));
+        source.add_function(init.clone());
+        let mut other = AbstractAST::new("shop".to_string());
+        init.calls = vec![FunctionCall::new("b".into(), None, 2)];
+        other.add_function(init);
+
+        let mut asts = [header, source, other];
+        for ast in &mut asts {
+            ast.set_language(Language::Cpp.as_str());
+        }
+        fold_duplicates(&mut asts);
+        assert!(asts[0].functions.is_empty());
+        assert!(asts[2].functions.is_empty());
+        let calls: Vec<&str> = asts[1].functions[1].calls.iter().map(|c| c.target_name.as_str()).collect();
+        assert_eq!(calls, ["a", "b"]);
+    }
+}
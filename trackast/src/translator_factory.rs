@@ -1,5 +1,5 @@
 use crate::translator_trait::Translator;
-use crate::translators::{RustTranslator, PythonTranslator, JavaScriptTranslator};
+use crate::translators::{RustTranslator, PythonTranslator, JavaScriptTranslator, TypeScriptTranslator, GoTranslator, LuaTranslator, CppTranslator, JavaTranslator};
 use crate::language::Language;
 
 /// Factory for creating translators based on language
@@ -9,6 +9,11 @@ pub fn get_translator(language: Language) -> Box<dyn Translator> {
         Language::Rust => Box::new(RustTranslator::new()),
         Language::Python => Box::new(PythonTranslator::new()),
         Language::JavaScript => Box::new(JavaScriptTranslator::new()),
+        Language::TypeScript => Box::new(TypeScriptTranslator::new()),
+        Language::Go => Box::new(GoTranslator::new()),
+        Language::Lua => Box::new(LuaTranslator::new()),
+        Language::Cpp => Box::new(CppTranslator::new()),
+        Language::Java => Box::new(JavaTranslator::new()),
     }
 }
 
@@ -33,4 +38,34 @@ mod tests {
         let translator = get_translator(Language::JavaScript);
         let _: &dyn Translator = &*translator;
     }
+
+    #[test]
+    fn test_get_translator_typescript() {
+        let translator = get_translator(Language::TypeScript);
+        let _: &dyn Translator = &*translator;
+    }
+
+    #[test]
+    fn test_get_translator_go() {
+        let translator = get_translator(Language::Go);
+        let _: &dyn Translator = &*translator;
+    }
+
+    #[test]
+    fn test_get_translator_lua() {
+        let translator = get_translator(Language::Lua);
+        let _: &dyn Translator = &*translator;
+    }
+
+    #[test]
+    fn test_get_translator_cpp() {
+        let translator = get_translator(Language::Cpp);
+        let _: &dyn Translator = &*translator;
+    }
+
+    #[test]
+    fn test_get_translator_java() {
+        let translator = get_translator(Language::Java);
+        let _: &dyn Translator = &*translator;
+    }
 }
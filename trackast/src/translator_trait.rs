@@ -1,4 +1,4 @@
-use trackast_lib::ast::AbstractAST;
+use trackast_lib::ast::{AbstractAST, FunctionDef};
 
 /// Trait for language-specific translators
 pub trait Translator {
@@ -8,12 +8,30 @@ pub trait Translator {
     ///
     /// Returns an error if the file cannot be read or parsed.
     fn translate_file(&self, path: &str, module_path: Option<&str>) -> Result<AbstractAST, String>;
+
+    /// Whether `later` defines the same function as `earlier` and is folded
+    /// into it rather than kept beside it
+    ///
+    /// By default no two definitions are the same function. Languages that
+    /// declare a function in one file and define it in another, or allow
+    /// repeating a definition, say so here.
+    fn same_definition(&self, _earlier: &FunctionDef, _later: &FunctionDef) -> bool {
+        false
+    }
+
+    /// Add a definition from another file of the same load to `ast`
+    ///
+    /// Appends it by default; languages overriding
+    /// [`Translator::same_definition`] fold it into the earlier definition.
+    fn add_function(&self, ast: &mut AbstractAST, func_def: FunctionDef) {
+        ast.add_function(func_def);
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::translators::{RustTranslator, PythonTranslator, JavaScriptTranslator};
+    use crate::translators::{RustTranslator, PythonTranslator, JavaScriptTranslator, TypeScriptTranslator, GoTranslator, LuaTranslator, CppTranslator, JavaTranslator};
 
     #[test]
     fn test_rust_translator_implements_trait() {
@@ -32,4 +50,34 @@ mod tests {
         let translator = JavaScriptTranslator::new();
         let _: &dyn Translator = &translator;
     }
+
+    #[test]
+    fn test_typescript_translator_implements_trait() {
+        let translator = TypeScriptTranslator::new();
+        let _: &dyn Translator = &translator;
+    }
+
+    #[test]
+    fn test_go_translator_implements_trait() {
+        let translator = GoTranslator::new();
+        let _: &dyn Translator = &translator;
+    }
+
+    #[test]
+    fn test_lua_translator_implements_trait() {
+        let translator = LuaTranslator::new();
+        let _: &dyn Translator = &translator;
+    }
+
+    #[test]
+    fn test_cpp_translator_implements_trait() {
+        let translator = CppTranslator::new();
+        let _: &dyn Translator = &translator;
+    }
+
+    #[test]
+    fn test_java_translator_implements_trait() {
+        let translator = JavaTranslator::new();
+        let _: &dyn Translator = &translator;
+    }
 }
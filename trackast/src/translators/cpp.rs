@@ -0,0 +1,920 @@
+use std::collections::HashMap;
+use tree_sitter::{Node, Parser};
+use trackast_lib::ast::{AbstractAST, FunctionDef, Import, Signature};
+use super::{call_site, fold_function, normalize_type, span_of, CallSite};
+
+/// Extensions of C and C++ headers
+pub const HEADER_EXTENSIONS: [&str; 4] = ["h", "hh", "hpp", "hxx"];
+
+/// Extensions of C and C++ source files, in the order they are looked for
+/// beside a header
+pub const SOURCE_EXTENSIONS: [&str; 4] = ["cpp", "cc", "cxx", "c"];
+
+/// Casts that parse as calls to template functions
+const CASTS: [&str; 4] = ["static_cast", "dynamic_cast", "const_cast", "reinterpret_cast"];
+
+/// Translator for C and C++ source code to abstract AST
+///
+/// Namespaces and classes are module paths, so a function gets the same ID
+/// in the header declaring it and the source file defining it:
+/// `geo::Circle::area` is `area` in module `geo::Circle`, and the global
+/// namespace is the empty module (`::main`). Functions with internal
+/// linkage (`static`, or in an anonymous namespace) are scoped by the
+/// file's module instead. Signatures hold parameter types, so overloads get
+/// distinct IDs. C is parsed with the C++ grammar.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CppTranslator;
+
+/// Where a declaration sits
+#[derive(Debug, Clone, Default)]
+struct Scope {
+    /// Enclosing namespaces and classes
+    path: Vec<String>,
+    /// Parameter lists of the `template <...>` headers wrapping the
+    /// declaration, outermost first
+    templates: Vec<Vec<String>>,
+    /// Inside a class body, where `static` declares a static member
+    in_class: bool,
+    /// Inside an anonymous namespace
+    internal: bool,
+}
+
+/// A file being translated
+struct TranslationUnit<'a> {
+    source: &'a str,
+    /// The file's module, which holds functions with internal linkage
+    module: &'a str,
+    ast: AbstractAST,
+    /// Functions with internal linkage, by name, with the namespace they
+    /// are declared in and their module
+    internal: HashMap<String, (String, String)>,
+}
+
+impl TranslationUnit<'_> {
+    fn text(&self, node: Node) -> &str {
+        &self.source[node.start_byte()..node.end_byte()]
+    }
+
+    /// Module path of a scope; internal scopes sit under the file's module
+    fn module_of(&self, scope: &Scope, path: &[String]) -> String {
+        let path = path.join("::");
+        if !scope.internal {
+            return path;
+        }
+        match (self.module.is_empty(), path.is_empty()) {
+            (true, _) => path,
+            (_, true) => self.module.to_string(),
+            _ => format!("{}::{path}", self.module),
+        }
+    }
+
+    /// Let an internal module see the namespace it is nested in, as an
+    /// anonymous namespace does
+    fn link_internal(&mut self, module: &str, path: &[String]) {
+        if path.is_empty() {
+            return;
+        }
+        let import = Import::glob(module.to_string(), format!("::{}", path.join("::")));
+        if !self.ast.imports.contains(&import) {
+            self.ast.add_import(import);
+        }
+    }
+}
+
+impl CppTranslator {
+    /// Create a new C/C++ translator
+    #[must_use]
+    pub fn new() -> Self {
+        CppTranslator
+    }
+
+    /// Set up a parser for C++
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the parser cannot be initialized or language set.
+    pub fn setup_parser() -> Result<Parser, String> {
+        let mut parser = Parser::new();
+        let language = tree_sitter_cpp::language();
+        parser
+            .set_language(language)
+            .map_err(|_| "Failed to set C++ language".to_string())?;
+        Ok(parser)
+    }
+
+    /// Parse source code and return the tree
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if parsing fails.
+    pub fn parse_source(&self, source: &str) -> Result<tree_sitter::Tree, String> {
+        let mut parser = Self::setup_parser()?;
+        parser
+            .parse(source, None)
+            .ok_or_else(|| "Failed to parse source".to_string())
+    }
+
+    /// Translate C or C++ source to abstract AST
+    ///
+    /// `module_path` names the file; it holds the functions with internal
+    /// linkage.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if parsing fails.
+    pub fn translate(&self, source: &str, module_path: &str) -> Result<AbstractAST, String> {
+        let tree = self.parse_source(source)?;
+        let mut unit = TranslationUnit {
+            source,
+            module: module_path,
+            ast: AbstractAST::new(module_path.to_string()),
+            internal: HashMap::new(),
+        };
+        Self::extract_declarations(tree.root_node(), &Scope::default(), &mut unit);
+
+        // A call to a function with internal linkage stays in this file
+        let TranslationUnit { mut ast, internal, .. } = unit;
+        for call in ast.functions.iter_mut().flat_map(|f| f.calls.iter_mut()) {
+            let Some((namespace, module)) = internal.get(&call.target_name) else {
+                continue;
+            };
+            if call.target_module.is_none() && call.qualifier.as_ref().is_none_or(|q| q == namespace) {
+                call.target_module = Some(module.clone());
+            }
+        }
+        Ok(ast)
+    }
+
+    /// Add a function, folding it into an earlier one with the same name
+    /// and parameter types
+    ///
+    /// A declaration has no span; the definition it is paired with supplies
+    /// the parameter names and location. Calls of both are kept.
+    pub(crate) fn add_function(ast: &mut AbstractAST, func_def: FunctionDef) {
+        fold_function(ast, func_def, Self::same_function);
+    }
+
+    /// Whether two functions are the same overload of one name in one module
+    fn same_function(a: &FunctionDef, b: &FunctionDef) -> bool {
+        a.module == b.module && a.name == b.name && same_overload(&a.signature, &b.signature)
+    }
+
+    /// Walk the declarations of a translation unit, namespace, class or
+    /// linkage specification
+    fn extract_declarations(node: Node, scope: &Scope, unit: &mut TranslationUnit) {
+        for child in node.named_children(&mut node.walk()) {
+            match child.kind() {
+                "namespace_definition" => {
+                    let Some(body) = child.child_by_field_name("body") else {
+                        continue;
+                    };
+                    let mut inner = Scope {
+                        templates: vec![],
+                        ..scope.clone()
+                    };
+                    match child.child_by_field_name("name") {
+                        Some(name) => inner.path.extend(unit.text(name).split("::").map(|s| s.trim().to_string())),
+                        None => {
+                            inner.internal = true;
+                            let module = unit.module_of(&inner, &inner.path);
+                            unit.link_internal(&module, &inner.path);
+                        }
+                    }
+                    Self::extract_declarations(body, &inner, unit);
+                }
+                "template_declaration" => {
+                    let params = child
+                        .child_by_field_name("parameters")
+                        .map(|list| Self::template_parameters(list, unit.source))
+                        .unwrap_or_default();
+                    let mut inner = scope.clone();
+                    inner.templates.push(params);
+                    Self::extract_declarations(child, &inner, unit);
+                }
+                "class_specifier" | "struct_specifier" | "union_specifier" => Self::extract_class(child, scope, unit),
+                "function_definition" => {
+                    Self::extract_function(child, child.child_by_field_name("declarator"), scope, unit);
+                }
+                "declaration" | "field_declaration" | "type_definition" => {
+                    if let Some(ty) = child.child_by_field_name("type") {
+                        if matches!(ty.kind(), "class_specifier" | "struct_specifier" | "union_specifier") {
+                            Self::extract_class(ty, scope, unit);
+                        }
+                    }
+                    if child.kind() != "type_definition" {
+                        for declarator in child.children_by_field_name("declarator", &mut child.walk()) {
+                            Self::extract_function(child, Some(declarator), scope, unit);
+                        }
+                    }
+                }
+                "using_declaration" | "namespace_alias_definition" => Self::extract_using(child, scope, unit),
+                "declaration_list" | "linkage_specification" => Self::extract_declarations(child, scope, unit),
+                kind if kind.starts_with("preproc_") => Self::extract_declarations(child, scope, unit),
+                _ => {}
+            }
+        }
+    }
+
+    /// Walk the members of a class, struct or union with a body
+    ///
+    /// Each base class is a glob import of the class's module, so inherited
+    /// members are found through it.
+    fn extract_class(node: Node, scope: &Scope, unit: &mut TranslationUnit) {
+        let (Some(name), Some(body)) = (node.child_by_field_name("name"), node.child_by_field_name("body")) else {
+            return;
+        };
+        let mut inner = Scope {
+            templates: vec![],
+            in_class: true,
+            ..scope.clone()
+        };
+        inner.path.extend(Self::qualified_path(name, unit.source));
+        let module = unit.module_of(&inner, &inner.path);
+
+        for child in node.named_children(&mut node.walk()).filter(|c| c.kind() == "base_class_clause") {
+            for base in child.named_children(&mut child.walk()) {
+                if let Some(base) = Self::type_path(base, unit.source) {
+                    unit.ast.add_import(Import::glob(module.clone(), base));
+                }
+            }
+        }
+        Self::extract_declarations(body, &inner, unit);
+    }
+
+    /// Record `using namespace a::b;`, `using a::b::f;` and `namespace n = a::b;`
+    fn extract_using(node: Node, scope: &Scope, unit: &mut TranslationUnit) {
+        let module = unit.module_of(scope, &scope.path);
+        let import = if node.kind() == "namespace_alias_definition" {
+            let Some(alias) = node.child_by_field_name("name") else {
+                return;
+            };
+            let Some(target) = node.named_children(&mut node.walk()).filter(|c| c.id() != alias.id()).last() else {
+                return;
+            };
+            let target = unit.text(target).split_whitespace().collect::<String>();
+            Import::module(module, target, unit.text(alias).to_string())
+        } else {
+            let Some(target) = node.named_child(0) else {
+                return;
+            };
+            let mut path = Self::qualified_path(target, unit.source);
+            let is_directive = node.children(&mut node.walk()).any(|c| c.kind() == "namespace");
+            if is_directive {
+                Import::glob(module, path.join("::"))
+            } else {
+                let Some(name) = path.pop() else {
+                    return;
+                };
+                Import::item(module, path.join("::"), name.clone(), name)
+            }
+        };
+        unit.ast.add_import(import);
+    }
+
+    /// Build the function declared by `declarator` in a definition or
+    /// declaration
+    ///
+    /// Declarators that are not functions (variables, function pointers)
+    /// are skipped. A declaration gets no span and no calls.
+    fn extract_function(node: Node, declarator: Option<Node>, scope: &Scope, unit: &mut TranslationUnit) {
+        let Some(mut declarator) = declarator else {
+            return;
+        };
+
+        // `int* f()` and `const T& f()` wrap the function declarator
+        let mut return_suffix = String::new();
+        while declarator.kind() != "function_declarator" {
+            match declarator.kind() {
+                "pointer_declarator" => return_suffix.push('*'),
+                "reference_declarator" => {
+                    if let Some(token) = declarator.child(0) {
+                        return_suffix.push_str(unit.text(token));
+                    }
+                }
+                _ => return,
+            }
+            let inner = declarator
+                .child_by_field_name("declarator")
+                .or_else(|| declarator.named_child(declarator.named_child_count().saturating_sub(1)));
+            let Some(inner) = inner else {
+                return;
+            };
+            declarator = inner;
+        }
+
+        let Some(name_node) = declarator.child_by_field_name("declarator") else {
+            return;
+        };
+        if !matches!(
+            name_node.kind(),
+            "identifier" | "field_identifier" | "qualified_identifier" | "operator_name" | "destructor_name" | "template_function"
+        ) {
+            return;
+        }
+        let mut path = scope.path.clone();
+        let mut qualified = Self::qualified_path(name_node, unit.source);
+        let Some(name) = qualified.pop() else {
+            return;
+        };
+        // Out of line, `Stack<T>::push` is declared under the class's template header
+        let class_templates = Self::count_template_scopes(name_node);
+        path.extend(qualified);
+
+        let is_static = node
+            .named_children(&mut node.walk())
+            .any(|c| c.kind() == "storage_class_specifier" && unit.text(c) == "static");
+        let internal_scope = Scope {
+            internal: scope.internal || (is_static && !scope.in_class),
+            ..scope.clone()
+        };
+        let module = unit.module_of(&internal_scope, &path);
+        if internal_scope.internal {
+            unit.internal.insert(name.clone(), (path.join("::"), module.clone()));
+            unit.link_internal(&module, &path);
+        }
+
+        let mut params = Vec::new();
+        // Member functions overload on `const` and ref qualifiers, which
+        // apply to the implicit object parameter
+        let qualifiers: Vec<&str> = declarator
+            .named_children(&mut declarator.walk())
+            .filter(|c| c.kind() == "type_qualifier" || c.kind() == "ref_qualifier")
+            .map(|c| unit.text(c))
+            .collect();
+        if let (false, Some(owner)) = (qualifiers.is_empty(), path.last()) {
+            let reference = qualifiers.iter().find(|q| q.starts_with('&')).copied().unwrap_or("&");
+            let cv: Vec<&str> = qualifiers.iter().filter(|q| !q.starts_with('&')).copied().collect();
+            let ty = if cv.is_empty() {
+                format!("{owner}{reference}")
+            } else {
+                format!("{} {owner}{reference}", cv.join(" "))
+            };
+            params.push(("this".to_string(), ty));
+        }
+        if let Some(parameters) = declarator.child_by_field_name("parameters") {
+            params.extend(Self::parameters(parameters, unit.source));
+        }
+
+        let return_type = match node.child_by_field_name("type") {
+            Some(ty) => {
+                let cv: Vec<&str> = node
+                    .named_children(&mut node.walk())
+                    .filter(|c| c.kind() == "type_qualifier" && c.end_byte() <= ty.start_byte())
+                    .map(|c| unit.text(c))
+                    .collect();
+                let base = declarator_type(&format!("{} {}", cv.join(" "), unit.text(ty)));
+                format!("{base}{return_suffix}")
+            }
+            // Constructors and destructors
+            None => "()".to_string(),
+        };
+        let type_params = scope.templates.iter().skip(class_templates).flatten().cloned().collect();
+        let signature = Signature::new(params, return_type).with_type_params(type_params);
+        let mut func_def = FunctionDef::new(name, signature, module);
+
+        if let Some(body) = node.child_by_field_name("body").filter(|_| node.kind() == "function_definition") {
+            let mut variables = HashMap::new();
+            if let Some(parameters) = declarator.child_by_field_name("parameters") {
+                Self::variable_types(parameters, unit.source, &mut variables);
+            }
+            Self::variable_types(body, unit.source, &mut variables);
+
+            let mut sites = Vec::new();
+            Self::extract_calls_recursive(body, unit.source, &variables, &mut sites);
+            func_def = func_def.with_span(span_of(node));
+            for site in sites {
+                func_def.add_call(site.into_call(None));
+            }
+        }
+        Self::add_function(&mut unit.ast, func_def);
+    }
+
+    /// `(name, type)` pairs of a parameter list
+    ///
+    /// The type is the declaration without its name or default value
+    /// (`const std::string&`, `char**`); unnamed parameters are called `_`,
+    /// a C-style `...` is `_: ...` and `(void)` takes no parameters.
+    fn parameters(list: Node, source: &str) -> Vec<(String, String)> {
+        let mut params = Vec::new();
+        for param in list.children(&mut list.walk()) {
+            match param.kind() {
+                "parameter_declaration" | "optional_parameter_declaration" | "variadic_parameter_declaration" => {}
+                "..." => {
+                    params.push(("_".to_string(), "...".to_string()));
+                    continue;
+                }
+                _ => continue,
+            }
+            let declarator = param.child_by_field_name("declarator");
+            let end = match (param.kind(), declarator, param.child_by_field_name("type")) {
+                ("optional_parameter_declaration", Some(declarator), _) => declarator.end_byte(),
+                ("optional_parameter_declaration", None, Some(ty)) => ty.end_byte(),
+                _ => param.end_byte(),
+            };
+            let (name, ty) = match declarator.and_then(Self::declared_name) {
+                Some(name) => (
+                    source[name.start_byte()..name.end_byte()].to_string(),
+                    format!("{}{}", &source[param.start_byte()..name.start_byte()], &source[name.end_byte()..end]),
+                ),
+                None => ("_".to_string(), source[param.start_byte()..end].to_string()),
+            };
+            params.push((name, declarator_type(&ty)));
+        }
+        if let [(name, ty)] = params.as_slice() {
+            if name == "_" && ty == "void" {
+                params.clear();
+            }
+        }
+        params
+    }
+
+    /// The parameters of a `template <...>` header, without defaults
+    fn template_parameters(list: Node, source: &str) -> Vec<String> {
+        list.named_children(&mut list.walk())
+            .map(|param| {
+                let text = &source[param.start_byte()..param.end_byte()];
+                declarator_type(text.split('=').next().unwrap_or(text))
+            })
+            .collect()
+    }
+
+    /// The identifier a declarator declares, through pointers, references,
+    /// arrays and parentheses; `None` for abstract declarators
+    fn declared_name(mut node: Node) -> Option<Node> {
+        loop {
+            match node.kind() {
+                "identifier" | "field_identifier" => return Some(node),
+                _ => {
+                    node = node
+                        .child_by_field_name("declarator")
+                        .or_else(|| node.named_child(node.named_child_count().checked_sub(1)?))?;
+                }
+            }
+        }
+    }
+
+    /// Segments of a possibly qualified name, without template arguments:
+    /// `geo::Circle::area` and `Stack<T>::push` give `[geo, Circle, area]`
+    /// and `[Stack, push]`
+    fn qualified_path(node: Node, source: &str) -> Vec<String> {
+        match node.kind() {
+            "qualified_identifier" => {
+                let mut path = node
+                    .child_by_field_name("scope")
+                    .map(|scope| Self::qualified_path(scope, source))
+                    .unwrap_or_default();
+                if let Some(name) = node.child_by_field_name("name") {
+                    path.extend(Self::qualified_path(name, source));
+                }
+                path
+            }
+            "template_type" | "template_function" | "template_method" => node
+                .child_by_field_name("name")
+                .map(|name| Self::qualified_path(name, source))
+                .unwrap_or_default(),
+            "nested_namespace_specifier" => node
+                .named_children(&mut node.walk())
+                .flat_map(|segment| Self::qualified_path(segment, source))
+                .collect(),
+            _ => vec![declarator_type(&source[node.start_byte()..node.end_byte()])],
+        }
+    }
+
+    /// How many scopes of a qualified name carry template arguments
+    fn count_template_scopes(node: Node) -> usize {
+        let mut count = 0;
+        let mut node = Some(node);
+        while let Some(current) = node.filter(|n| n.kind() == "qualified_identifier") {
+            if current.child_by_field_name("scope").is_some_and(|s| s.kind() == "template_type") {
+                count += 1;
+            }
+            node = current.child_by_field_name("name");
+        }
+        count
+    }
+
+    /// The `::`-joined path of a named type, e.g. `geo::Circle` for
+    /// `const geo::Circle&`'s type node; `None` for built-in and `auto` types
+    fn type_path(node: Node, source: &str) -> Option<String> {
+        matches!(node.kind(), "type_identifier" | "qualified_identifier" | "template_type")
+            .then(|| Self::qualified_path(node, source).join("::"))
+    }
+
+    /// Record the named type of every parameter and local variable below
+    /// `node`, so calls through them can be qualified by the type
+    fn variable_types(node: Node, source: &str, variables: &mut HashMap<String, String>) {
+        if matches!(
+            node.kind(),
+            "declaration" | "parameter_declaration" | "optional_parameter_declaration" | "for_range_loop"
+        ) {
+            if let Some(ty) = node.child_by_field_name("type").and_then(|ty| Self::type_path(ty, source)) {
+                for declarator in node.children_by_field_name("declarator", &mut node.walk()) {
+                    if let Some(name) = Self::declared_name(declarator) {
+                        variables.insert(source[name.start_byte()..name.end_byte()].to_string(), ty.clone());
+                    }
+                }
+            }
+        }
+        for child in node.named_children(&mut node.walk()) {
+            Self::variable_types(child, source, variables);
+        }
+    }
+
+    /// Recursively find function calls, including those in lambdas
+    ///
+    /// Besides call expressions, `new T(...)` and `T value(...)` call `T`'s
+    /// constructor, qualified by `T`.
+    fn extract_calls_recursive(node: Node, source: &str, variables: &HashMap<String, String>, calls: &mut Vec<CallSite>) {
+        let text = |n: Node| &source[n.start_byte()..n.end_byte()];
+        match node.kind() {
+            "call_expression" => {
+                if let Some(function) = node.child_by_field_name("function") {
+                    let site = match function.kind() {
+                        "identifier" => Some(call_site(text(function), function)),
+                        "template_function" => function
+                            .child_by_field_name("name")
+                            .filter(|name| !CASTS.contains(&text(*name)))
+                            .map(|name| call_site(text(name), name)),
+                        // `ns::f()`, `Class::make()`
+                        "qualified_identifier" => {
+                            let mut path = Self::qualified_path(function, source);
+                            match (path.pop(), Self::last_name(function)) {
+                                (Some(name), Some(name_node)) => {
+                                    let qualifier = (!path.is_empty()).then(|| path.join("::"));
+                                    Some(call_site(name, name_node).qualified(qualifier))
+                                }
+                                _ => None,
+                            }
+                        }
+                        // `value.method()`, `ptr->method()`, `this->method()`
+                        "field_expression" => {
+                            let field = function.child_by_field_name("field");
+                            let name_node = field.map(|f| f.child_by_field_name("name").unwrap_or(f));
+                            name_node.map(|name_node| {
+                                let qualifier = function
+                                    .child_by_field_name("argument")
+                                    .and_then(|argument| Self::operand_path(argument, source, variables));
+                                call_site(text(name_node), name_node).qualified(qualifier)
+                            })
+                        }
+                        _ => None,
+                    };
+                    if let Some(site) = site {
+                        let arguments = node.child_by_field_name("arguments");
+                        calls.push(site.with_arguments(arguments).with_arg_types(Self::argument_types(arguments, source)));
+                    }
+                }
+            }
+            "new_expression" => {
+                if let Some(ty) = node.child_by_field_name("type") {
+                    if let Some(call) = Self::constructor_call(ty, source) {
+                        let arguments = node.child_by_field_name("arguments");
+                        calls.push(call.with_arguments(arguments).with_arg_types(Self::argument_types(arguments, source)));
+                    }
+                }
+            }
+            "declaration" => {
+                let arguments = node
+                    .children_by_field_name("declarator", &mut node.walk())
+                    .filter(|d| d.kind() == "init_declarator")
+                    .find_map(|d| d.child_by_field_name("value").filter(|v| v.kind() == "argument_list"));
+                if let Some(ty) = node.child_by_field_name("type").filter(|_| arguments.is_some()) {
+                    if let Some(call) = Self::constructor_call(ty, source) {
+                        calls.push(call.with_arguments(arguments).with_arg_types(Self::argument_types(arguments, source)));
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        for child in node.children(&mut node.walk()) {
+            Self::extract_calls_recursive(child, source, variables, calls);
+        }
+    }
+
+    /// The type of each argument in `arguments` that a cast or a literal
+    /// shows, `None` for the others
+    fn argument_types(arguments: Option<Node>, source: &str) -> Vec<Option<String>> {
+        let Some(arguments) = arguments else {
+            return Vec::new();
+        };
+        let text = |n: Node| &source[n.start_byte()..n.end_byte()];
+        arguments
+            .named_children(&mut arguments.walk())
+            .filter(|argument| argument.kind() != "comment")
+            .map(|argument| match argument.kind() {
+                // `(double)value`
+                "cast_expression" => argument.child_by_field_name("type").map(|ty| declarator_type(text(ty))),
+                // `static_cast<double>(value)`
+                "call_expression" => argument
+                    .child_by_field_name("function")
+                    .filter(|function| function.kind() == "template_function")
+                    .filter(|function| {
+                        function
+                            .child_by_field_name("name")
+                            .is_some_and(|name| text(name).ends_with("_cast"))
+                    })
+                    .and_then(|function| function.child_by_field_name("arguments"))
+                    .and_then(|list| list.named_child(0))
+                    .map(|ty| declarator_type(text(ty))),
+                "number_literal" => Some(number_type(text(argument))),
+                "string_literal" | "raw_string_literal" => Some("const char*".to_string()),
+                "char_literal" => Some("char".to_string()),
+                "true" | "false" => Some("bool".to_string()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// A call to the constructor of the named type `ty`
+    fn constructor_call(ty: Node, source: &str) -> Option<CallSite> {
+        let path = Self::type_path(ty, source)?;
+        let name = path.rsplit("::").next().unwrap_or(&path).to_string();
+        let name_node = Self::last_name(ty).unwrap_or(ty);
+        Some(call_site(name, name_node).qualified(Some(path)))
+    }
+
+    /// The node of the last segment of a possibly qualified name
+    fn last_name(mut node: Node) -> Option<Node> {
+        loop {
+            match node.kind() {
+                "qualified_identifier" | "template_type" | "template_function" => node = node.child_by_field_name("name")?,
+                _ => return Some(node),
+            }
+        }
+    }
+
+    /// The qualifier of a call through `operand`: the type of a known
+    /// variable, else the `::`-joined path of an identifier or member
+    /// chain; `None` for `this` and other expressions
+    fn operand_path(node: Node, source: &str, variables: &HashMap<String, String>) -> Option<String> {
+        match node.kind() {
+            "identifier" => {
+                let name = &source[node.start_byte()..node.end_byte()];
+                Some(variables.get(name).cloned().unwrap_or_else(|| name.to_string()))
+            }
+            "field_expression" => {
+                let argument = node.child_by_field_name("argument")?;
+                let field = node.child_by_field_name("field")?;
+                let field = &source[field.start_byte()..field.end_byte()];
+                match argument.kind() {
+                    "this" => Some(field.to_string()),
+                    _ => Some(format!("{}::{field}", Self::operand_path(argument, source, variables)?)),
+                }
+            }
+            "pointer_expression" | "parenthesized_expression" => {
+                Self::operand_path(node.named_child(node.named_child_count().checked_sub(1)?)?, source, variables)
+            }
+            _ => None,
+        }
+    }
+
+    /// Translate a C or C++ file to abstract AST
+    ///
+    /// Without a module path the file is named after its stem.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if file reading or parsing fails.
+    pub fn translate_file(&self, path: &str, module_path: Option<&str>) -> Result<AbstractAST, String> {
+        let source = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read file: {e}"))?;
+
+        let module = module_path.map_or_else(
+            || {
+                std::path::Path::new(path)
+                    .file_stem()
+                    .and_then(|s| s.to_str())
+                    .unwrap_or("root")
+                    .to_string()
+            },
+            str::to_string,
+        );
+
+        let mut ast = self.translate(&source, &module)?;
+
+        ast.set_source_file(path);
+
+        Ok(ast)
+    }
+}
+
+impl crate::translator_trait::Translator for CppTranslator {
+    fn translate_file(&self, path: &str, module_path: Option<&str>) -> Result<AbstractAST, String> {
+        CppTranslator::translate_file(self, path, module_path)
+    }
+
+    fn same_definition(&self, earlier: &FunctionDef, later: &FunctionDef) -> bool {
+        Self::same_function(earlier, later)
+    }
+
+    fn add_function(&self, ast: &mut AbstractAST, func_def: FunctionDef) {
+        Self::add_function(ast, func_def);
+    }
+}
+
+/// Whether two signatures declare the same overload: parameter names do
+/// not count, and are often left out of declarations
+fn same_overload(a: &Signature, b: &Signature) -> bool {
+    a.type_params == b.type_params
+        && a.return_type == b.return_type
+        && a.params.len() == b.params.len()
+        && a.params.iter().zip(&b.params).all(|((_, a), (_, b))| a == b)
+}
+
+/// The type of a numeric literal, from its form and suffix
+fn number_type(literal: &str) -> String {
+    let literal = literal.to_ascii_lowercase();
+    let hex = literal.starts_with("0x");
+    if literal.contains('.') || (!hex && literal.contains('e')) {
+        return if literal.ends_with('f') { "float" } else { "double" }.to_string();
+    }
+    let suffix = &literal[literal.trim_end_matches(['u', 'l']).len()..];
+    let base = match suffix.matches('l').count() {
+        2 => "long long",
+        1 => "long",
+        _ => "int",
+    };
+    if suffix.contains('u') {
+        format!("unsigned {base}")
+    } else {
+        base.to_string()
+    }
+}
+
+/// [`normalize_type`], with `*`, `&` and `[]` attached to what they
+/// modify: `const std::string &` becomes `const std::string&`
+fn declarator_type(text: &str) -> String {
+    normalize_type(text)
+        .replace(" *", "*")
+        .replace(" &", "&")
+        .replace(" [", "[")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn translate(source: &str) -> AbstractAST {
+        CppTranslator::new().translate(source, "app").unwrap()
+    }
+
+    fn ids(ast: &AbstractAST) -> Vec<String> {
+        ast.functions.iter().map(|f| f.fn_id().to_string()).collect()
+    }
+
+    #[test]
+    fn test_overloads_get_distinct_ids() {
+        let source = "double scale(double value, int factor);\n\
+                      double scale(double value, double factor) { return value * factor; }\n\
+                      int* find(const std::string &key, int n = 3, ...);\n\
+                      void run(void);\n\
+                      template <typename T, int N = 4> T pick(const T *items, int count = N);\n";
+        let ast = translate(source);
+        assert_eq!(
+            ids(&ast),
+            [
+                "::scale::(value: double, factor: int) -> double",
+                "::scale::(value: double, factor: double) -> double",
+                "::find::(key: const std::string&, n: int, _: ...) -> int*",
+                "::run::() -> void",
+                "::pick::<typename T, int N>(items: const T*, count: int) -> T",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_namespaces_and_classes_are_modules() {
+        let source = "namespace geo {\n\
+                      class Circle : public Shape {\n\
+                      public:\n\
+                      \texplicit Circle(double radius);\n\
+                      \tdouble area() const override;\n\
+                      \tdouble area() &&;\n\
+                      \tstatic Circle* make(double);\n\
+                      \tvoid draw() { area(); }\n\
+                      };\n\
+                      namespace io { void print(const Circle&); }\n\
+                      }\n";
+        let ast = translate(source);
+        assert_eq!(
+            ids(&ast),
+            [
+                "geo::Circle::Circle::(radius: double) -> ()",
+                "geo::Circle::area::(this: const Circle&) -> double",
+                "geo::Circle::area::(this: Circle&&) -> double",
+                "geo::Circle::make::(_: double) -> Circle*",
+                "geo::Circle::draw::() -> void",
+                "geo::io::print::(_: const Circle&) -> void",
+            ]
+        );
+        assert_eq!(ast.imports, [Import::glob("geo::Circle".into(), "Shape".into())]);
+    }
+
+    #[test]
+    fn test_declarations_pair_with_out_of_line_definitions() {
+        let source = "namespace geo {\n\
+                      class Circle {\n\tdouble area() const;\n};\n\
+                      template <typename T> class Stack {\n\tvoid push(T);\n};\n\
+                      }\n\
+                      double geo::Circle::area() const { return pi(); }\n\
+                      namespace geo {\ntemplate <typename T> void Stack<T>::push(T value) { grow(); }\n}\n";
+        let ast = translate(source);
+        assert_eq!(
+            ids(&ast),
+            ["geo::Circle::area::(this: const Circle&) -> double", "geo::Stack::push::(value: T) -> void"]
+        );
+        let area = &ast.functions[0];
+        assert_eq!(area.span.map(|s| s.start_line), Some(9));
+        assert_eq!(area.calls[0].target_name, "pi");
+    }
+
+    #[test]
+    fn test_calls_record_argument_types_from_casts_and_literals() {
+        let source = "void run(int x) { f(static_cast<double>(x), (long)x, 2, 2.5f, 3ul, \"s\", 'c', true, x); }";
+        let ast = translate(source);
+        let types = &ast.get_function("run").unwrap().calls[0].arg_types;
+        assert_eq!(
+            types.iter().map(Option::as_deref).collect::<Vec<_>>(),
+            [
+                Some("double"),
+                Some("long"),
+                Some("int"),
+                Some("float"),
+                Some("unsigned long"),
+                Some("const char*"),
+                Some("char"),
+                Some("bool"),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_internal_linkage_is_scoped_by_file() {
+        let source = "static int clamp(int v) { return v; }\n\
+                      namespace geo { namespace { double square(double x) { return x * x; } } }\n\
+                      int main() { return clamp(1) + geo::square(2); }\n";
+        let ast = translate(source);
+        assert_eq!(
+            ids(&ast),
+            ["app::clamp::(v: int) -> int", "app::geo::square::(x: double) -> double", "::main::() -> int"]
+        );
+        let main = ast.get_function("main").unwrap();
+        assert_eq!(main.calls[0].target_module.as_deref(), Some("app"));
+        assert_eq!(main.calls[1].target_module.as_deref(), Some("app::geo"));
+        assert!(ast.imports.contains(&Import::glob("app::geo".into(), "::geo".into())));
+    }
+
+    #[test]
+    fn test_calls_record_qualifiers() {
+        let source = "void run(geo::Circle& c, Shape* s) {\n\
+                      \tc.area(); s->draw(); this->reset(); geo::Circle::make(1.0);\n\
+                      \tSquare sq(2.0); sq.area();\n\
+                      \tauto p = new geo::Circle(3.0); p->area();\n\
+                      \tmax<int>(1, 2); [&] { helper(); }(); static_cast<int>(2.0);\n\
+                      \tstd::sort(v.begin(), v.end()); app.config.load();\n\
+                      }\n";
+        let ast = translate(source);
+        let calls: Vec<(&str, Option<&str>)> = ast.functions[0]
+            .calls
+            .iter()
+            .map(|c| (c.target_name.as_str(), c.qualifier.as_deref()))
+            .collect();
+        assert_eq!(
+            calls,
+            [
+                ("area", Some("geo::Circle")),
+                ("draw", Some("Shape")),
+                ("reset", None),
+                ("make", Some("geo::Circle")),
+                ("Square", Some("Square")),
+                ("area", Some("Square")),
+                ("Circle", Some("geo::Circle")),
+                ("area", Some("p")),
+                ("max", None),
+                ("helper", None),
+                ("sort", Some("std")),
+                ("begin", Some("v")),
+                ("end", Some("v")),
+                ("load", Some("app::config")),
+            ]
+        );
+        let arg_counts: Vec<Option<usize>> = ast.functions[0].calls.iter().map(|c| c.arg_count).collect();
+        assert_eq!(arg_counts[3..9], [Some(1), Some(1), Some(0), Some(1), Some(0), Some(2)]);
+    }
+
+    #[test]
+    fn test_using_declarations_are_imports() {
+        let source = "using namespace geo;\nusing util::log;\nnamespace io { namespace fs = std::filesystem; }\n";
+        let ast = translate(source);
+        assert_eq!(
+            ast.imports,
+            [
+                Import::glob(String::new(), "geo".into()),
+                Import::item(String::new(), "util".into(), "log".into(), "log".into()),
+                Import::module("io".into(), "std::filesystem".into(), "fs".into()),
+            ]
+        );
+    }
+}
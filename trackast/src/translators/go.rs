@@ -0,0 +1,467 @@
+use std::collections::HashMap;
+use tree_sitter::{Node, Parser};
+use trackast_lib::ast::{AbstractAST, FunctionDef, Import, Signature};
+use super::{call_site, fold_function, normalize_type, span_of, CallSite};
+
+/// Translator for Go source code to abstract AST
+///
+/// Every file of a package shares one module path; the loader passes the
+/// package's import path, and a file translated on its own is named after
+/// its `package` clause. Methods are named `Type::Method` after their
+/// receiver's base type, and calls on a receiver or parameter of a named
+/// type are qualified by that type.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GoTranslator;
+
+impl GoTranslator {
+    /// Create a new Go translator
+    #[must_use]
+    pub fn new() -> Self {
+        GoTranslator
+    }
+
+    /// Set up a parser for Go
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the parser cannot be initialized or language set.
+    pub fn setup_parser() -> Result<Parser, String> {
+        let mut parser = Parser::new();
+        let language = tree_sitter_go::language();
+        parser
+            .set_language(language)
+            .map_err(|_| "Failed to set Go language".to_string())?;
+        Ok(parser)
+    }
+
+    /// Parse source code and return the tree
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if parsing fails.
+    pub fn parse_source(&self, source: &str) -> Result<tree_sitter::Tree, String> {
+        let mut parser = Self::setup_parser()?;
+        parser
+            .parse(source, None)
+            .ok_or_else(|| "Failed to parse source".to_string())
+    }
+
+    /// The name in a file's `package` clause
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if parsing fails or the file has no `package` clause.
+    pub fn package_name(&self, source: &str) -> Result<String, String> {
+        let tree = self.parse_source(source)?;
+        let root = tree.root_node();
+        let clause = root
+            .named_children(&mut root.walk())
+            .find(|child| child.kind() == "package_clause");
+        clause
+            .and_then(|clause| clause.named_child(0))
+            .map(|name| source[name.start_byte()..name.end_byte()].to_string())
+            .ok_or_else(|| "Missing package clause".to_string())
+    }
+
+    /// Translate Go source to abstract AST
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if parsing fails.
+    pub fn translate(&self, source: &str, module_path: &str) -> Result<AbstractAST, String> {
+        let tree = self.parse_source(source)?;
+        let root = tree.root_node();
+        let mut ast = AbstractAST::new(module_path.to_string());
+
+        for node in root.named_children(&mut root.walk()) {
+            match node.kind() {
+                "import_declaration" => Self::extract_imports(node, source, module_path, &mut ast),
+                "function_declaration" | "method_declaration" => {
+                    if let Some(func_def) = Self::extract_function(node, source, module_path) {
+                        Self::add_function(&mut ast, func_def);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(ast)
+    }
+
+    /// Add a definition, folding it into an earlier one with the same name
+    /// and signature
+    ///
+    /// A package may declare several `init` functions, and files excluded by
+    /// build constraints often define the same function for another platform.
+    pub(crate) fn add_function(ast: &mut AbstractAST, func_def: FunctionDef) {
+        fold_function(ast, func_def, Self::same_function);
+    }
+
+    /// Whether two definitions share their module, name and signature
+    fn same_function(a: &FunctionDef, b: &FunctionDef) -> bool {
+        a.module == b.module && a.name == b.name && a.signature == b.signature
+    }
+
+    /// Record the bindings of an `import` declaration
+    ///
+    /// Sources keep the import path as written (`net/http`); a package is
+    /// bound to its explicit name, else to the last path element without a
+    /// major version suffix. Dot imports become globs; blank imports bind
+    /// nothing.
+    fn extract_imports(node: Node, source: &str, module: &str, ast: &mut AbstractAST) {
+        let text = |n: Node| source[n.start_byte()..n.end_byte()].to_string();
+        let mut specs = Vec::new();
+        for child in node.named_children(&mut node.walk()) {
+            match child.kind() {
+                "import_spec" => specs.push(child),
+                "import_spec_list" => specs.extend(child.named_children(&mut child.walk()).filter(|c| c.kind() == "import_spec")),
+                _ => {}
+            }
+        }
+
+        for spec in specs {
+            let Some(path) = spec.child_by_field_name("path") else {
+                continue;
+            };
+            let path = text(path).trim_matches(|c| c == '"' || c == '`').to_string();
+            let import = match spec.child_by_field_name("name") {
+                Some(name) if name.kind() == "dot" => Import::glob(module.to_string(), path),
+                Some(name) if name.kind() == "blank_identifier" => continue,
+                Some(name) => Import::module(module.to_string(), path, text(name)),
+                None => {
+                    let alias = Self::default_package_name(&path);
+                    Import::module(module.to_string(), path, alias)
+                }
+            };
+            ast.add_import(import);
+        }
+    }
+
+    /// The name a package is imported as by default: the last path element,
+    /// skipping a `/vN` major version and dropping a `.vN` suffix
+    /// (`gopkg.in/yaml.v3` is `yaml`)
+    pub(crate) fn default_package_name(path: &str) -> String {
+        let is_version = |segment: &str| {
+            segment.strip_prefix('v').is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+        };
+        let mut segments = path.rsplit('/');
+        let last = segments.next().unwrap_or(path);
+        let last = if is_version(last) { segments.next().unwrap_or(last) } else { last };
+        match last.rsplit_once('.') {
+            Some((name, version)) if is_version(version) => name.to_string(),
+            _ => last.to_string(),
+        }
+    }
+
+    /// Build the definition of a function or method declaration
+    fn extract_function(node: Node, source: &str, module: &str) -> Option<FunctionDef> {
+        let name_node = node.child_by_field_name("name")?;
+        let mut name = source[name_node.start_byte()..name_node.end_byte()].to_string();
+
+        let mut params = Vec::new();
+        if let Some(receiver) = node.child_by_field_name("receiver") {
+            let receiver_params = Self::parameters(receiver, source);
+            let receiver_type = receiver_params.first().and_then(|(_, ty)| Self::named_type(ty))?;
+            name = format!("{receiver_type}::{name}");
+            params.extend(receiver_params);
+        }
+        if let Some(parameters) = node.child_by_field_name("parameters") {
+            params.extend(Self::parameters(parameters, source));
+        }
+
+        let return_type = node
+            .child_by_field_name("result")
+            .map_or_else(|| "()".to_string(), |result| normalize_type(&source[result.start_byte()..result.end_byte()]));
+        let type_params = node
+            .child_by_field_name("type_parameters")
+            .map(|list| Self::parameters(list, source).into_iter().map(|(name, ty)| format!("{name} {ty}")).collect())
+            .unwrap_or_default();
+
+        // Calls through a receiver, parameter or local of a named type go
+        // through the type
+        let mut receivers: HashMap<String, String> = params
+            .iter()
+            .filter_map(|(param, ty)| Some((param.clone(), Self::named_type(ty)?)))
+            .collect();
+
+        let mut sites = Vec::new();
+        if let Some(body) = node.child_by_field_name("body") {
+            Self::local_types(body, source, &mut receivers);
+            Self::extract_calls_recursive(body, source, &mut sites);
+        }
+
+        let signature = Signature::new(params, return_type).with_type_params(type_params);
+        let mut func_def = FunctionDef::new(name, signature, module.to_string()).with_span(span_of(node));
+        for mut site in sites {
+            if let Some(ty) = site.qualifier.as_deref().and_then(|q| receivers.get(q)) {
+                site.qualifier = Some(ty.clone());
+            }
+            func_def.add_call(site.into_call(None));
+        }
+        Some(func_def)
+    }
+
+    /// `(name, type)` pairs of a parameter list, one per name
+    ///
+    /// `a, b int` declares two parameters; unnamed ones are called `_` and
+    /// variadic ones have type `...T`.
+    fn parameters(list: Node, source: &str) -> Vec<(String, String)> {
+        let text = |n: Node| normalize_type(&source[n.start_byte()..n.end_byte()]);
+        let mut params = Vec::new();
+        for param in list.named_children(&mut list.walk()) {
+            let Some(ty) = param.child_by_field_name("type").map(text) else {
+                continue;
+            };
+            let ty = match param.kind() {
+                "variadic_parameter_declaration" => format!("...{ty}"),
+                "parameter_declaration" => ty,
+                _ => continue,
+            };
+            let names: Vec<String> = param.children_by_field_name("name", &mut param.walk()).map(text).collect();
+            if names.is_empty() {
+                params.push(("_".to_string(), ty));
+            } else {
+                params.extend(names.into_iter().map(|name| (name, ty.clone())));
+            }
+        }
+        params
+    }
+
+    /// Record the type of each local declared with `:=` or `var` from a value
+    ///
+    /// A composite literal (`Cart{}`, `&Cart{}`) gives its type. A call gives
+    /// the callee followed by `()`, as in `cart::New()`, which the resolver
+    /// replaces with the callee's return type. Only the first name of a
+    /// multi-value declaration is typed, as in `c, err := cart.Load()`.
+    fn local_types(node: Node, source: &str, locals: &mut HashMap<String, String>) {
+        let (names, values) = match node.kind() {
+            "short_var_declaration" => (node.child_by_field_name("left"), node.child_by_field_name("right")),
+            "var_spec" => (Some(node), node.child_by_field_name("value")),
+            _ => (None, None),
+        };
+        let name = names.and_then(|names| names.named_children(&mut names.walk()).find(|n| n.kind() == "identifier"));
+        let value = values.and_then(|values| values.named_child(0));
+        if let (Some(name), Some(value)) = (name, value) {
+            if let Some(ty) = Self::value_type(value, source) {
+                locals.insert(source[name.start_byte()..name.end_byte()].to_string(), ty);
+            }
+        }
+
+        for child in node.children(&mut node.walk()) {
+            Self::local_types(child, source, locals);
+        }
+    }
+
+    /// The named type of an expression a local is initialized with, if known
+    fn value_type(value: Node, source: &str) -> Option<String> {
+        match value.kind() {
+            "composite_literal" => {
+                let ty = value.child_by_field_name("type")?;
+                Self::named_type(&source[ty.start_byte()..ty.end_byte()])
+            }
+            "unary_expression" => Self::value_type(value.child_by_field_name("operand")?, source),
+            "call_expression" => {
+                let function = value.child_by_field_name("function")?;
+                Some(format!("{}()", Self::operand_path(function, source)?))
+            }
+            _ => None,
+        }
+    }
+
+    /// Recursively find function calls, including those in function literals
+    fn extract_calls_recursive(node: Node, source: &str, calls: &mut Vec<CallSite>) {
+        if node.kind() == "call_expression" {
+            if let Some(function) = node.child_by_field_name("function") {
+                match function.kind() {
+                    "identifier" => {
+                        calls.push(call_site(&source[function.start_byte()..function.end_byte()], function));
+                    }
+                    // `pkg.Func()`, `value.Method()`, `a.b.Method()`
+                    "selector_expression" => {
+                        if let Some(field) = function.child_by_field_name("field") {
+                            let qualifier = function
+                                .child_by_field_name("operand")
+                                .and_then(|operand| Self::operand_path(operand, source));
+                            let name = &source[field.start_byte()..field.end_byte()];
+                            calls.push(call_site(name, field).qualified(qualifier));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        for child in node.children(&mut node.walk()) {
+            Self::extract_calls_recursive(child, source, calls);
+        }
+    }
+
+    /// The `::`-joined path of an identifier or chain of selectors, e.g. `s::repo`
+    fn operand_path(node: Node, source: &str) -> Option<String> {
+        match node.kind() {
+            "identifier" => Some(source[node.start_byte()..node.end_byte()].to_string()),
+            "selector_expression" => {
+                let operand = Self::operand_path(node.child_by_field_name("operand")?, source)?;
+                let field = node.child_by_field_name("field")?;
+                Some(format!("{operand}::{}", &source[field.start_byte()..field.end_byte()]))
+            }
+            _ => None,
+        }
+    }
+
+    /// The named type behind a parameter type, without pointers and type
+    /// arguments: `*Stack[T]` gives `Stack`, `*cart.Cart` gives `cart::Cart`
+    pub(crate) fn named_type(ty: &str) -> Option<String> {
+        let base = ty.trim_start_matches('*');
+        let base = base.split('[').next().unwrap_or(base);
+        let is_name = !base.is_empty()
+            && base.split('.').all(|part| {
+                part.starts_with(|c: char| c.is_alphabetic() || c == '_') && part.chars().all(|c| c.is_alphanumeric() || c == '_')
+            });
+        is_name.then(|| base.replace('.', "::"))
+    }
+
+    /// Translate a Go file to abstract AST
+    ///
+    /// Without a module path the file is named after its `package` clause.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if file reading or parsing fails.
+    pub fn translate_file(&self, path: &str, module_path: Option<&str>) -> Result<AbstractAST, String> {
+        let source = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read file: {e}"))?;
+
+        let module = match module_path {
+            Some(m) => m.to_string(),
+            None => self.package_name(&source)?,
+        };
+
+        let mut ast = self.translate(&source, &module)?;
+
+        ast.set_source_file(path);
+
+        Ok(ast)
+    }
+}
+
+impl crate::translator_trait::Translator for GoTranslator {
+    fn translate_file(&self, path: &str, module_path: Option<&str>) -> Result<AbstractAST, String> {
+        GoTranslator::translate_file(self, path, module_path)
+    }
+
+    fn same_definition(&self, earlier: &FunctionDef, later: &FunctionDef) -> bool {
+        Self::same_function(earlier, later)
+    }
+
+    fn add_function(&self, ast: &mut AbstractAST, func_def: FunctionDef) {
+        Self::add_function(ast, func_def);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn translate(source: &str) -> AbstractAST {
+        GoTranslator::new().translate(source, "shop::cart").unwrap()
+    }
+
+    fn signature_of(ast: &AbstractAST, name: &str) -> String {
+        ast.get_function(name).unwrap().signature.to_string()
+    }
+
+    #[test]
+    fn test_package_name() {
+        let source = "// Package cart holds carts.\npackage cart\n\nfunc New() {}\n";
+        assert_eq!(GoTranslator::new().package_name(source).unwrap(), "cart");
+    }
+
+    #[test]
+    fn test_signatures() {
+        let source = "package cart\n\
+                      func Sum(a, b int, _ string, rest ...float64) (total int, err error) { return }\n\
+                      func Run(int, string) {}\n\
+                      func Keys[K comparable, V any](m map[K]V) []K { return nil }\n";
+        let ast = translate(source);
+        assert_eq!(
+            signature_of(&ast, "Sum"),
+            "(a: int, b: int, _: string, rest: ...float64) -> (total int, err error)"
+        );
+        assert_eq!(signature_of(&ast, "Run"), "(_: int, _: string) -> ()");
+        assert_eq!(signature_of(&ast, "Keys"), "<K comparable, V any>(m: map[K]V) -> []K");
+    }
+
+    #[test]
+    fn test_methods_are_scoped_by_receiver_type() {
+        let source = "package cart\n\
+                      func (c *Cart) Add(item Item) error {\n\tc.validate(item)\n\treturn nil\n}\n\
+                      func (Cart) validate(Item) {}\n\
+                      func (s *Stack[T]) Push(v T) {}\n";
+        let ast = translate(source);
+        assert_eq!(signature_of(&ast, "Cart::Add"), "(c: *Cart, item: Item) -> error");
+        assert_eq!(signature_of(&ast, "Cart::validate"), "(_: Cart, _: Item) -> ()");
+        assert_eq!(signature_of(&ast, "Stack::Push"), "(s: *Stack[T], v: T) -> ()");
+
+        let validate = &ast.get_function("Cart::Add").unwrap().calls[0];
+        assert_eq!(validate.target_name, "validate");
+        assert_eq!(validate.qualifier.as_deref(), Some("Cart"));
+    }
+
+    #[test]
+    fn test_calls_record_qualifiers() {
+        let source = "package main\n\
+                      func main() {\n\tfmt.Println(str.ToUpper(\"x\"))\n\tf := func() { helper() }\n\tf()\n\ts.repo.Save()\n}\n\
+                      func checkout(c *cart.Cart) { c.Total() }\n";
+        let ast = translate(source);
+        let calls = &ast.get_function("main").unwrap().calls;
+        let qualifier = |name: &str| calls.iter().find(|c| c.target_name == name).unwrap().qualifier.clone();
+        assert_eq!(qualifier("Println").as_deref(), Some("fmt"));
+        assert_eq!(qualifier("ToUpper").as_deref(), Some("str"));
+        assert_eq!(qualifier("helper"), None);
+        assert_eq!(qualifier("Save").as_deref(), Some("s::repo"));
+
+        let total = &ast.get_function("checkout").unwrap().calls[0];
+        assert_eq!(total.qualifier.as_deref(), Some("cart::Cart"));
+    }
+
+    #[test]
+    fn test_locals_are_typed_by_their_value() {
+        let source = "package main\n\
+                      func main() {\n\tc := cart.New()\n\tc.Add()\n\tvar s = &Stack{}\n\ts.Push()\n\tn := len(x)\n\tn.Bad()\n}\n";
+        let ast = translate(source);
+        let calls = &ast.get_function("main").unwrap().calls;
+        let qualifier = |name: &str| calls.iter().find(|c| c.target_name == name).unwrap().qualifier.clone();
+        assert_eq!(qualifier("Add").as_deref(), Some("cart::New()"));
+        assert_eq!(qualifier("Push").as_deref(), Some("Stack"));
+        assert_eq!(qualifier("Bad").as_deref(), Some("len()"));
+    }
+
+    #[test]
+    fn test_imports_are_recorded() {
+        let source = "package main\n\
+                      import \"fmt\"\n\
+                      import (\n\tstr \"strings\"\n\t. \"example.com/shop/dsl\"\n\t_ \"embed\"\n\t\"gopkg.in/yaml.v3\"\n\t\"github.com/jackc/pgx/v5\"\n)\n";
+        let ast = translate(source);
+        let module = || "shop::cart".to_string();
+        assert_eq!(
+            ast.imports,
+            vec![
+                Import::module(module(), "fmt".into(), "fmt".into()),
+                Import::module(module(), "strings".into(), "str".into()),
+                Import::glob(module(), "example.com/shop/dsl".into()),
+                Import::module(module(), "gopkg.in/yaml.v3".into(), "yaml".into()),
+                Import::module(module(), "github.com/jackc/pgx/v5".into(), "pgx".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_repeated_init_functions_are_folded() {
+        let source = "package main\nfunc init() { a() }\nfunc init() { b() }\n";
+        let ast = translate(source);
+        let inits: Vec<&FunctionDef> = ast.functions.iter().filter(|f| f.name == "init").collect();
+        assert_eq!(inits.len(), 1);
+        assert_eq!(inits[0].calls.len(), 2);
+    }
+}
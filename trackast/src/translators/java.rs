@@ -0,0 +1,674 @@
+use std::collections::HashMap;
+use tree_sitter::{Node, Parser};
+use trackast_lib::ast::{AbstractAST, FunctionDef, Import, Signature};
+use super::{call_site, normalize_type, span_of, CallSite};
+
+/// Name of constructors, as the JVM calls them
+pub const CONSTRUCTOR: &str = "<init>";
+
+/// Name of the function folding a class's static initializers
+pub const STATIC_INITIALIZER: &str = "<clinit>";
+
+/// Declarations whose bodies hold members
+const TYPE_DECLARATIONS: [&str; 5] = [
+    "class_declaration",
+    "interface_declaration",
+    "enum_declaration",
+    "record_declaration",
+    "annotation_type_declaration",
+];
+
+/// Translator for Java source code to abstract AST
+///
+/// A class is the module path of its package followed by the classes it is
+/// nested in (`com::acme::shop::Cart::Line`), and its methods are named as
+/// written, with parameter types in the signature so overloads get distinct
+/// IDs. Constructors are `<init>` and static initializer blocks are folded
+/// into one `<clinit>`. The classes a class extends or implements are glob
+/// imports of its module, so inherited methods resolve like its own.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct JavaTranslator;
+
+/// The class a member is declared in
+#[derive(Debug, Clone, Default)]
+struct ClassScope {
+    module: String,
+    /// The superclass as written, which `super.m()` calls go through
+    superclass: Option<String>,
+    /// Types of the fields of this class and the classes enclosing it
+    fields: HashMap<String, String>,
+}
+
+/// A file being translated
+struct TranslationUnit<'a> {
+    source: &'a str,
+    /// Single-type imports, by simple name, as module paths
+    imported_types: HashMap<String, String>,
+    /// Imports of the file, recorded for each top-level class
+    imports: Vec<Import>,
+    ast: AbstractAST,
+}
+
+impl TranslationUnit<'_> {
+    fn text(&self, node: Node) -> &str {
+        &self.source[node.start_byte()..node.end_byte()]
+    }
+
+    /// The class a type names, as a module path if the file can tell:
+    /// `a.b.C` is `a::b::C` and a single-type import names its class.
+    /// Other simple names are left for the resolver, which looks them up
+    /// in the enclosing classes, the package and wildcard imports.
+    fn class_path(&self, ty: &str) -> String {
+        let ty = ty.replace('.', "::");
+        self.imported_types.get(&ty).cloned().unwrap_or(ty)
+    }
+}
+
+impl JavaTranslator {
+    /// Create a new Java translator
+    #[must_use]
+    pub fn new() -> Self {
+        JavaTranslator
+    }
+
+    /// Set up a parser for Java
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the parser cannot be initialized or language set.
+    pub fn setup_parser() -> Result<Parser, String> {
+        let mut parser = Parser::new();
+        let language = tree_sitter_java::language();
+        parser
+            .set_language(language)
+            .map_err(|_| "Failed to set Java language".to_string())?;
+        Ok(parser)
+    }
+
+    /// Parse source code and return the tree
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if parsing fails.
+    pub fn parse_source(&self, source: &str) -> Result<tree_sitter::Tree, String> {
+        let mut parser = Self::setup_parser()?;
+        parser
+            .parse(source, None)
+            .ok_or_else(|| "Failed to parse source".to_string())
+    }
+
+    /// The package a file declares, dotted (`com.acme.shop`); empty for the
+    /// default package
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if parsing fails.
+    pub fn package(&self, source: &str) -> Result<String, String> {
+        let tree = self.parse_source(source)?;
+        let root = tree.root_node();
+        let package = root
+            .named_children(&mut root.walk())
+            .find(|child| child.kind() == "package_declaration")
+            .and_then(|declaration| declaration.named_child(0))
+            .map(|name| source[name.start_byte()..name.end_byte()].split_whitespace().collect());
+        Ok(package.unwrap_or_default())
+    }
+
+    /// Translate Java source to abstract AST
+    ///
+    /// `module_path` is the package's module path (`com::acme::shop`), or
+    /// empty for the default package.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if parsing fails.
+    pub fn translate(&self, source: &str, module_path: &str) -> Result<AbstractAST, String> {
+        let tree = self.parse_source(source)?;
+        let root = tree.root_node();
+        let mut unit = TranslationUnit {
+            source,
+            imported_types: HashMap::new(),
+            imports: Vec::new(),
+            ast: AbstractAST::new(module_path.to_string()),
+        };
+
+        for node in root.named_children(&mut root.walk()) {
+            if node.kind() == "import_declaration" {
+                Self::extract_import(node, &mut unit);
+            }
+        }
+        for node in root.named_children(&mut root.walk()) {
+            let Some(name) = node.child_by_field_name("name").filter(|_| TYPE_DECLARATIONS.contains(&node.kind())) else {
+                continue;
+            };
+            // Before the class's supertypes, which the resolver may find
+            // through a wildcard import
+            let module = join(module_path, unit.text(name));
+            for import in unit.imports.clone() {
+                unit.ast.add_import(Import { module: module.clone(), ..import });
+            }
+            let outer = ClassScope {
+                module: module_path.to_string(),
+                ..ClassScope::default()
+            };
+            Self::extract_class(node, &outer, &mut unit);
+        }
+
+        Ok(unit.ast)
+    }
+
+    /// Add a definition, folding it into an earlier one with the same name
+    /// and signature, as several static initializer blocks are
+    fn add_function(ast: &mut AbstractAST, func_def: FunctionDef) {
+        let existing = ast.functions.iter_mut().find(|f| {
+            f.module == func_def.module && f.name == func_def.name && f.signature == func_def.signature
+        });
+        match existing {
+            Some(existing) => existing.calls.extend(func_def.calls),
+            None => ast.add_function(func_def),
+        }
+    }
+
+    /// Record an `import` declaration
+    ///
+    /// `import a.b.C;` binds `C` to the class `a::b::C` and `import a.b.*;`
+    /// is a glob of the package. Static imports bind members the same way:
+    /// `import static a.b.C.max;` binds `max` in `a::b::C`. The module field
+    /// is filled in for each top-level class of the file.
+    fn extract_import(node: Node, unit: &mut TranslationUnit) {
+        let Some(name) = node.named_child(0) else {
+            return;
+        };
+        let path = unit.text(name).split_whitespace().collect::<String>().replace('.', "::");
+        let is_static = node.children(&mut node.walk()).any(|child| child.kind() == "static");
+        let is_glob = node.named_children(&mut node.walk()).any(|child| child.kind() == "asterisk");
+
+        let import = if is_glob {
+            Import::glob(String::new(), path)
+        } else {
+            let (source, item) = path.rsplit_once("::").unwrap_or(("", &path));
+            if !is_static {
+                unit.imported_types.insert(item.to_string(), path.clone());
+            }
+            Import::item(String::new(), source.to_string(), item.to_string(), item.to_string())
+        };
+        unit.imports.push(import);
+    }
+
+    /// Translate a class, interface, enum or record and the classes nested
+    /// in it
+    fn extract_class(node: Node, outer: &ClassScope, unit: &mut TranslationUnit) {
+        let Some(name) = node.child_by_field_name("name") else {
+            return;
+        };
+        let module = join(&outer.module, unit.text(name));
+
+        // `extends` and `implements` clauses
+        let mut superclass = None;
+        for child in node.named_children(&mut node.walk()) {
+            let supertypes: Vec<Node> = match child.kind() {
+                "superclass" => child.named_children(&mut child.walk()).collect(),
+                "super_interfaces" | "extends_interfaces" => child
+                    .named_children(&mut child.walk())
+                    .filter(|list| list.kind() == "type_list")
+                    .flat_map(|list| list.named_children(&mut list.walk()).collect::<Vec<_>>())
+                    .collect(),
+                _ => continue,
+            };
+            for ty in supertypes {
+                let Some(ty) = Self::type_name(ty, unit.source) else {
+                    continue;
+                };
+                let base = unit.class_path(&ty);
+                if child.kind() == "superclass" {
+                    superclass = Some(base.clone());
+                }
+                unit.ast.add_import(Import::glob(module.clone(), base));
+            }
+        }
+
+        let Some(body) = node.child_by_field_name("body") else {
+            return;
+        };
+        // Enum members follow the constants
+        let mut members: Vec<Node> = body.named_children(&mut body.walk()).collect();
+        if let Some(declarations) = members.iter().position(|member| member.kind() == "enum_body_declarations") {
+            let declarations = members.remove(declarations);
+            members.extend(declarations.named_children(&mut declarations.walk()));
+        }
+
+        let mut scope = ClassScope {
+            module: module.clone(),
+            superclass,
+            fields: outer.fields.clone(),
+        };
+        for member in &members {
+            if member.kind() == "field_declaration" {
+                Self::variable_types(*member, unit.source, &mut scope.fields);
+            }
+        }
+        // Record components are fields too
+        let components = node.child_by_field_name("parameters");
+        if let Some(components) = components {
+            Self::variable_types(components, unit.source, &mut scope.fields);
+        }
+
+        for member in members {
+            let func_def = match member.kind() {
+                "method_declaration" | "constructor_declaration" => Self::extract_method(member, member.child_by_field_name("parameters"), &scope, unit),
+                "compact_constructor_declaration" => Self::extract_method(member, components, &scope, unit),
+                "static_initializer" => Self::extract_method(member, None, &scope, unit),
+                kind if TYPE_DECLARATIONS.contains(&kind) => {
+                    Self::extract_class(member, &scope, unit);
+                    None
+                }
+                _ => None,
+            };
+            if let Some(func_def) = func_def {
+                Self::add_function(&mut unit.ast, func_def);
+            }
+        }
+    }
+
+    /// Build the definition of a method, constructor or static initializer
+    ///
+    /// `parameters` is the parameter list, which a compact constructor takes
+    /// from its record.
+    fn extract_method(node: Node, parameters: Option<Node>, scope: &ClassScope, unit: &TranslationUnit) -> Option<FunctionDef> {
+        let (name, return_type) = match node.kind() {
+            "method_declaration" => {
+                let name = unit.text(node.child_by_field_name("name")?).to_string();
+                let mut return_type = node
+                    .child_by_field_name("type")
+                    .map_or_else(|| "void".to_string(), |ty| java_type(unit.text(ty)));
+                if let Some(dimensions) = node.child_by_field_name("dimensions") {
+                    return_type.push_str(&java_type(unit.text(dimensions)));
+                }
+                (name, return_type)
+            }
+            "static_initializer" => (STATIC_INITIALIZER.to_string(), "void".to_string()),
+            _ => (CONSTRUCTOR.to_string(), "void".to_string()),
+        };
+
+        let params = parameters.map(|list| Self::parameters(list, unit.source)).unwrap_or_default();
+        let type_params = node
+            .child_by_field_name("type_parameters")
+            .map(|list| list.named_children(&mut list.walk()).map(|param| java_type(unit.text(param))).collect())
+            .unwrap_or_default();
+
+        let mut variables = scope.fields.clone();
+        if let Some(parameters) = parameters {
+            Self::variable_types(parameters, unit.source, &mut variables);
+        }
+        let body = node
+            .child_by_field_name("body")
+            .or_else(|| node.named_children(&mut node.walk()).find(|child| child.kind() == "block"));
+        let mut sites = Vec::new();
+        if let Some(body) = body {
+            Self::variable_types(body, unit.source, &mut variables);
+            Self::extract_calls_recursive(body, scope, &variables, unit, &mut sites);
+        }
+
+        let signature = Signature::new(params, return_type).with_type_params(type_params);
+        let mut func_def = FunctionDef::new(name, signature, scope.module.clone()).with_span(span_of(node));
+        for site in sites {
+            func_def.add_call(site.into_call(None));
+        }
+        Some(func_def)
+    }
+
+    /// `(name, type)` pairs of a parameter list; varargs have type `T...`
+    fn parameters(list: Node, source: &str) -> Vec<(String, String)> {
+        let text = |n: Node| java_type(&source[n.start_byte()..n.end_byte()]);
+        list.named_children(&mut list.walk())
+            .filter_map(|param| match param.kind() {
+                "formal_parameter" => {
+                    let mut ty = text(param.child_by_field_name("type")?);
+                    if let Some(dimensions) = param.child_by_field_name("dimensions") {
+                        ty.push_str(&text(dimensions));
+                    }
+                    Some((text(param.child_by_field_name("name")?), ty))
+                }
+                "spread_parameter" => {
+                    let ty = param.named_children(&mut param.walk()).find(|child| child.kind() != "modifiers")?;
+                    let declarator = param.named_children(&mut param.walk()).find(|child| child.kind() == "variable_declarator")?;
+                    Some((text(declarator.child_by_field_name("name")?), format!("{}...", text(ty))))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The class named by a type, without type arguments: `List<Item>` is
+    /// `List` and `a.b.C` is `a.b.C`; `None` for primitives, arrays and `var`
+    fn type_name(node: Node, source: &str) -> Option<String> {
+        match node.kind() {
+            "type_identifier" | "scoped_type_identifier" => {
+                let name: String = source[node.start_byte()..node.end_byte()].split_whitespace().collect();
+                (name != "var").then_some(name)
+            }
+            "generic_type" => Self::type_name(node.named_child(0)?, source),
+            _ => None,
+        }
+    }
+
+    /// Record the class of every field, parameter and local variable below
+    /// `node`, so calls through them can be qualified by the class
+    fn variable_types(node: Node, source: &str, variables: &mut HashMap<String, String>) {
+        match node.kind() {
+            "field_declaration" | "local_variable_declaration" => {
+                if let Some(ty) = node.child_by_field_name("type").and_then(|ty| Self::type_name(ty, source)) {
+                    for declarator in node.children_by_field_name("declarator", &mut node.walk()) {
+                        if let Some(name) = declarator.child_by_field_name("name") {
+                            variables.insert(source[name.start_byte()..name.end_byte()].to_string(), ty.replace('.', "::"));
+                        }
+                    }
+                }
+            }
+            "formal_parameter" | "catch_formal_parameter" | "enhanced_for_statement" => {
+                let ty = node.child_by_field_name("type").or_else(|| {
+                    node.named_children(&mut node.walk()).find(|child| child.kind() == "catch_type").and_then(|ty| ty.named_child(0))
+                });
+                let name = node.child_by_field_name("name");
+                if let (Some(ty), Some(name)) = (ty.and_then(|ty| Self::type_name(ty, source)), name) {
+                    variables.insert(source[name.start_byte()..name.end_byte()].to_string(), ty.replace('.', "::"));
+                }
+            }
+            _ => {}
+        }
+        // Members of local and anonymous classes are not in scope
+        if node.kind() == "class_body" {
+            return;
+        }
+        for child in node.named_children(&mut node.walk()) {
+            Self::variable_types(child, source, variables);
+        }
+    }
+
+    /// Recursively find calls, including those in lambdas and anonymous
+    /// classes
+    ///
+    /// Method invocations are qualified by the class of their receiver.
+    /// `new T(...)` and `T::new` call `T`'s constructor, `this(...)` calls
+    /// another constructor of the class and `super(...)` one of the
+    /// superclass. Method references are calls to the method they name.
+    fn extract_calls_recursive(
+        node: Node,
+        scope: &ClassScope,
+        variables: &HashMap<String, String>,
+        unit: &TranslationUnit,
+        calls: &mut Vec<CallSite>,
+    ) {
+        match node.kind() {
+            "method_invocation" => {
+                if let Some(name) = node.child_by_field_name("name") {
+                    let qualifier = node
+                        .child_by_field_name("object")
+                        .and_then(|object| Self::operand_path(object, scope, variables, unit));
+                    let site = call_site(unit.text(name), name).qualified(qualifier);
+                    calls.push(site.with_arguments(node.child_by_field_name("arguments")));
+                }
+            }
+            "object_creation_expression" => {
+                if let Some(ty) = node.child_by_field_name("type") {
+                    let qualifier = Self::type_name(ty, unit.source).map(|ty| ty.replace('.', "::"));
+                    let site = call_site(CONSTRUCTOR, ty).qualified(qualifier);
+                    calls.push(site.with_arguments(node.child_by_field_name("arguments")));
+                }
+            }
+            "explicit_constructor_invocation" => {
+                if let Some(constructor) = node.child_by_field_name("constructor") {
+                    let qualifier = match constructor.kind() {
+                        "super" => scope.superclass.clone(),
+                        _ => None,
+                    };
+                    let site = call_site(CONSTRUCTOR, constructor).qualified(qualifier);
+                    calls.push(site.with_arguments(node.child_by_field_name("arguments")));
+                }
+            }
+            // `Type::method`, `value::method`, `this::method`, `Type::new`
+            "method_reference" => {
+                let target = node.named_child(0);
+                let method = node.named_child(1);
+                let is_constructor = node.children(&mut node.walk()).any(|child| child.kind() == "new");
+                match (target, method) {
+                    (Some(target), Some(method)) => {
+                        let qualifier = Self::operand_path(target, scope, variables, unit);
+                        calls.push(call_site(unit.text(method), method).qualified(qualifier));
+                    }
+                    (Some(target), None) if is_constructor => {
+                        let qualifier = Self::type_name(target, unit.source)
+                            .or_else(|| (target.kind() == "identifier").then(|| unit.text(target).to_string()))
+                            .map(|ty| ty.replace('.', "::"));
+                        calls.push(call_site(CONSTRUCTOR, target).qualified(qualifier));
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+
+        for child in node.children(&mut node.walk()) {
+            Self::extract_calls_recursive(child, scope, variables, unit, calls);
+        }
+    }
+
+    /// The qualifier of a call through `node`: the class of a known
+    /// variable or field, the superclass for `super`, else the `::`-joined
+    /// path of an identifier or field chain (`a.b.C` is `a::b::C`); `None`
+    /// for `this` and other expressions
+    fn operand_path(
+        node: Node,
+        scope: &ClassScope,
+        variables: &HashMap<String, String>,
+        unit: &TranslationUnit,
+    ) -> Option<String> {
+        match node.kind() {
+            "identifier" | "type_identifier" | "scoped_type_identifier" | "generic_type" => {
+                let name = Self::type_name(node, unit.source).unwrap_or_else(|| unit.text(node).to_string());
+                Some(variables.get(&name).cloned().unwrap_or_else(|| name.replace('.', "::")))
+            }
+            "super" => scope.superclass.clone(),
+            "field_access" => {
+                let object = node.child_by_field_name("object")?;
+                let field = unit.text(node.child_by_field_name("field")?);
+                match object.kind() {
+                    "this" => Some(scope.fields.get(field).cloned().unwrap_or_else(|| field.to_string())),
+                    _ => Some(format!("{}::{field}", Self::operand_path(object, scope, variables, unit)?)),
+                }
+            }
+            "parenthesized_expression" => Self::operand_path(node.named_child(0)?, scope, variables, unit),
+            _ => None,
+        }
+    }
+
+    /// Translate a Java file to abstract AST
+    ///
+    /// Without a module path the file's classes are placed in the package it
+    /// declares.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if file reading or parsing fails.
+    pub fn translate_file(&self, path: &str, module_path: Option<&str>) -> Result<AbstractAST, String> {
+        let source = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read file: {e}"))?;
+
+        let module = match module_path {
+            Some(m) => m.to_string(),
+            None => self.package(&source)?.replace('.', "::"),
+        };
+
+        let mut ast = self.translate(&source, &module)?;
+
+        ast.set_source_file(path);
+
+        Ok(ast)
+    }
+}
+
+impl crate::translator_trait::Translator for JavaTranslator {
+    fn translate_file(&self, path: &str, module_path: Option<&str>) -> Result<AbstractAST, String> {
+        JavaTranslator::translate_file(self, path, module_path)
+    }
+}
+
+/// Join a module path and a class name; the default package is empty
+fn join(module: &str, name: &str) -> String {
+    if module.is_empty() {
+        name.to_string()
+    } else {
+        format!("{module}::{name}")
+    }
+}
+
+/// [`normalize_type`], with `[]` and `...` attached to the type they
+/// modify: `String []` becomes `String[]`
+fn java_type(text: &str) -> String {
+    normalize_type(text)
+        .replace(" [", "[")
+        .replace(" ...", "...")
+        .replace("< ", "<")
+        .replace(" >", ">")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn translate(source: &str) -> AbstractAST {
+        JavaTranslator::new().translate(source, "com::acme").unwrap()
+    }
+
+    fn ids(ast: &AbstractAST) -> Vec<String> {
+        ast.functions.iter().map(|f| f.fn_id().to_string()).collect()
+    }
+
+    fn calls_of(ast: &AbstractAST, module: &str, name: &str) -> Vec<(String, Option<String>)> {
+        let func = ast.functions.iter().find(|f| f.module == module && f.name == name).unwrap();
+        func.calls.iter().map(|c| (c.target_name.clone(), c.qualifier.clone())).collect()
+    }
+
+    #[test]
+    fn test_package() {
+        let translator = JavaTranslator::new();
+        assert_eq!(translator.package("package com . acme.shop;\nclass A {}").unwrap(), "com.acme.shop");
+        assert_eq!(translator.package("class A {}").unwrap(), "");
+    }
+
+    #[test]
+    fn test_overloads_and_constructors_get_distinct_ids() {
+        let source = "class Cart {\n\
+                      \tCart() { this(10); }\n\
+                      \tCart(int capacity) {}\n\
+                      \tvoid add(String sku) {}\n\
+                      \tvoid add(String sku, int quantity) {}\n\
+                      \tstatic int[] sizes(String... names) { return null; }\n\
+                      \t<T extends Comparable<T>> T max(java.util.List<T> items, T fallback) { return fallback; }\n\
+                      \tstatic { init(); }\n\
+                      \tstatic { reset(); }\n\
+                      }\n";
+        let ast = translate(source);
+        assert_eq!(
+            ids(&ast),
+            [
+                "com::acme::Cart::<init>::() -> void",
+                "com::acme::Cart::<init>::(capacity: int) -> void",
+                "com::acme::Cart::add::(sku: String) -> void",
+                "com::acme::Cart::add::(sku: String, quantity: int) -> void",
+                "com::acme::Cart::sizes::(names: String...) -> int[]",
+                "com::acme::Cart::max::<T extends Comparable<T>>(items: java.util.List<T>, fallback: T) -> T",
+                "com::acme::Cart::<clinit>::() -> void",
+            ]
+        );
+        assert_eq!(calls_of(&ast, "com::acme::Cart", "<clinit>"), [("init".into(), None), ("reset".into(), None)]);
+    }
+
+    #[test]
+    fn test_nested_classes_are_modules() {
+        let source = "interface Shape { double area(); }\n\
+                      enum Unit { CM, IN; double factor() { return 1; } }\n\
+                      record Point(double x, double y) { Point { check(x); } }\n\
+                      class Outer { static class Inner { class Deepest { void run() {} } } }\n";
+        let ast = translate(source);
+        assert_eq!(
+            ids(&ast),
+            [
+                "com::acme::Shape::area::() -> double",
+                "com::acme::Unit::factor::() -> double",
+                "com::acme::Point::<init>::(x: double, y: double) -> void",
+                "com::acme::Outer::Inner::Deepest::run::() -> void",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_imports_and_supertypes() {
+        let source = "import java.util.List;\n\
+                      import com.acme.util.*;\n\
+                      import static com.acme.util.Strings.capitalize;\n\
+                      class Circle extends com.acme.geo.Shape implements Comparable<Circle>, Drawable {}\n\
+                      class Square extends Base {}\n";
+        let ast = translate(source);
+        let imports: Vec<(&str, &str, Option<&str>)> = ast
+            .imports
+            .iter()
+            .map(|i| (i.module.as_str(), i.source.as_str(), i.name.as_deref()))
+            .collect();
+        assert_eq!(
+            imports,
+            [
+                ("com::acme::Circle", "java::util", Some("List")),
+                ("com::acme::Circle", "com::acme::util", Some("*")),
+                ("com::acme::Circle", "com::acme::util::Strings", Some("capitalize")),
+                ("com::acme::Circle", "com::acme::geo::Shape", Some("*")),
+                ("com::acme::Circle", "Comparable", Some("*")),
+                ("com::acme::Circle", "Drawable", Some("*")),
+                ("com::acme::Square", "java::util", Some("List")),
+                ("com::acme::Square", "com::acme::util", Some("*")),
+                ("com::acme::Square", "com::acme::util::Strings", Some("capitalize")),
+                ("com::acme::Square", "Base", Some("*")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_calls_are_qualified_by_receiver_class() {
+        let source = "import com.acme.pricing.Pricing;\n\
+                      class Checkout extends Base {\n\
+                      \tprivate final Pricing pricing = new Pricing();\n\
+                      \tCheckout(Cart cart) {\n\
+                      \t\tsuper(cart);\n\
+                      \t\tList<Item> items = cart.items();\n\
+                      \t\tfor (Item item : items) { item.price(); }\n\
+                      \t\tthis.pricing.total(items);\n\
+                      \t\tsuper.log();\n\
+                      \t\tvalidate();\n\
+                      \t\tcom.acme.util.Strings.trim(\"x\");\n\
+                      \t\titems.forEach(Line::new);\n\
+                      \t\titems.stream().map(this::describe);\n\
+                      \t\tRunnable r = () -> pricing.reset();\n\
+                      \t}\n\
+                      }\n";
+        let ast = translate(source);
+        let some = |s: &str| Some(s.to_string());
+        assert_eq!(
+            calls_of(&ast, "com::acme::Checkout", CONSTRUCTOR),
+            [
+                ("<init>".into(), some("Base")),
+                ("items".into(), some("Cart")),
+                ("price".into(), some("Item")),
+                ("total".into(), some("Pricing")),
+                ("log".into(), some("Base")),
+                ("validate".into(), None),
+                ("trim".into(), some("com::acme::util::Strings")),
+                ("forEach".into(), some("List")),
+                ("<init>".into(), some("Line")),
+                ("map".into(), None),
+                ("stream".into(), some("List")),
+                ("describe".into(), None),
+                ("reset".into(), some("Pricing")),
+            ]
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use tree_sitter::Parser;
-use trackast_lib::ast::{AbstractAST, FunctionDef, Signature, FunctionCall};
+use trackast_lib::ast::{AbstractAST, FunctionDef, Import, Signature};
+use super::{call_site, span_of, CallSite};
 
 /// Translator for JavaScript/TypeScript source code to abstract AST
 pub struct JavaScriptTranslator;
@@ -113,14 +114,14 @@ impl JavaScriptTranslator {
         let mut calls = Vec::new();
 
         Self::extract_calls_recursive(root, source, &mut calls);
-        Ok(calls)
+        Ok(calls.into_iter().map(|site| site.name).collect())
     }
 
     /// Recursively find function calls in the tree
-    fn extract_calls_recursive(
+    pub(crate) fn extract_calls_recursive(
         node: tree_sitter::Node,
         source: &str,
-        calls: &mut Vec<String>,
+        calls: &mut Vec<CallSite>,
     ) {
         // Look for call_expression nodes
         if node.kind() == "call_expression" {
@@ -130,7 +131,7 @@ impl JavaScriptTranslator {
                     "identifier" => {
                         // Direct function call: function_name()
                         let name = &source[child.start_byte()..child.end_byte()];
-                        calls.push(name.to_string());
+                        calls.push(call_site(name, child));
                     }
                     "member_expression" => {
                         // Member access call: obj.method() or this.method()
@@ -166,7 +167,7 @@ impl JavaScriptTranslator {
                                                 let name = &source[arg_child.start_byte()..arg_child.end_byte()];
                                                 // Filter out common Express middleware parameter names
                                                 if name != "req" && name != "res" && name != "next" && name != "err" {
-                                                    calls.push(name.to_string());
+                                                    calls.push(call_site(name, arg_child));
                                                 }
                                             }
                                         }
@@ -198,12 +199,10 @@ impl JavaScriptTranslator {
                 // Find the right side of the assignment
                 for i in 0..node.child_count() {
                     if let Some(child) = node.child(i) {
-                        // Skip the assignment operator and member_expression
-                        if child.kind() != "=" && child.kind() != "member_expression" {
-                            if child.kind() == "identifier" {
-                                let name = &source[child.start_byte()..child.end_byte()];
-                                calls.push(name.to_string());
-                            }
+                        // Only the identifier on the right-hand side is a reference
+                        if child.kind() == "identifier" {
+                            let name = &source[child.start_byte()..child.end_byte()];
+                            calls.push(call_site(name, child));
                         }
                     }
                 }
@@ -219,75 +218,149 @@ impl JavaScriptTranslator {
     fn extract_member_call(
         member_node: tree_sitter::Node,
         source: &str,
-        calls: &mut Vec<String>,
+        calls: &mut Vec<CallSite>,
     ) {
-        // Handle member access patterns: obj.method() or this.method()
-        // The member_expression node should have structure: object property
-        let mut object_name = None;
-        let mut method_name = None;
-        
-        if let Some(object) = member_node.child(0) {
-            object_name = Some(&source[object.start_byte()..object.end_byte()]);
-        }
-        
-        if let Some(property) = member_node.child(member_node.child_count() - 1) {
-            if property.kind() == "property_identifier" {
-                method_name = Some(&source[property.start_byte()..property.end_byte()]);
-            }
-        }
-
-        if let (Some(obj), Some(method)) = (object_name, method_name) {
-            if obj == "this" {
-                // For this.method() calls, just use the method name
-                // It will be resolved to the current class context
-                calls.push(method.to_string());
-            } else {
-                // For other object method calls (e.g., obj.method()), 
-                // we can't easily resolve the type, so just record the method name
-                calls.push(method.to_string());
-            }
-        }
+        Self::extract_member_call_with_context(member_node, source, calls, "");
     }
 
     /// Extract method name from member access calls with class context
     fn extract_member_call_with_context(
         member_node: tree_sitter::Node,
         source: &str,
-        calls: &mut Vec<String>,
+        calls: &mut Vec<CallSite>,
         class_context: &str,
     ) {
-        // Handle member access patterns: obj.method() or this.method()
-        let mut object_name = None;
-        let mut method_name = None;
-        
-        if let Some(object) = member_node.child(0) {
-            object_name = Some(&source[object.start_byte()..object.end_byte()]);
+        // Handle member access patterns: obj.method(), this.method() or ns.sub.func()
+        let Some(property) = member_node.child_by_field_name("property") else {
+            return;
+        };
+        if property.kind() != "property_identifier" {
+            return;
         }
-        
-        if let Some(property) = member_node.child(member_node.child_count() - 1) {
-            if property.kind() == "property_identifier" {
-                method_name = Some(&source[property.start_byte()..property.end_byte()]);
+        let method = &source[property.start_byte()..property.end_byte()];
+        let object = member_node
+            .child_by_field_name("object")
+            .and_then(|object| Self::member_path(object, source));
+
+        match object.as_deref() {
+            Some("this") if !class_context.is_empty() => {
+                // For this.method() calls, resolve to the current class context
+                let resolved_method = format!("{class_context}.{method}");
+                calls.push(call_site(resolved_method, property));
             }
+            Some("this") | None => calls.push(call_site(method, property)),
+            // The object may be an imported namespace or a plain variable; resolution decides
+            Some(_) => calls.push(call_site(method, property).qualified(object)),
         }
+    }
 
-        if let (Some(obj), Some(method)) = (object_name, method_name) {
-            if obj == "this" && !class_context.is_empty() {
-                // For this.method() calls, resolve to the current class context
-                let resolved_method = format!("{}.{}", class_context, method);
-                calls.push(resolved_method);
-            } else {
-                // For other object method calls (e.g., obj.method()), 
-                // we can't easily resolve the type, so just record the method name
-                calls.push(method.to_string());
+    /// The `::`-joined path of a name or chain of member accesses, e.g. `utils::strings`
+    fn member_path(node: tree_sitter::Node, source: &str) -> Option<String> {
+        match node.kind() {
+            "identifier" | "this" => Some(source[node.start_byte()..node.end_byte()].to_string()),
+            "member_expression" => {
+                let object = Self::member_path(node.child_by_field_name("object")?, source)?;
+                let property = node.child_by_field_name("property")?;
+                Some(format!("{}::{}", object, &source[property.start_byte()..property.end_byte()]))
+            }
+            _ => None,
+        }
+    }
+
+    /// Text of a string literal without its quotes
+    fn string_value(node: tree_sitter::Node, source: &str) -> String {
+        source[node.start_byte()..node.end_byte()]
+            .trim_matches(|c| c == '\'' || c == '"' || c == '`')
+            .to_string()
+    }
+
+    /// Record the bindings introduced by an ES `import` statement
+    ///
+    /// Sources keep the specifier as written (`./lib/util.js`, `lodash/fp`);
+    /// the module loader and resolver map it to a module.
+    pub(crate) fn extract_import(node: tree_sitter::Node, source: &str, module: &str, ast: &mut AbstractAST) {
+        let Some(specifier) = node.child_by_field_name("source") else {
+            return;
+        };
+        let from = Self::string_value(specifier, source);
+        let text = |n: tree_sitter::Node| source[n.start_byte()..n.end_byte()].to_string();
+
+        for clause in node.named_children(&mut node.walk()).filter(|c| c.kind() == "import_clause") {
+            for binding in clause.named_children(&mut clause.walk()) {
+                match binding.kind() {
+                    // `import helper from './helper'` binds the default export
+                    "identifier" => ast.add_import(Import::item(
+                        module.to_string(),
+                        from.clone(),
+                        "default".to_string(),
+                        text(binding),
+                    )),
+                    "namespace_import" => {
+                        if let Some(alias) = binding.named_child(0) {
+                            ast.add_import(Import::module(module.to_string(), from.clone(), text(alias)));
+                        }
+                    }
+                    "named_imports" => {
+                        for spec in binding.named_children(&mut binding.walk()) {
+                            let Some(name) = spec.child_by_field_name("name") else {
+                                continue;
+                            };
+                            let alias = spec.child_by_field_name("alias").unwrap_or(name);
+                            ast.add_import(Import::item(module.to_string(), from.clone(), text(name), text(alias)));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    /// Record the bindings introduced by `const x = require('...')` or `const { a, b: c } = require('...')`
+    pub(crate) fn extract_require(declarator: tree_sitter::Node, source: &str, module: &str, ast: &mut AbstractAST) {
+        let (Some(pattern), Some(value)) = (declarator.child_by_field_name("name"), declarator.child_by_field_name("value")) else {
+            return;
+        };
+        if value.kind() != "call_expression"
+            || value.child_by_field_name("function").map(|f| &source[f.start_byte()..f.end_byte()]) != Some("require")
+        {
+            return;
+        }
+        let Some(specifier) = value
+            .child_by_field_name("arguments")
+            .and_then(|args| args.named_child(0))
+            .filter(|arg| arg.kind() == "string")
+        else {
+            return;
+        };
+        let from = Self::string_value(specifier, source);
+        let text = |n: tree_sitter::Node| source[n.start_byte()..n.end_byte()].to_string();
+
+        match pattern.kind() {
+            "identifier" => ast.add_import(Import::module(module.to_string(), from, text(pattern))),
+            "object_pattern" => {
+                for prop in pattern.named_children(&mut pattern.walk()) {
+                    match prop.kind() {
+                        "shorthand_property_identifier_pattern" => {
+                            ast.add_import(Import::item(module.to_string(), from.clone(), text(prop), text(prop)));
+                        }
+                        "pair_pattern" => {
+                            if let (Some(key), Some(value)) = (prop.child_by_field_name("key"), prop.child_by_field_name("value")) {
+                                ast.add_import(Import::item(module.to_string(), from.clone(), text(key), text(value)));
+                            }
+                        }
+                        _ => {}
+                    }
+                }
             }
+            _ => {}
         }
     }
 
     /// Extract calls within a single function with class context for better resolution
-    fn extract_calls_from_function_with_context(
+    pub(crate) fn extract_calls_from_function_with_context(
         func_node: tree_sitter::Node,
         source: &str,
-        calls: &mut Vec<String>,
+        calls: &mut Vec<CallSite>,
         class_context: &str,
     ) {
         for child in func_node.children(&mut func_node.walk()) {
@@ -299,7 +372,7 @@ impl JavaScriptTranslator {
     fn extract_calls_recursive_with_context(
         node: tree_sitter::Node,
         source: &str,
-        calls: &mut Vec<String>,
+        calls: &mut Vec<CallSite>,
         class_context: &str,
     ) {
         // Look for call_expression nodes
@@ -310,7 +383,7 @@ impl JavaScriptTranslator {
                     "identifier" => {
                         // Direct function call: function_name()
                         let name = &source[child.start_byte()..child.end_byte()];
-                        calls.push(name.to_string());
+                        calls.push(call_site(name, child));
                     }
                     "member_expression" => {
                         // Member access call: obj.method() or this.method()
@@ -344,7 +417,7 @@ impl JavaScriptTranslator {
                                         if let Some(arg_child) = arg.child(j) {
                                             if arg_child.kind() == "identifier" {
                                                 let name = &source[arg_child.start_byte()..arg_child.end_byte()];
-                                                calls.push(name.to_string());
+                                                calls.push(call_site(name, arg_child));
                                             }
                                         }
                                     }
@@ -375,12 +448,10 @@ impl JavaScriptTranslator {
                 // Find the right side of the assignment
                 for i in 0..node.child_count() {
                     if let Some(child) = node.child(i) {
-                        // Skip the assignment operator and member_expression
-                        if child.kind() != "=" && child.kind() != "member_expression" {
-                            if child.kind() == "identifier" {
-                                let name = &source[child.start_byte()..child.end_byte()];
-                                calls.push(name.to_string());
-                            }
+                        // Only the identifier on the right-hand side is a reference
+                        if child.kind() == "identifier" {
+                            let name = &source[child.start_byte()..child.end_byte()];
+                            calls.push(call_site(name, child));
                         }
                     }
                 }
@@ -416,6 +487,15 @@ impl JavaScriptTranslator {
         ast: &mut AbstractAST,
         class_context: &str,
     ) {
+        if node.kind() == "import_statement" {
+            Self::extract_import(node, source, module, ast);
+            return;
+        }
+
+        if node.kind() == "variable_declarator" {
+            Self::extract_require(node, source, module, ast);
+        }
+
         if node.kind() == "class_declaration" || node.kind() == "class" {
             // Extract class name
             let mut class_name = String::new();
@@ -455,18 +535,18 @@ impl JavaScriptTranslator {
                 } else {
                     format!("{}.{}", class_context, func_name)
                 };
-                let mut func_def = FunctionDef::new(scoped_name, sig, module.to_string());
+                let mut func_def = FunctionDef::new(scoped_name, sig, module.to_string()).with_span(span_of(node));
                 
-                for call_name in calls {
+                for site in calls {
                     // Determine if this is a local call that should be resolved within the module
-                    let target_module = if call_name.contains('.') {
+                    let target_module = if site.name.contains('.') {
                         // For method calls like "MyClass.method2", try to resolve within current module
                         Some(module.to_string())
                     } else {
                         // For simple function calls, leave as None (external)
                         None
                     };
-                    let call = FunctionCall::new(call_name, target_module, 0);
+                    let call = site.into_call(target_module);
                     func_def.add_call(call);
                 }
 
@@ -503,18 +583,18 @@ impl JavaScriptTranslator {
 
                                 // Create function definition
                                 let sig = Signature::empty();
-                                let mut func_def = FunctionDef::new(var_name, sig, module.to_string());
+                                let mut func_def = FunctionDef::new(var_name, sig, module.to_string()).with_span(span_of(child));
                                 
-                                for call_name in calls {
+                                for site in calls {
                                     // Determine if this is a local call that should be resolved within the module
-                                    let target_module = if call_name.contains('.') {
+                                    let target_module = if site.name.contains('.') {
                                         // For method calls like "MyClass.method2", try to resolve within current module
                                         Some(module.to_string())
                                     } else {
                                         // For simple function calls, leave as None (external)
                                         None
                                     };
-                                    let call = FunctionCall::new(call_name, target_module, 0);
+                                    let call = site.into_call(target_module);
                                     func_def.add_call(call);
                                 }
 
@@ -542,18 +622,18 @@ impl JavaScriptTranslator {
                 // Create function definition with class context
                 let sig = Signature::empty();
                 let scoped_name = format!("{}.{}", class_context, func_name);
-                let mut func_def = FunctionDef::new(scoped_name, sig, module.to_string());
+                let mut func_def = FunctionDef::new(scoped_name, sig, module.to_string()).with_span(span_of(node));
                 
-                for call_name in calls {
+                for site in calls {
                     // Determine if this is a local call that should be resolved within the module
-                    let target_module = if call_name.contains('.') {
+                    let target_module = if site.name.contains('.') {
                         // For method calls like "MyClass.method2", try to resolve within current module
                         Some(module.to_string())
                     } else {
                         // For simple function calls, leave as None (external)
                         None
                     };
-                    let call = FunctionCall::new(call_name, target_module, 0);
+                    let call = site.into_call(target_module);
                     func_def.add_call(call);
                 }
 
@@ -569,16 +649,16 @@ impl JavaScriptTranslator {
                 let sig = Signature::empty();
                 let mut func_def = FunctionDef::new("<module>".to_string(), sig, module.to_string());
                 
-                for call_name in calls {
+                for site in calls {
                     // Determine if this is a local call that should be resolved within the module
-                    let target_module = if call_name.contains('.') {
+                    let target_module = if site.name.contains('.') {
                         // For method calls like "MyClass.method2", try to resolve within current module
                         Some(module.to_string())
                     } else {
                         // For simple function calls, leave as None (external)
                         None
                     };
-                    let call = FunctionCall::new(call_name, target_module, 0);
+                    let call = site.into_call(target_module);
                     func_def.add_call(call);
                 }
                 
@@ -599,17 +679,6 @@ impl JavaScriptTranslator {
         }
     }
 
-    /// Extract calls within a single function
-    fn extract_calls_from_function(
-        func_node: tree_sitter::Node,
-        source: &str,
-        calls: &mut Vec<String>,
-    ) {
-        for child in func_node.children(&mut func_node.walk()) {
-            Self::extract_calls_recursive(child, source, calls);
-        }
-    }
-
     /// Translate a JavaScript file to abstract AST
     ///
     /// # Errors
@@ -625,7 +694,11 @@ impl JavaScriptTranslator {
             self.extract_module_path(path)?
         };
         
-        self.translate(&source, &module)
+        let mut ast = self.translate(&source, &module)?;
+        
+        ast.set_source_file(path);
+        
+        Ok(ast)
     }
 }
 
@@ -640,7 +713,11 @@ impl crate::translator_trait::Translator for JavaScriptTranslator {
             self.extract_module_path(path)?
         };
         
-        self.translate(&source, &module)
+        let mut ast = self.translate(&source, &module)?;
+        
+        ast.set_source_file(path);
+        
+        Ok(ast)
     }
 }
 
@@ -700,4 +777,57 @@ mod tests {
         assert_eq!(ast.module_path(), "mymod");
         assert!(ast.functions.len() >= 2);
     }
+
+    #[test]
+    fn test_call_sites_have_line_and_column() {
+        let source = "function main() {\n  helper();\n  obj.run();\n}";
+        let ast = JavaScriptTranslator::new().translate(source, "root").unwrap();
+        let calls = &ast.get_function("main").unwrap().calls;
+
+        let helper = calls.iter().find(|c| c.target_name == "helper").unwrap();
+        assert_eq!((helper.line, helper.column), (2, 3));
+        let run = calls.iter().find(|c| c.target_name == "run").unwrap();
+        assert_eq!((run.line, run.column), (3, 7));
+    }
+
+    #[test]
+    fn test_function_spans() {
+        let source = "function main() {\n  helper();\n}\nvar helper = () => {\n  return 1;\n};";
+        let ast = JavaScriptTranslator::new().translate(source, "root").unwrap();
+
+        let main = ast.get_function("main").unwrap().span.unwrap();
+        assert_eq!((main.start_line, main.end_line), (1, 3));
+        let helper = ast.get_function("helper").unwrap().span.unwrap();
+        assert_eq!((helper.start_line, helper.end_line), (4, 6));
+    }
+
+    #[test]
+    fn test_imports_are_recorded() {
+        let source = "import d, { a, b as c } from './lib/u.js';\nimport * as ns from 'pkg';\nconst m = require('./w');\nconst { p, q: r } = require('./w2');";
+        let ast = JavaScriptTranslator::new().translate(source, "app").unwrap();
+        assert_eq!(
+            ast.imports,
+            vec![
+                Import::item("app".into(), "./lib/u.js".into(), "default".into(), "d".into()),
+                Import::item("app".into(), "./lib/u.js".into(), "a".into(), "a".into()),
+                Import::item("app".into(), "./lib/u.js".into(), "b".into(), "c".into()),
+                Import::module("app".into(), "pkg".into(), "ns".into()),
+                Import::module("app".into(), "./w".into(), "m".into()),
+                Import::item("app".into(), "./w2".into(), "p".into(), "p".into()),
+                Import::item("app".into(), "./w2".into(), "q".into(), "r".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_member_calls_record_qualifier() {
+        let source = "function main() {\n  ns.util.run();\n  helper();\n}";
+        let ast = JavaScriptTranslator::new().translate(source, "app").unwrap();
+        let calls = &ast.get_function("main").unwrap().calls;
+
+        let run = calls.iter().find(|c| c.target_name == "run").unwrap();
+        assert_eq!(run.qualifier.as_deref(), Some("ns::util"));
+        let helper = calls.iter().find(|c| c.target_name == "helper").unwrap();
+        assert_eq!(helper.qualifier, None);
+    }
 }
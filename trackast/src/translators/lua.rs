@@ -0,0 +1,689 @@
+use trackast_lib::ast::{AbstractAST, FunctionCall, FunctionDef, Import, Signature, SourceSpan};
+use super::CallSite;
+
+/// Reserved words, which never name a function or variable
+const KEYWORDS: [&str; 22] = [
+    "and", "break", "do", "else", "elseif", "end", "false", "for", "function", "goto", "if", "in",
+    "local", "nil", "not", "or", "repeat", "return", "then", "true", "until", "while",
+];
+
+/// Multi-character operators, longest first
+const SYMBOLS: [&str; 10] = ["...", "..", "==", "~=", "<=", ">=", "<<", ">>", "//", "::"];
+
+/// Translator for Lua source code to abstract AST
+///
+/// There is no tree-sitter grammar for Lua among our dependencies, so the
+/// source is tokenized here and scanned for `function ... end` blocks and
+/// calls. That is enough for call graphs: Lua has no types, and a call is
+/// always a name path followed by arguments.
+///
+/// Functions stored on the table a module returns (`local M = {} ...
+/// return M`) are the module's functions and are named plainly, so
+/// `require("a.b").foo()` binds to `foo` in `a::b`. Functions on any other
+/// table are named `Table.name`, whether defined with `.` or `:`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LuaTranslator;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenKind {
+    Name,
+    Keyword,
+    String,
+    Number,
+    Symbol,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Token<'a> {
+    kind: TokenKind,
+    text: &'a str,
+    /// 1-based line and byte column of the first character
+    line: usize,
+    column: usize,
+    start: usize,
+    end: usize,
+}
+
+impl Token<'_> {
+    fn is_symbol(&self, symbol: &str) -> bool {
+        self.kind == TokenKind::Symbol && self.text == symbol
+    }
+
+    fn is_keyword(&self, keyword: &str) -> bool {
+        self.kind == TokenKind::Keyword && self.text == keyword
+    }
+
+    /// `.` or `:` between the parts of a name path
+    fn is_separator(&self) -> bool {
+        self.is_symbol(".") || self.is_symbol(":")
+    }
+}
+
+/// A chain of names such as `a.b:c`, ending just before some token
+struct NamePath<'a> {
+    /// Index of the first name
+    start: usize,
+    names: Vec<&'a str>,
+    /// The chain hangs off something other than a name, as in `f().a.b`
+    /// or `t[1]:m`, so only the last name is known
+    partial: bool,
+}
+
+/// A function whose body is being scanned
+struct Frame {
+    /// Scoped name; `None` for an anonymous function, whose calls count
+    /// toward the enclosing named function
+    name: Option<String>,
+    /// Table the function is defined on, as written, which `self` refers
+    /// to in a method
+    owner: Option<String>,
+    start_line: usize,
+    start_byte: usize,
+    sites: Vec<CallSite>,
+}
+
+/// A block closed by `end` (or `until`)
+enum Block {
+    Function,
+    Other,
+}
+
+/// Everything found in one chunk
+struct Scan {
+    functions: Vec<FunctionDef>,
+    imports: Vec<Import>,
+}
+
+impl LuaTranslator {
+    /// Create a new Lua translator
+    #[must_use]
+    pub fn new() -> Self {
+        LuaTranslator
+    }
+
+    /// Extract module path from Lua file path (`net/http.lua` is `net::http`)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file path is invalid.
+    pub fn extract_module_path(&self, file_path: &str) -> Result<String, String> {
+        let path = std::path::Path::new(file_path);
+        let stem = path.file_stem()
+            .and_then(|s| s.to_str())
+            .ok_or("Invalid file path")?;
+
+        match path.parent().and_then(|p| p.to_str()) {
+            Some(parent) if parent != "." && !parent.is_empty() => Ok(format!("{}::{}", parent.replace('/', "::"), stem)),
+            _ => Ok(stem.to_string()),
+        }
+    }
+
+    /// Translate Lua source to abstract AST
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the source has an unterminated string or comment.
+    pub fn translate(&self, source: &str, module_path: &str) -> Result<AbstractAST, String> {
+        let tokens = tokenize(source)?;
+        let scan = Self::scan(&tokens, module_path);
+
+        let mut ast = AbstractAST::new(module_path.to_string());
+        for func_def in scan.functions {
+            // A redefinition adds to the first definition rather than
+            // producing a second node with the same ID
+            match ast.functions.iter_mut().find(|f| f.name == func_def.name) {
+                Some(existing) => existing.calls.extend(func_def.calls),
+                None => ast.add_function(func_def),
+            }
+        }
+        for import in scan.imports {
+            ast.add_import(import);
+        }
+        Ok(ast)
+    }
+
+    /// Walk the tokens, tracking blocks, function frames and table constructors
+    fn scan(tokens: &[Token], module: &str) -> Scan {
+        let exported = returned_table(tokens);
+        let mut scan = Scan {
+            functions: Vec::new(),
+            imports: Vec::new(),
+        };
+        let mut blocks: Vec<Block> = Vec::new();
+        let mut frames: Vec<Frame> = Vec::new();
+        // Owner of each open table constructor, when it is assigned to a name
+        let mut tables: Vec<Option<String>> = Vec::new();
+        let mut module_sites: Vec<CallSite> = Vec::new();
+
+        for (i, token) in tokens.iter().enumerate() {
+            if token.is_keyword("function") {
+                let (name, owner) = match definition_path(tokens, i, &tables) {
+                    Some(path) => Self::scoped_name(&path, exported),
+                    None => (None, None),
+                };
+                let start = if i > 0 && tokens[i - 1].is_keyword("local") { tokens[i - 1] } else { *token };
+                frames.push(Frame {
+                    name,
+                    owner,
+                    start_line: start.line,
+                    start_byte: start.start,
+                    sites: Vec::new(),
+                });
+                blocks.push(Block::Function);
+                continue;
+            }
+
+            match token.kind {
+                TokenKind::Keyword if matches!(token.text, "do" | "if" | "repeat") => blocks.push(Block::Other),
+                TokenKind::Keyword if matches!(token.text, "end" | "until") => {
+                    if let Some(Block::Function) = blocks.pop() {
+                        let frame = frames.pop().expect("every function block has a frame");
+                        Self::finish(frame, token, module, exported, &mut frames, &mut module_sites, &mut scan.functions);
+                    }
+                }
+                TokenKind::Symbol if token.text == "}" => {
+                    tables.pop();
+                }
+                _ => {}
+            }
+
+            if token.kind == TokenKind::String || token.is_symbol("(") || token.is_symbol("{") {
+                if let Some(site) = Self::call_at(tokens, i, module, &mut scan.imports) {
+                    match frames.last_mut() {
+                        Some(frame) => frame.sites.push(site),
+                        None => module_sites.push(site),
+                    }
+                }
+            }
+            if token.is_symbol("{") {
+                let owner = i.checked_sub(1).and_then(|eq| assignment_target(tokens, eq, &tables));
+                tables.push(owner.map(|path| path.join(".")));
+            }
+        }
+
+        // Close functions left open at the end of a malformed chunk
+        while let Some(frame) = frames.pop() {
+            let last = tokens.last().copied().expect("a function keyword was seen");
+            Self::finish(frame, &last, module, exported, &mut frames, &mut module_sites, &mut scan.functions);
+        }
+
+        if !module_sites.is_empty() {
+            let mut func_def = FunctionDef::new("<module>".to_string(), Signature::empty(), module.to_string());
+            for site in module_sites {
+                func_def.add_call(Self::into_call(site, None, module, exported));
+            }
+            scan.functions.push(func_def);
+        }
+        scan
+    }
+
+    /// Turn a finished frame into a definition, or hand an anonymous
+    /// function's calls to the enclosing function (or `<module>`), which
+    /// is what runs them
+    fn finish(
+        frame: Frame,
+        end: &Token,
+        module: &str,
+        exported: Option<&str>,
+        frames: &mut [Frame],
+        module_sites: &mut Vec<CallSite>,
+        functions: &mut Vec<FunctionDef>,
+    ) {
+        let Some(name) = frame.name else {
+            match frames.last_mut() {
+                Some(parent) => parent.sites.extend(frame.sites),
+                None => module_sites.extend(frame.sites),
+            }
+            return;
+        };
+
+        let span = SourceSpan::new(frame.start_line, end.line, frame.start_byte, end.end);
+        let mut func_def = FunctionDef::new(name, Signature::empty(), module.to_string()).with_span(span);
+        for site in frame.sites {
+            func_def.add_call(Self::into_call(site, frame.owner.as_deref(), module, exported));
+        }
+        functions.push(func_def);
+    }
+
+    /// Scoped name and owner of a function defined as `path`
+    ///
+    /// `M.f` and `M:f` on the exported table `M` are plain `f`; on any other
+    /// table they are `T.f`.
+    fn scoped_name(path: &[String], exported: Option<&str>) -> (Option<String>, Option<String>) {
+        let owner = (path.len() > 1).then(|| path[..path.len() - 1].join("."));
+        let names = match path.split_first() {
+            Some((first, rest)) if !rest.is_empty() && Some(first.as_str()) == exported => rest,
+            _ => path,
+        };
+        (Some(names.join(".")), owner)
+    }
+
+    /// Bind `self` to the method's table and the exported table to the module
+    fn into_call(mut site: CallSite, owner: Option<&str>, module: &str, exported: Option<&str>) -> FunctionCall {
+        match (site.qualifier.as_deref(), owner) {
+            // `self:save()` inside `function Account:deposit()` calls `Account.save`
+            (Some("self"), Some(owner)) => {
+                let owner = match owner.split_once('.') {
+                    Some((first, rest)) if Some(first) == exported => Some(rest),
+                    _ if Some(owner) == exported => None,
+                    _ => Some(owner),
+                };
+                site.name = match owner {
+                    Some(owner) => format!("{owner}.{}", site.name),
+                    None => site.name,
+                };
+                site.qualifier = None;
+                site.into_call(Some(module.to_string()))
+            }
+            // `M.helper()` in the module returning `M` is a call within the module
+            (Some(qualifier), _) => {
+                let Some(exported) = exported else {
+                    return site.into_call(None);
+                };
+                if qualifier == exported {
+                    site.qualifier = None;
+                } else if let Some(rest) = qualifier.strip_prefix(exported).and_then(|rest| rest.strip_prefix("::")) {
+                    site.name = format!("{}.{}", rest.replace("::", "."), site.name);
+                    site.qualifier = None;
+                    return site.into_call(Some(module.to_string()));
+                }
+                site.into_call(None)
+            }
+            (None, _) => site.into_call(None),
+        }
+    }
+
+    /// The call whose arguments start at `tokens[i]`: `f(...)`, `f "..."`,
+    /// `f {...}`, `a.b:c(...)`
+    ///
+    /// `require` calls with a literal module name are recorded as imports
+    /// instead: `local x = require("a.b")` binds `x` to `a::b`, and a bare
+    /// `require "a.b"`, which may define globals, imports all of `a::b`.
+    fn call_at(tokens: &[Token], i: usize, module: &str, imports: &mut Vec<Import>) -> Option<CallSite> {
+        let callee = *tokens.get(i.checked_sub(1)?)?;
+        if callee.kind != TokenKind::Name {
+            return None;
+        }
+        let path = name_path(tokens, i)?;
+        if path.start > 0 && tokens[path.start - 1].is_keyword("function") {
+            return None;
+        }
+
+        if path.names == ["require"] {
+            let argument = match tokens.get(i) {
+                Some(token) if token.kind == TokenKind::String => Some(token),
+                Some(token) if token.is_symbol("(") && tokens.get(i + 2).is_some_and(|t| t.is_symbol(")")) => {
+                    tokens.get(i + 1).filter(|t| t.kind == TokenKind::String)
+                }
+                _ => None,
+            };
+            if let Some(source) = argument.and_then(|argument| string_value(argument.text)) {
+                let source = source.replace('.', "::");
+                let alias = path
+                    .start
+                    .checked_sub(1)
+                    .filter(|&eq| tokens[eq].is_symbol("="))
+                    .and_then(|eq| name_path(tokens, eq))
+                    .filter(|target| target.names.len() == 1 && !target.partial)
+                    .map(|target| target.names[0].to_string());
+                imports.push(match alias {
+                    Some(alias) => Import::module(module.to_string(), source, alias),
+                    None => Import::glob(module.to_string(), source),
+                });
+                return None;
+            }
+        }
+
+        let (name, qualifier) = path.names.split_last()?;
+        let qualifier = (!qualifier.is_empty() && !path.partial).then(|| qualifier.join("::"));
+        Some(
+            CallSite {
+                name: (*name).to_string(),
+                qualifier: None,
+                line: callee.line,
+                column: callee.column,
+                arg_count: None,
+                arg_types: Vec::new(),
+            }
+            .qualified(qualifier),
+        )
+    }
+
+    /// Translate a Lua file to abstract AST
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if file reading or scanning fails.
+    pub fn translate_file(&self, path: &str, module_path: Option<&str>) -> Result<AbstractAST, String> {
+        let source = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read file: {e}"))?;
+
+        let module = if let Some(m) = module_path {
+            m.to_string()
+        } else {
+            self.extract_module_path(path)?
+        };
+
+        let mut ast = self
+            .translate(&source, &module)
+            .map_err(|e| format!("{path}: {e}"))?;
+
+        ast.set_source_file(path);
+
+        Ok(ast)
+    }
+}
+
+impl crate::translator_trait::Translator for LuaTranslator {
+    fn translate_file(&self, path: &str, module_path: Option<&str>) -> Result<AbstractAST, String> {
+        LuaTranslator::translate_file(self, path, module_path)
+    }
+}
+
+/// The names chained by `.` and `:` that end just before `tokens[end]`
+fn name_path<'a>(tokens: &[Token<'a>], end: usize) -> Option<NamePath<'a>> {
+    let mut start = end.checked_sub(1)?;
+    if tokens[start].kind != TokenKind::Name {
+        return None;
+    }
+    let mut names = vec![tokens[start].text];
+    while start >= 2 && tokens[start - 1].is_separator() && tokens[start - 2].kind == TokenKind::Name {
+        start -= 2;
+        names.push(tokens[start].text);
+    }
+    names.reverse();
+    let partial = start >= 1 && tokens[start - 1].is_separator();
+    Some(NamePath { start, names, partial })
+}
+
+/// The path a function is defined under, e.g. `["Account", "deposit"]`
+///
+/// Handles `function a.b:c()`, `local function f()`, `a.b = function()`,
+/// `local f = function()` and the field `f = function()` of a table
+/// constructor assigned to `t` (giving `t.f`). Other function expressions
+/// are anonymous.
+fn definition_path(tokens: &[Token], function: usize, tables: &[Option<String>]) -> Option<Vec<String>> {
+    // `function a.b:c(` names the path before the parameter list
+    let mut end = function + 1;
+    while tokens.get(end).is_some_and(|t| t.kind == TokenKind::Name || t.is_separator()) {
+        end += 1;
+    }
+    if end > function + 1 && tokens.get(end).is_some_and(|t| t.is_symbol("(")) {
+        let path = name_path(tokens, end).filter(|path| path.start == function + 1)?;
+        return Some(path.names.iter().map(ToString::to_string).collect());
+    }
+
+    let eq = function.checked_sub(1).filter(|&eq| tokens[eq].is_symbol("="))?;
+    assignment_target(tokens, eq, tables)
+}
+
+/// The variable or field assigned by the `=` at `tokens[eq]`
+///
+/// A lone name right after `{`, `,` or `;` inside a table constructor is a
+/// field of that table, and is only known when the table itself is assigned
+/// to a name.
+fn assignment_target(tokens: &[Token], eq: usize, tables: &[Option<String>]) -> Option<Vec<String>> {
+    if !tokens.get(eq)?.is_symbol("=") {
+        return None;
+    }
+    let path = name_path(tokens, eq).filter(|path| !path.partial)?;
+    let names = path.names.iter().map(ToString::to_string);
+    let before = path.start.checked_sub(1).map(|j| tokens[j]);
+    let in_constructor = before.is_some_and(|t| t.is_symbol("{") || t.is_symbol(",") || t.is_symbol(";"));
+    match tables.last() {
+        Some(owner) if in_constructor => {
+            let owner = owner.as_ref()?;
+            (path.names.len() == 1).then(|| owner.split('.').map(str::to_string).chain(names).collect())
+        }
+        _ => Some(names.collect()),
+    }
+}
+
+/// The name in a chunk's final top-level `return name`, if any
+fn returned_table<'a>(tokens: &[Token<'a>]) -> Option<&'a str> {
+    let mut depth = 0usize;
+    let mut returned = None;
+    for (i, token) in tokens.iter().enumerate().filter(|(_, t)| t.kind == TokenKind::Keyword) {
+        match token.text {
+            "function" | "do" | "if" | "repeat" => depth += 1,
+            "end" | "until" => depth = depth.saturating_sub(1),
+            "return" if depth == 0 => {
+                let name = tokens.get(i + 1).filter(|t| t.kind == TokenKind::Name);
+                let rest = &tokens[(i + 2).min(tokens.len())..];
+                let at_end = rest.is_empty() || (rest.len() == 1 && rest[0].is_symbol(";"));
+                if let (Some(name), true) = (name, at_end) {
+                    returned = Some(name.text);
+                }
+            }
+            _ => {}
+        }
+    }
+    returned
+}
+
+/// The contents of a short string literal without escapes; `None` for long
+/// strings or strings with escapes, which never name a module
+fn string_value(literal: &str) -> Option<&str> {
+    let quote = literal.chars().next().filter(|c| matches!(c, '"' | '\''))?;
+    let value = literal.strip_prefix(quote)?.strip_suffix(quote)?;
+    (!value.contains('\\')).then_some(value)
+}
+
+/// Split Lua source into tokens, dropping whitespace and comments
+fn tokenize(source: &str) -> Result<Vec<Token<'_>>, String> {
+    let bytes = source.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    let mut line = 1;
+    let mut line_start = 0;
+
+    while i < bytes.len() {
+        let c = bytes[i];
+        if c.is_ascii_whitespace() {
+            if c == b'\n' {
+                line += 1;
+                line_start = i + 1;
+            }
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        let position = (line, start - line_start + 1);
+        let kind = if source[i..].starts_with("--") {
+            i += 2;
+            match long_bracket_level(&source[i..]) {
+                Some(level) => i = long_bracket_end(source, i, level).ok_or_else(|| format!("Unterminated comment at line {line}"))?,
+                None => {
+                    while i < bytes.len() && bytes[i] != b'\n' {
+                        i += 1;
+                    }
+                }
+            }
+            None
+        } else if c == b'[' && long_bracket_level(&source[i..]).is_some() {
+            let level = long_bracket_level(&source[i..]).unwrap_or_default();
+            i = long_bracket_end(source, i, level).ok_or_else(|| format!("Unterminated string at line {line}"))?;
+            Some(TokenKind::String)
+        } else if c == b'"' || c == b'\'' {
+            i += 1;
+            loop {
+                match bytes.get(i) {
+                    None | Some(b'\n') => return Err(format!("Unterminated string at line {line}")),
+                    Some(b'\\') => {
+                        // `\z` skips the following whitespace, newlines included
+                        if bytes.get(i + 1) == Some(&b'z') {
+                            i += 2;
+                            while bytes.get(i).is_some_and(u8::is_ascii_whitespace) {
+                                i += 1;
+                            }
+                        } else {
+                            i += 2;
+                        }
+                    }
+                    Some(&b) if b == c => {
+                        i += 1;
+                        break;
+                    }
+                    Some(_) => i += 1,
+                }
+            }
+            Some(TokenKind::String)
+        } else if c.is_ascii_digit() || (c == b'.' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)) {
+            while let Some(&b) = bytes.get(i) {
+                let exponent_sign = matches!(b, b'+' | b'-') && matches!(bytes[i - 1], b'e' | b'E' | b'p' | b'P');
+                if !(b.is_ascii_alphanumeric() || b == b'.' || exponent_sign) {
+                    break;
+                }
+                i += 1;
+            }
+            Some(TokenKind::Number)
+        } else if c.is_ascii_alphabetic() || c == b'_' || !c.is_ascii() {
+            while bytes.get(i).is_some_and(|&b| b.is_ascii_alphanumeric() || b == b'_' || !b.is_ascii()) {
+                i += 1;
+            }
+            if KEYWORDS.contains(&&source[start..i]) {
+                Some(TokenKind::Keyword)
+            } else {
+                Some(TokenKind::Name)
+            }
+        } else {
+            i += SYMBOLS.iter().find(|symbol| source[i..].starts_with(*symbol)).map_or(1, |symbol| symbol.len());
+            Some(TokenKind::Symbol)
+        };
+
+        // Long strings and comments may span lines
+        if let Some(newline) = source[start..i].rfind('\n') {
+            line += source[start..i].matches('\n').count();
+            line_start = start + newline + 1;
+        }
+        if let Some(kind) = kind {
+            tokens.push(Token {
+                kind,
+                text: &source[start..i],
+                line: position.0,
+                column: position.1,
+                start,
+                end: i,
+            });
+        }
+    }
+    Ok(tokens)
+}
+
+/// The level of a long bracket (`[[` is 0, `[==[` is 2) opening `text`
+fn long_bracket_level(text: &str) -> Option<usize> {
+    let rest = text.strip_prefix('[')?;
+    let level = rest.bytes().take_while(|&b| b == b'=').count();
+    (rest.as_bytes().get(level) == Some(&b'[')).then_some(level)
+}
+
+/// The index just past the long bracket of `level` closing the one opened at `start`
+fn long_bracket_end(source: &str, start: usize, level: usize) -> Option<usize> {
+    let close = format!("]{}]", "=".repeat(level));
+    let body = start + level + 2;
+    source[body..].find(&close).map(|offset| body + offset + close.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn translate(source: &str) -> AbstractAST {
+        LuaTranslator::new().translate(source, "app").unwrap()
+    }
+
+    fn names(ast: &AbstractAST) -> Vec<&str> {
+        ast.functions.iter().map(|f| f.name.as_str()).collect()
+    }
+
+    fn call<'a>(ast: &'a AbstractAST, caller: &str, name: &str) -> &'a FunctionCall {
+        ast.get_function(caller)
+            .unwrap()
+            .calls
+            .iter()
+            .find(|c| c.target_name == name)
+            .unwrap_or_else(|| panic!("{caller} does not call {name}"))
+    }
+
+    #[test]
+    fn test_tokenize_skips_comments_and_strings() {
+        let source = "-- f()\n--[==[ g()\n]==] local s = [[ h() ]] .. 'i()' x.y:z(1e-3)";
+        let tokens = tokenize(source).unwrap();
+        let texts: Vec<&str> = tokens.iter().map(|t| t.text).collect();
+        assert_eq!(texts, ["local", "s", "=", "[[ h() ]]", "..", "'i()'", "x", ".", "y", ":", "z", "(", "1e-3", ")"]);
+        assert_eq!((tokens[0].line, tokens[0].column), (3, 6));
+        assert!(tokenize("local s = 'open\n'").is_err());
+    }
+
+    #[test]
+    fn test_definition_forms() {
+        let source = "local M = {}\n\
+                      local function helper() end\n\
+                      function M.greet(name) helper() end\n\
+                      function M:shout() self:greet() end\n\
+                      M.wave = function() end\n\
+                      local Account = { new = function() end }\n\
+                      function Account:deposit(v) self:log(v) end\n\
+                      local cb = function() end\n\
+                      return M\n";
+        let ast = translate(source);
+        assert_eq!(names(&ast), ["helper", "greet", "shout", "wave", "Account.new", "Account.deposit", "cb"]);
+
+        let greet = call(&ast, "shout", "greet");
+        assert_eq!((greet.target_module.as_deref(), greet.qualifier.as_deref()), (Some("app"), None));
+        let log = call(&ast, "Account.deposit", "Account.log");
+        assert_eq!(log.target_module.as_deref(), Some("app"));
+
+        let span = ast.get_function("helper").unwrap().span.unwrap();
+        assert_eq!((span.start_line, span.end_line), (2, 2));
+        assert!(source[span.start_byte..span.end_byte].starts_with("local function helper"));
+    }
+
+    #[test]
+    fn test_calls_and_qualifiers() {
+        let source = "function run(t)\n  print \"hi\"\n  os.exit(0)\n  t.items:insert{1}\n  get().value:save()\n  each(t, function(x) visit(x) end)\nend\n";
+        let ast = translate(source);
+        let qualifier = |name: &str| call(&ast, "run", name).qualifier.clone();
+        assert_eq!(qualifier("print"), None);
+        assert_eq!(qualifier("exit").as_deref(), Some("os"));
+        assert_eq!(qualifier("insert").as_deref(), Some("t::items"));
+        assert_eq!(qualifier("save"), None);
+        assert_eq!(qualifier("visit"), None);
+        assert_eq!((call(&ast, "run", "exit").line, call(&ast, "run", "exit").column), (3, 6));
+    }
+
+    #[test]
+    fn test_module_table_calls_stay_in_module() {
+        let source = "local M = {}\nfunction M.a() M.b() end\nfunction M.b() end\nreturn M";
+        let ast = translate(source);
+        let b = call(&ast, "a", "b");
+        assert_eq!(b.qualifier, None);
+    }
+
+    #[test]
+    fn test_requires_are_imports() {
+        let source = "local json = require(\"lib.json\")\nrequire 'globals'\nlocal x = require(name)\n\
+                      function f() json.encode({}) end";
+        let ast = translate(source);
+        assert_eq!(
+            ast.imports,
+            vec![
+                Import::module("app".into(), "lib::json".into(), "json".into()),
+                Import::glob("app".into(), "globals".into()),
+            ]
+        );
+        // A computed module name is only a call
+        let module = ast.get_function("<module>").unwrap();
+        assert_eq!(module.calls.iter().map(|c| (c.target_name.as_str(), c.line)).collect::<Vec<_>>(), [("require", 3)]);
+    }
+
+    #[test]
+    fn test_top_level_calls_go_to_module_function() {
+        let source = "local app = App.new()\nbutton:on_click(function() app:quit() end)\n";
+        let ast = translate(source);
+        let module = ast.get_function("<module>").unwrap();
+        let called: Vec<&str> = module.calls.iter().map(|c| c.target_name.as_str()).collect();
+        assert_eq!(called, ["new", "on_click", "quit"]);
+    }
+}
@@ -1,7 +1,125 @@
 pub mod rust;
 pub mod python;
 pub mod javascript;
+pub mod typescript;
+pub mod go;
+pub mod lua;
+pub mod cpp;
+pub mod java;
 
 pub use rust::RustTranslator;
 pub use python::PythonTranslator;
 pub use javascript::JavaScriptTranslator;
+pub use typescript::TypeScriptTranslator;
+pub use go::GoTranslator;
+pub use lua::LuaTranslator;
+pub use cpp::CppTranslator;
+pub use java::JavaTranslator;
+
+use trackast_lib::ast::{AbstractAST, FunctionCall, FunctionDef};
+
+/// Add `func_def` to `ast`, folding it into an earlier definition that
+/// `same` matches
+///
+/// Calls of both are kept. A declaration without a span takes the
+/// signature, file and span of the definition folded into it.
+pub(crate) fn fold_function(ast: &mut AbstractAST, func_def: FunctionDef, same: impl Fn(&FunctionDef, &FunctionDef) -> bool) {
+    match ast.functions.iter_mut().find(|f| same(f, &func_def)) {
+        Some(existing) => {
+            if existing.span.is_none() && func_def.span.is_some() {
+                existing.signature = func_def.signature;
+                existing.file = func_def.file;
+                existing.span = func_def.span;
+            }
+            existing.calls.extend(func_def.calls);
+        }
+        None => ast.add_function(func_def),
+    }
+}
+
+/// A call found while walking a function body
+pub(crate) struct CallSite {
+    pub name: String,
+    /// Module path or receiver the call goes through, `::`-separated
+    pub qualifier: Option<String>,
+    /// 1-based position of the called name
+    pub line: usize,
+    pub column: usize,
+    /// Number of arguments passed, where the language overloads by arity
+    pub arg_count: Option<usize>,
+    /// Type of each argument where the call site shows it
+    pub arg_types: Vec<Option<String>>,
+}
+
+impl CallSite {
+    pub fn qualified(mut self, qualifier: Option<String>) -> Self {
+        self.qualifier = qualifier;
+        self
+    }
+
+    /// Record the arguments passed in the argument list `arguments`
+    pub fn with_arguments(mut self, arguments: Option<tree_sitter::Node>) -> Self {
+        self.arg_count = arguments.map(|list| {
+            list.named_children(&mut list.walk())
+                .filter(|argument| argument.kind() != "comment")
+                .count()
+        });
+        self
+    }
+
+    pub fn with_arg_types(mut self, arg_types: Vec<Option<String>>) -> Self {
+        self.arg_types = arg_types;
+        self
+    }
+
+    pub fn into_call(self, target_module: Option<String>) -> FunctionCall {
+        let mut call = FunctionCall::new(self.name, target_module, self.line).with_column(self.column);
+        if let Some(count) = self.arg_count {
+            call = call.with_arg_count(count);
+        }
+        if !self.arg_types.is_empty() {
+            call = call.with_arg_types(self.arg_types);
+        }
+        match self.qualifier {
+            Some(qualifier) => call.with_qualifier(qualifier),
+            None => call,
+        }
+    }
+}
+
+/// Record a call to `name` located at `node`
+pub(crate) fn call_site(name: impl Into<String>, node: tree_sitter::Node) -> CallSite {
+    let position = node.start_position();
+    CallSite {
+        name: name.into(),
+        qualifier: None,
+        line: position.row + 1,
+        column: position.column + 1,
+        arg_count: None,
+        arg_types: Vec::new(),
+    }
+}
+
+/// The node naming the called function: the method in `obj.method()`,
+/// the last segment in `a::b()`, otherwise the callee itself
+pub(crate) fn callee_name_node(callee: tree_sitter::Node) -> tree_sitter::Node {
+    ["field", "property", "attribute", "name"]
+        .iter()
+        .find_map(|field| callee.child_by_field_name(field))
+        .unwrap_or(callee)
+}
+
+/// Source span covering `node`
+pub(crate) fn span_of(node: tree_sitter::Node) -> trackast_lib::ast::SourceSpan {
+    trackast_lib::ast::SourceSpan::new(
+        node.start_position().row + 1,
+        node.end_position().row + 1,
+        node.start_byte(),
+        node.end_byte(),
+    )
+}
+
+/// Collapse whitespace (including newlines) inside a type so IDs stay on one line
+pub(crate) fn normalize_type(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
@@ -1,5 +1,6 @@
 use tree_sitter::Parser;
-use trackast_lib::ast::{AbstractAST, FunctionDef, Signature, FunctionCall};
+use trackast_lib::ast::{AbstractAST, FunctionDef, Import, Signature};
+use super::{call_site, span_of, CallSite};
 
 /// Translator for Python source code to abstract AST
 pub struct PythonTranslator;
@@ -110,14 +111,14 @@ impl PythonTranslator {
         let mut calls = Vec::new();
 
         Self::extract_calls_recursive(root, source, &mut calls);
-        Ok(calls)
+        Ok(calls.into_iter().map(|site| site.name).collect())
     }
 
     /// Recursively find function calls in the tree
     fn extract_calls_recursive(
         node: tree_sitter::Node,
         source: &str,
-        calls: &mut Vec<String>,
+        calls: &mut Vec<CallSite>,
     ) {
         // Look for call nodes
         if node.kind() == "call" {
@@ -127,7 +128,7 @@ impl PythonTranslator {
                     "identifier" => {
                         // Direct function call: function_name()
                         let name = &source[child.start_byte()..child.end_byte()];
-                        calls.push(name.to_string());
+                        calls.push(call_site(name, child));
                     }
                     "attribute" => {
                         // Attribute access call: obj.method() or self.method()
@@ -158,7 +159,7 @@ impl PythonTranslator {
                                         if let Some(arg_child) = arg.child(j) {
                                             if arg_child.kind() == "identifier" {
                                                 let name = &source[arg_child.start_byte()..arg_child.end_byte()];
-                                                calls.push(name.to_string());
+                                                calls.push(call_site(name, arg_child));
                                             }
                                         }
                                     }
@@ -179,39 +180,86 @@ impl PythonTranslator {
     fn extract_attribute_call(
         attribute_node: tree_sitter::Node,
         source: &str,
-        calls: &mut Vec<String>,
+        calls: &mut Vec<CallSite>,
     ) {
-        // Handle attribute access patterns: obj.method() or self.method()
-        // The attribute node should have structure: object "." attribute
-        let mut object_name = None;
-        let mut method_name = None;
-        
-        for child in attribute_node.children(&mut attribute_node.walk()) {
-            match child.kind() {
-                "identifier" => {
-                    if object_name.is_none() {
-                        // First identifier is the object (e.g., "self", "obj", etc.)
-                        object_name = Some(&source[child.start_byte()..child.end_byte()]);
-                    } else {
-                        // Second identifier is the method name
-                        method_name = Some(&source[child.start_byte()..child.end_byte()]);
-                    }
-                }
-                _ => {}
+        Self::extract_attribute_call_with_context(attribute_node, source, calls, "");
+    }
+
+    /// The `::`-joined path of a name or chain of attribute accesses, e.g. `os::path`
+    fn dotted_path(node: tree_sitter::Node, source: &str) -> Option<String> {
+        match node.kind() {
+            "identifier" => Some(source[node.start_byte()..node.end_byte()].to_string()),
+            "attribute" => {
+                let object = Self::dotted_path(node.child_by_field_name("object")?, source)?;
+                let attribute = node.child_by_field_name("attribute")?;
+                Some(format!("{}::{}", object, &source[attribute.start_byte()..attribute.end_byte()]))
             }
+            _ => None,
         }
+    }
 
-        if let (Some(obj), Some(method)) = (object_name, method_name) {
-            if obj == "self" {
-                // For self.method() calls, just use the method name
-                // It will be resolved to the current class context
-                calls.push(method.to_string());
-            } else {
-                // For other object method calls (e.g., obj.method()), 
-                // we can't easily resolve the type, so just record the method name
-                // This could be enhanced with more sophisticated type tracking
-                calls.push(method.to_string());
+    /// Record the bindings introduced by `import ...` and `from ... import ...`
+    fn extract_import(node: tree_sitter::Node, source: &str, module: &str, ast: &mut AbstractAST) {
+        let text = |n: tree_sitter::Node| source[n.start_byte()..n.end_byte()].replace('.', "::");
+        let names: Vec<tree_sitter::Node> = node.children_by_field_name("name", &mut node.walk()).collect();
+
+        if node.kind() == "import_statement" {
+            for name in names {
+                let import = if name.kind() == "aliased_import" {
+                    let (Some(path), Some(alias)) = (name.child_by_field_name("name"), name.child_by_field_name("alias")) else {
+                        continue;
+                    };
+                    Import::module(module.to_string(), text(path), text(alias))
+                } else {
+                    // `import a.b` loads `a.b` but binds `a`; `a.b.f()` then
+                    // resolves through it once the loader has seen the package
+                    let path = text(name);
+                    let root = path.split("::").next().unwrap_or_default().to_string();
+                    Import::module(module.to_string(), path, root)
+                };
+                ast.add_import(import);
+            }
+            return;
+        }
+
+        // Relative imports keep their leading dots (`..pkg::mod`); the loader
+        // anchors them at the importing file's package
+        let from = node.child_by_field_name("module_name").map(|m| match m.kind() {
+            "relative_import" => {
+                let dots = m
+                    .named_children(&mut m.walk())
+                    .find(|c| c.kind() == "import_prefix")
+                    .map(|prefix| source[prefix.start_byte()..prefix.end_byte()].trim().to_string())
+                    .unwrap_or_default();
+                let path = m
+                    .named_children(&mut m.walk())
+                    .find(|c| c.kind() == "dotted_name")
+                    .map(text)
+                    .unwrap_or_default();
+                dots + &path
             }
+            _ => text(m),
+        });
+        let Some(from) = from else {
+            return;
+        };
+
+        if node.named_children(&mut node.walk()).any(|c| c.kind() == "wildcard_import") {
+            ast.add_import(Import::glob(module.to_string(), from));
+            return;
+        }
+
+        for name in names {
+            let (item, alias) = if name.kind() == "aliased_import" {
+                match (name.child_by_field_name("name"), name.child_by_field_name("alias")) {
+                    (Some(item), Some(alias)) => (text(item), text(alias)),
+                    _ => continue,
+                }
+            } else {
+                (text(name), text(name))
+            };
+            // The item may be a function, a class or a submodule
+            ast.add_import(Import::item(module.to_string(), from.clone(), item, alias));
         }
     }
 
@@ -239,6 +287,11 @@ impl PythonTranslator {
         ast: &mut AbstractAST,
         class_context: &str,
     ) {
+        if matches!(node.kind(), "import_statement" | "import_from_statement") {
+            Self::extract_import(node, source, module, ast);
+            return;
+        }
+
         if node.kind() == "class_definition" {
             // Extract class name
             let mut class_name = String::new();
@@ -278,18 +331,18 @@ impl PythonTranslator {
                 } else {
                     format!("{}.{}", class_context, func_name)
                 };
-                let mut func_def = FunctionDef::new(scoped_name, sig, module.to_string());
+                let mut func_def = FunctionDef::new(scoped_name, sig, module.to_string()).with_span(span_of(node));
                 
-                for call_name in calls {
+                for site in calls {
                     // Determine if this is a local call that should be resolved within the module
-                    let target_module = if call_name.contains('.') {
+                    let target_module = if site.name.contains('.') {
                         // For method calls like "MyClass.method2", try to resolve within current module
                         Some(module.to_string())
                     } else {
                         // For simple function calls, leave as None (external)
                         None
                     };
-                    let call = FunctionCall::new(call_name, target_module, 0);
+                    let call = site.into_call(target_module);
                     func_def.add_call(call);
                 }
 
@@ -307,16 +360,16 @@ impl PythonTranslator {
                 let sig = Signature::empty();
                 let mut func_def = FunctionDef::new("<module>".to_string(), sig, module.to_string());
                 
-                for call_name in calls {
+                for site in calls {
                     // Determine if this is a local call that should be resolved within the module
-                    let target_module = if call_name.contains('.') {
+                    let target_module = if site.name.contains('.') {
                         // For method calls like "MyClass.method2", try to resolve within current module
                         Some(module.to_string())
                     } else {
                         // For simple function calls, leave as None (external)
                         None
                     };
-                    let call = FunctionCall::new(call_name, target_module, 0);
+                    let call = site.into_call(target_module);
                     func_def.add_call(call);
                 }
                 
@@ -342,7 +395,7 @@ impl PythonTranslator {
     fn extract_calls_from_function_with_context(
         func_node: tree_sitter::Node,
         source: &str,
-        calls: &mut Vec<String>,
+        calls: &mut Vec<CallSite>,
         class_context: &str,
     ) {
         for child in func_node.children(&mut func_node.walk()) {
@@ -354,7 +407,7 @@ impl PythonTranslator {
     fn extract_calls_recursive_with_context(
         node: tree_sitter::Node,
         source: &str,
-        calls: &mut Vec<String>,
+        calls: &mut Vec<CallSite>,
         class_context: &str,
     ) {
         // Look for call nodes
@@ -365,7 +418,7 @@ impl PythonTranslator {
                     "identifier" => {
                         // Direct function call: function_name()
                         let name = &source[child.start_byte()..child.end_byte()];
-                        calls.push(name.to_string());
+                        calls.push(call_site(name, child));
                     }
                     "attribute" => {
                         // Attribute access call: obj.method() or self.method()
@@ -396,7 +449,7 @@ impl PythonTranslator {
                                         if let Some(arg_child) = arg.child(j) {
                                             if arg_child.kind() == "identifier" {
                                                 let name = &source[arg_child.start_byte()..arg_child.end_byte()];
-                                                calls.push(name.to_string());
+                                                calls.push(call_site(name, arg_child));
                                             }
                                         }
                                     }
@@ -417,40 +470,27 @@ impl PythonTranslator {
     fn extract_attribute_call_with_context(
         attribute_node: tree_sitter::Node,
         source: &str,
-        calls: &mut Vec<String>,
+        calls: &mut Vec<CallSite>,
         class_context: &str,
     ) {
-        // Handle attribute access patterns: obj.method() or self.method()
-        // The attribute node should have structure: object "." attribute
-        let mut object_name = None;
-        let mut method_name = None;
-        
-        for child in attribute_node.children(&mut attribute_node.walk()) {
-            match child.kind() {
-                "identifier" => {
-                    if object_name.is_none() {
-                        // First identifier is the object (e.g., "self", "obj", etc.)
-                        object_name = Some(&source[child.start_byte()..child.end_byte()]);
-                    } else {
-                        // Second identifier is the method name
-                        method_name = Some(&source[child.start_byte()..child.end_byte()]);
-                    }
-                }
-                _ => {}
-            }
-        }
+        // Handle attribute access patterns: obj.method(), self.method() or pkg.mod.func()
+        let Some(method_node) = attribute_node.child_by_field_name("attribute") else {
+            return;
+        };
+        let method = &source[method_node.start_byte()..method_node.end_byte()];
+        let object = attribute_node
+            .child_by_field_name("object")
+            .and_then(|object| Self::dotted_path(object, source));
 
-        if let (Some(obj), Some(method)) = (object_name, method_name) {
-            if obj == "self" && !class_context.is_empty() {
+        match object.as_deref() {
+            Some("self") if !class_context.is_empty() => {
                 // For self.method() calls, resolve to the current class context
-                let resolved_method = format!("{}.{}", class_context, method);
-                calls.push(resolved_method);
-            } else {
-                // For other object method calls (e.g., obj.method()), 
-                // we can't easily resolve the type, so just record the method name
-                // This could be enhanced with more sophisticated type tracking
-                calls.push(method.to_string());
+                let resolved_method = format!("{class_context}.{method}");
+                calls.push(call_site(resolved_method, method_node));
             }
+            Some("self") | None => calls.push(call_site(method, method_node)),
+            // The object may be an imported module or a plain variable; resolution decides
+            Some(_) => calls.push(call_site(method, method_node).qualified(object)),
         }
     }
 
@@ -469,7 +509,11 @@ impl PythonTranslator {
             self.extract_module_path(path)?
         };
         
-        self.translate(&source, &module)
+        let mut ast = self.translate(&source, &module)?;
+        
+        ast.set_source_file(path);
+        
+        Ok(ast)
     }
 }
 
@@ -482,7 +526,9 @@ impl crate::translator_trait::Translator for PythonTranslator {
         } else {
             self.extract_module_path(path)?
         };
-        self.translate(&source, &module)
+        let mut ast = self.translate(&source, &module)?;
+        ast.set_source_file(path);
+        Ok(ast)
     }
 }
 
@@ -542,4 +588,59 @@ mod tests {
         assert_eq!(ast.module_path(), "mymod");
         assert!(ast.functions.len() >= 2);
     }
+
+    #[test]
+    fn test_call_sites_have_line_and_column() {
+        let source = "def main():\n    helper()\n    obj.run()\n";
+        let ast = PythonTranslator::new().translate(source, "root").unwrap();
+        let calls = &ast.get_function("main").unwrap().calls;
+
+        let helper = calls.iter().find(|c| c.target_name == "helper").unwrap();
+        assert_eq!((helper.line, helper.column), (2, 5));
+        let run = calls.iter().find(|c| c.target_name == "run").unwrap();
+        assert_eq!((run.line, run.column), (3, 9));
+    }
+
+    #[test]
+    fn test_function_spans() {
+        let source = "class A:\n    def run(self):\n        pass\n\ndef main():\n    A().run()\n";
+        let ast = PythonTranslator::new().translate(source, "root").unwrap();
+
+        let run = ast.get_function("A.run").unwrap().span.unwrap();
+        assert_eq!((run.start_line, run.end_line), (2, 3));
+        let main = ast.get_function("main").unwrap().span.unwrap();
+        assert_eq!((main.start_line, main.end_line), (5, 6));
+        assert!(source[main.start_byte..main.end_byte].starts_with("def main()"));
+    }
+
+    #[test]
+    fn test_imports_are_recorded() {
+        let source = "import os, a.b as c\nimport x.y\nfrom m import f, g as h\nfrom . import sibling\nfrom .pkg import *\nfrom ..up.mod import x\n";
+        let ast = PythonTranslator::new().translate(source, "app").unwrap();
+        assert_eq!(
+            ast.imports,
+            vec![
+                Import::module("app".into(), "os".into(), "os".into()),
+                Import::module("app".into(), "a::b".into(), "c".into()),
+                Import::module("app".into(), "x::y".into(), "x".into()),
+                Import::item("app".into(), "m".into(), "f".into(), "f".into()),
+                Import::item("app".into(), "m".into(), "g".into(), "h".into()),
+                Import::item("app".into(), ".".into(), "sibling".into(), "sibling".into()),
+                Import::glob("app".into(), ".pkg".into()),
+                Import::item("app".into(), "..up::mod".into(), "x".into(), "x".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_attribute_calls_record_qualifier() {
+        let source = "def main():\n    os.path.join('a')\n    helper()\n";
+        let ast = PythonTranslator::new().translate(source, "app").unwrap();
+        let calls = &ast.get_function("main").unwrap().calls;
+
+        let join = calls.iter().find(|c| c.target_name == "join").unwrap();
+        assert_eq!(join.qualifier.as_deref(), Some("os::path"));
+        let helper = calls.iter().find(|c| c.target_name == "helper").unwrap();
+        assert_eq!(helper.qualifier, None);
+    }
 }
@@ -1,5 +1,19 @@
+use std::collections::HashMap;
 use tree_sitter::Parser;
-use trackast_lib::ast::{AbstractAST, FunctionDef, Signature, FunctionCall};
+use trackast_lib::ast::{AbstractAST, FunctionDef, Import, Signature};
+use trackast_lib::function_id::FunctionId;
+use super::{call_site, callee_name_node, normalize_type, span_of, CallSite};
+
+/// A module declared with `mod`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleDecl {
+    /// Full module path, e.g. `net::client`
+    pub path: String,
+    /// `mod name { ... }` rather than `mod name;`
+    pub inline: bool,
+    /// File named by a `#[path = "..."]` attribute
+    pub file: Option<String>,
+}
 
 /// Translator for Rust source code to abstract AST
 pub struct RustTranslator;
@@ -72,30 +86,66 @@ impl RustTranslator {
         }
     }
 
-    /// Extract module path from Rust source (simple version)
+    /// Modules declared with `mod` in a file whose module is `module_path`,
+    /// inline and file-backed, in source order
+    ///
+    /// Paths are full module paths, so `mod a { mod b; }` in the crate root
+    /// `app` yields `app::a` and `app::a::b`.
     ///
     /// # Errors
     ///
-    /// This function currently always succeeds, but returns Result for consistency.
-    pub fn extract_module_path(&self, source: &str, default_path: &str) -> Result<String, String> {
+    /// Returns an error if parsing fails.
+    pub fn module_tree(&self, source: &str, module_path: &str) -> Result<Vec<ModuleDecl>, String> {
+        let tree = self.parse_source(source)?;
         let mut modules = Vec::new();
-        
-        for line in source.lines() {
-            let trimmed = line.trim();
-            if trimmed.starts_with("mod ") && trimmed.ends_with(';') {
-                let mod_name = trimmed[4..trimmed.len() - 1].trim();
-                modules.push(mod_name.to_string());
-            } else if trimmed.starts_with("pub mod ") && trimmed.ends_with(';') {
-                let mod_name = trimmed[8..trimmed.len() - 1].trim();
-                modules.push(mod_name.to_string());
+        Self::collect_modules(tree.root_node(), source, module_path, &mut modules);
+        Ok(modules)
+    }
+
+    fn collect_modules(node: tree_sitter::Node, source: &str, module: &str, modules: &mut Vec<ModuleDecl>) {
+        // `#[path = "..."]` applies to the next item
+        let mut file = None;
+        for child in node.children(&mut node.walk()) {
+            match child.kind() {
+                "attribute_item" => {
+                    if let Some(path) = Self::path_attribute(child, source) {
+                        file = Some(path);
+                    }
+                    continue;
+                }
+                "line_comment" | "block_comment" => continue,
+                "mod_item" => {}
+                _ => {
+                    file = None;
+                    continue;
+                }
+            }
+            let file = file.take();
+            let Some(name) = child.child_by_field_name("name") else {
+                continue;
+            };
+            let path = format!("{module}::{}", &source[name.start_byte()..name.end_byte()]);
+            let body = child.child_by_field_name("body");
+            modules.push(ModuleDecl {
+                path: path.clone(),
+                inline: body.is_some(),
+                file,
+            });
+            if let Some(body) = body {
+                Self::collect_modules(body, source, &path, modules);
             }
         }
+    }
 
-        if modules.is_empty() {
-            Ok(default_path.to_string())
-        } else {
-            Ok(format!("{}::{}", default_path, modules.join("::")))
+    /// The value of a `#[path = "..."]` attribute
+    fn path_attribute(attribute_item: tree_sitter::Node, source: &str) -> Option<String> {
+        let attribute = attribute_item.named_child(0)?;
+        let name = attribute.named_child(0)?;
+        let value = attribute.child_by_field_name("value")?;
+        if &source[name.start_byte()..name.end_byte()] != "path" {
+            return None;
         }
+        Some(source[value.start_byte()..value.end_byte()].trim_matches('"').to_string())
     }
 
     /// Extract function calls from source code
@@ -109,20 +159,21 @@ impl RustTranslator {
         let mut calls = Vec::new();
 
         Self::extract_calls_recursive(root, source, &mut calls);
-        Ok(calls)
+        Ok(calls.into_iter().map(|site| site.name).collect())
     }
 
     /// Recursively find function calls in the tree
     fn extract_calls_recursive(
         node: tree_sitter::Node,
         source: &str,
-        calls: &mut Vec<String>,
+        calls: &mut Vec<CallSite>,
     ) {
         if node.kind() == "call_expression" {
             if let Some(child) = node.child(0) {
-                let call_name = Self::extract_identifier_or_field_access(child, source);
-                if let Some(name) = call_name {
-                    calls.push(name);
+                if let Some(site) = Self::scoped_call(child, source, "") {
+                    calls.push(site);
+                } else if let Some(name) = Self::extract_identifier_or_field_access(child, source) {
+                    calls.push(call_site(name, callee_name_node(child)));
                 }
             }
 
@@ -144,7 +195,7 @@ impl RustTranslator {
                                                 if let Some(arg_child) = arg.child(j) {
                                                     if arg_child.kind() == "identifier" {
                                                         let name = &source[arg_child.start_byte()..arg_child.end_byte()];
-                                                        calls.push(name.to_string());
+                                                        calls.push(call_site(name, arg_child));
                                                     }
                                                 }
                                             }
@@ -163,6 +214,108 @@ impl RustTranslator {
         }
     }
 
+    /// Handle path calls like `utils::helper()`, `Type::new()` or `parse::<T>()`
+    ///
+    /// The last segment becomes the call name and the rest its qualifier, with
+    /// `Self` replaced by the enclosing impl type.
+    fn scoped_call(callee: tree_sitter::Node, source: &str, impl_context: &str) -> Option<CallSite> {
+        let callee = if callee.kind() == "generic_function" {
+            callee.child_by_field_name("function")?
+        } else {
+            callee
+        };
+        if callee.kind() != "scoped_identifier" {
+            return None;
+        }
+
+        let name = callee.child_by_field_name("name")?;
+        let qualifier = callee.child_by_field_name("path").map(|path| {
+            let text = normalize_type(&source[path.start_byte()..path.end_byte()]);
+            if text == "Self" && !impl_context.is_empty() {
+                impl_context.to_string()
+            } else {
+                text
+            }
+        });
+        Some(call_site(&source[name.start_byte()..name.end_byte()], name).qualified(qualifier))
+    }
+
+    /// Record the bindings introduced by a `use` declaration
+    fn extract_use(node: tree_sitter::Node, source: &str, module: &str, ast: &mut AbstractAST) {
+        let Some(argument) = node.child_by_field_name("argument") else {
+            return;
+        };
+        let mut bindings = Vec::new();
+        Self::collect_use_tree(argument, source, "", &mut bindings);
+
+        for (path, alias) in bindings {
+            if let Some(glob_source) = path.strip_suffix("::*") {
+                ast.add_import(Import::glob(module.to_string(), glob_source.to_string()));
+                continue;
+            }
+            // `use a::{self}` imports the module `a` itself
+            let path = path.strip_suffix("::self").unwrap_or(&path);
+            let import = match path.rsplit_once("::") {
+                Some((parent, item)) => Import::item(
+                    module.to_string(),
+                    parent.to_string(),
+                    item.to_string(),
+                    alias.unwrap_or_else(|| item.to_string()),
+                ),
+                None => Import::module(
+                    module.to_string(),
+                    path.to_string(),
+                    alias.unwrap_or_else(|| path.to_string()),
+                ),
+            };
+            ast.add_import(import);
+        }
+    }
+
+    /// Flatten a use tree into `(path, alias)` pairs; glob imports end in `::*`
+    fn collect_use_tree(
+        node: tree_sitter::Node,
+        source: &str,
+        prefix: &str,
+        bindings: &mut Vec<(String, Option<String>)>,
+    ) {
+        let text = |n: tree_sitter::Node| normalize_type(&source[n.start_byte()..n.end_byte()]);
+        let join = |path: String| {
+            if prefix.is_empty() {
+                path
+            } else {
+                format!("{prefix}::{path}")
+            }
+        };
+
+        match node.kind() {
+            "use_as_clause" => {
+                if let Some(path) = node.child_by_field_name("path") {
+                    let alias = node.child_by_field_name("alias").map(text);
+                    bindings.push((join(text(path)), alias));
+                }
+            }
+            "use_wildcard" => {
+                let base = node.named_child(0).map_or_else(|| prefix.to_string(), |path| join(text(path)));
+                bindings.push((format!("{base}::*"), None));
+            }
+            "scoped_use_list" => {
+                let nested = node.child_by_field_name("path").map_or_else(|| prefix.to_string(), |p| join(text(p)));
+                if let Some(list) = node.child_by_field_name("list") {
+                    for item in list.named_children(&mut list.walk()) {
+                        Self::collect_use_tree(item, source, &nested, bindings);
+                    }
+                }
+            }
+            "use_list" => {
+                for item in node.named_children(&mut node.walk()) {
+                    Self::collect_use_tree(item, source, prefix, bindings);
+                }
+            }
+            _ => bindings.push((join(text(node)), None)),
+        }
+    }
+
     /// Extract identifier from a node (handles simple identifiers and field access)
     fn extract_identifier_or_field_access(
         node: tree_sitter::Node,
@@ -221,7 +374,8 @@ impl RustTranslator {
         let mut ast = AbstractAST::new(module_path.to_string());
 
         // Extract all functions and their calls
-        Self::extract_ast_recursive(root, source, module_path, &mut ast, "");
+        let mut definitions = HashMap::new();
+        Self::extract_ast_recursive(root, source, module_path, &mut ast, &mut definitions, "");
 
         Ok(ast)
     }
@@ -232,21 +386,36 @@ impl RustTranslator {
         source: &str,
         module: &str,
         ast: &mut AbstractAST,
+        definitions: &mut HashMap<FunctionId, usize>,
         impl_context: &str,
     ) {
-        if node.kind() == "impl_item" {
-            // Extract the type being implemented for
-            let mut impl_type = String::new();
-            for child in node.children(&mut node.walk()) {
-                if child.kind() == "type_identifier" || child.kind() == "identifier" {
-                    impl_type = source[child.start_byte()..child.end_byte()].to_string();
-                    break;
+        if node.kind() == "use_declaration" {
+            Self::extract_use(node, source, module, ast);
+            return;
+        }
+
+        if node.kind() == "mod_item" {
+            // Items of an inline `mod name { ... }` belong to the child module;
+            // file-backed `mod name;` declarations are followed by the loader
+            if let (Some(name), Some(body)) = (node.child_by_field_name("name"), node.child_by_field_name("body")) {
+                let child = format!("{module}::{}", &source[name.start_byte()..name.end_byte()]);
+                for item in body.children(&mut body.walk()) {
+                    Self::extract_ast_recursive(item, source, &child, ast, definitions, "");
                 }
             }
+            return;
+        }
+
+        if node.kind() == "impl_item" {
+            // Extract the type being implemented for (not the trait)
+            let impl_type = node
+                .child_by_field_name("type")
+                .map(|ty| Self::impl_type_name(ty, source))
+                .unwrap_or_default();
 
             // Recursively process children with impl context
             for child in node.children(&mut node.walk()) {
-                Self::extract_ast_recursive(child, source, module, ast, &impl_type);
+                Self::extract_ast_recursive(child, source, module, ast, definitions, &impl_type);
             }
             return;
         }
@@ -266,18 +435,19 @@ impl RustTranslator {
                 let mut calls = Vec::new();
                 Self::extract_calls_from_function_with_context(node, source, &mut calls, impl_context);
 
-                // Create function definition with impl context
-                let sig = Signature::empty(); // Simplified for now
+                // Create function definition with impl context; trait
+                // methods are named `<Type as Trait>::method`
+                let sig = Self::extract_signature(node, source);
                 let scoped_name = if impl_context.is_empty() {
                     func_name
                 } else {
-                    format!("{}::{}", impl_context, func_name)
+                    Self::trait_method_name(node, source).unwrap_or_else(|| format!("{impl_context}::{func_name}"))
                 };
-                let mut func_def = FunctionDef::new(scoped_name, sig, module.to_string());
+                let mut func_def = FunctionDef::new(scoped_name, sig, module.to_string()).with_span(span_of(node));
                 
-                for call_name in calls {
+                for site in calls {
                     // Determine if this is a local call that should be resolved within the module
-                    let target_module = if call_name.contains("::") {
+                    let target_module = if site.name.contains("::") {
                         // For method calls like "MyStruct::method2", try to resolve within current module
                         Some(module.to_string())
                     } else {
@@ -285,11 +455,11 @@ impl RustTranslator {
                         // This could be enhanced with more sophisticated analysis
                         None
                     };
-                    let call = FunctionCall::new(call_name, target_module, 0);
+                    let call = site.into_call(target_module);
                     func_def.add_call(call);
                 }
 
-                ast.add_function(func_def);
+                Self::add_definition(ast, definitions, func_def, node, source);
             }
         }
 
@@ -304,13 +474,13 @@ impl RustTranslator {
                 let sig = Signature::empty();
                 let mut func_def = FunctionDef::new("<module>".to_string(), sig, module.to_string());
                 
-                for call_name in calls {
-                    let call = FunctionCall::new(call_name, None, 0);
+                for site in calls {
+                    let call = site.into_call(None);
                     func_def.add_call(call);
                 }
                 
                 // Check if we already have a module-level function
-                if let Some(existing) = ast.functions.iter_mut().find(|f| f.name == "<module>") {
+                if let Some(existing) = ast.functions.iter_mut().find(|f| f.name == "<module>" && f.module == module) {
                     // Add calls to existing module function
                     for call in &func_def.calls {
                         existing.add_call(call.clone());
@@ -322,26 +492,145 @@ impl RustTranslator {
         }
 
         for child in node.children(&mut node.walk()) {
-            Self::extract_ast_recursive(child, source, module, ast, impl_context);
+            Self::extract_ast_recursive(child, source, module, ast, definitions, impl_context);
         }
     }
 
-    /// Extract calls within a single function
-    fn extract_calls_from_function(
-        func_node: tree_sitter::Node,
+    /// Add a definition, keeping function IDs unique within the module
+    ///
+    /// `definitions` indexes the functions added so far by ID. When both
+    /// definitions carry `#[cfg]` attributes they are alternatives of one
+    /// function, and the calls of the later one are merged into the first.
+    /// Any other clash is kept as a duplicate for the graph builder to report.
+    fn add_definition(
+        ast: &mut AbstractAST,
+        definitions: &mut HashMap<FunctionId, usize>,
+        func_def: FunctionDef,
+        node: tree_sitter::Node,
         source: &str,
-        calls: &mut Vec<String>,
     ) {
-        for child in func_node.children(&mut func_node.walk()) {
-            Self::extract_calls_recursive(child, source, calls);
+        let id = func_def.fn_id();
+        let Some(&index) = definitions.get(&id) else {
+            definitions.insert(id, ast.functions.len());
+            ast.add_function(func_def);
+            return;
+        };
+
+        let mut root = node;
+        while let Some(parent) = root.parent() {
+            root = parent;
+        }
+        let earlier = ast.functions[index]
+            .span
+            .and_then(|span| root.descendant_for_byte_range(span.start_byte, span.end_byte));
+        if Self::has_cfg(node, source) && earlier.is_some_and(|earlier| Self::has_cfg(earlier, source)) {
+            for call in func_def.calls {
+                ast.functions[index].add_call(call);
+            }
+        } else {
+            ast.add_function(func_def);
+        }
+    }
+
+    /// Whether an item is preceded by a `#[cfg(...)]` attribute
+    fn has_cfg(item: tree_sitter::Node, source: &str) -> bool {
+        let mut sibling = item.prev_sibling();
+        while let Some(node) = sibling {
+            match node.kind() {
+                "attribute_item" => {
+                    let name = node.named_child(0).and_then(|attribute| attribute.named_child(0));
+                    if name.is_some_and(|name| &source[name.start_byte()..name.end_byte()] == "cfg") {
+                        return true;
+                    }
+                }
+                "line_comment" | "block_comment" => {}
+                _ => return false,
+            }
+            sibling = node.prev_sibling();
+        }
+        false
+    }
+
+    /// `<Type as Trait>::method` for a method in a trait impl
+    fn trait_method_name(func_node: tree_sitter::Node, source: &str) -> Option<String> {
+        let impl_node = func_node.parent()?.parent().filter(|node| node.kind() == "impl_item")?;
+        let trait_node = impl_node.child_by_field_name("trait")?;
+        let type_node = impl_node.child_by_field_name("type")?;
+        let name = func_node.child_by_field_name("name")?;
+        Some(format!(
+            "<{} as {}>::{}",
+            Self::impl_type_name(type_node, source),
+            normalize_type(&source[trait_node.start_byte()..trait_node.end_byte()]),
+            &source[name.start_byte()..name.end_byte()]
+        ))
+    }
+
+    /// Base name of the type in an `impl` block, e.g. `Foo` for `Foo<'a, T>` or `a::Foo`
+    fn impl_type_name(node: tree_sitter::Node, source: &str) -> String {
+        match node.kind() {
+            "generic_type" => node
+                .child_by_field_name("type")
+                .map(|ty| Self::impl_type_name(ty, source))
+                .unwrap_or_default(),
+            "scoped_type_identifier" => node
+                .child_by_field_name("name")
+                .map(|name| source[name.start_byte()..name.end_byte()].to_string())
+                .unwrap_or_default(),
+            _ => normalize_type(&source[node.start_byte()..node.end_byte()]),
+        }
+    }
+
+    /// Build a signature from the generics, parameters and return type of a `function_item`
+    fn extract_signature(func_node: tree_sitter::Node, source: &str) -> Signature {
+        let text = |n: tree_sitter::Node| normalize_type(&source[n.start_byte()..n.end_byte()]);
+
+        let mut params = Vec::new();
+        if let Some(parameters) = func_node.child_by_field_name("parameters") {
+            for param in parameters.named_children(&mut parameters.walk()) {
+                match param.kind() {
+                    "self_parameter" => {
+                        // `&'a mut self` has type `&'a mut Self`; `mut self` is just `Self`
+                        let receiver = text(param);
+                        let ty = if receiver.starts_with('&') {
+                            format!("{}Self", receiver.trim_end_matches("self"))
+                        } else {
+                            "Self".to_string()
+                        };
+                        params.push(("self".to_string(), ty));
+                    }
+                    "parameter" => {
+                        let name = param
+                            .child_by_field_name("pattern")
+                            .map_or_else(|| "_".to_string(), text);
+                        let ty = param
+                            .child_by_field_name("type")
+                            .map(text)
+                            .unwrap_or_default();
+                        params.push((name, ty));
+                    }
+                    "variadic_parameter" => params.push(("...".to_string(), text(param))),
+                    _ => {}
+                }
+            }
         }
+
+        let return_type = func_node
+            .child_by_field_name("return_type")
+            .map_or_else(|| "()".to_string(), text);
+
+        let type_params = func_node
+            .child_by_field_name("type_parameters")
+            .map(|tp| tp.named_children(&mut tp.walk()).map(text).collect())
+            .unwrap_or_default();
+
+        Signature::new(params, return_type).with_type_params(type_params)
     }
 
     /// Extract calls within a single function with impl context for better resolution
     fn extract_calls_from_function_with_context(
         func_node: tree_sitter::Node,
         source: &str,
-        calls: &mut Vec<String>,
+        calls: &mut Vec<CallSite>,
         impl_context: &str,
     ) {
         for child in func_node.children(&mut func_node.walk()) {
@@ -353,14 +642,15 @@ impl RustTranslator {
     fn extract_calls_recursive_with_context(
         node: tree_sitter::Node,
         source: &str,
-        calls: &mut Vec<String>,
+        calls: &mut Vec<CallSite>,
         impl_context: &str,
     ) {
         if node.kind() == "call_expression" {
             if let Some(child) = node.child(0) {
-                let call_name = Self::extract_identifier_or_field_access_with_context(child, source, impl_context);
-                if let Some(name) = call_name {
-                    calls.push(name);
+                if let Some(site) = Self::scoped_call(child, source, impl_context) {
+                    calls.push(site);
+                } else if let Some(name) = Self::extract_identifier_or_field_access_with_context(child, source, impl_context) {
+                    calls.push(call_site(name, callee_name_node(child)));
                 }
             }
 
@@ -382,7 +672,7 @@ impl RustTranslator {
                                                 if let Some(arg_child) = arg.child(j) {
                                                     if arg_child.kind() == "identifier" {
                                                         let name = &source[arg_child.start_byte()..arg_child.end_byte()];
-                                                        calls.push(name.to_string());
+                                                        calls.push(call_site(name, arg_child));
                                                     }
                                                 }
                                             }
@@ -409,7 +699,9 @@ impl RustTranslator {
     pub fn translate_file(&self, path: &str, module_path: &str) -> Result<AbstractAST, String> {
         let source = std::fs::read_to_string(path)
             .map_err(|e| format!("Failed to read file: {e}"))?;
-        self.translate(&source, module_path)
+        let mut ast = self.translate(&source, module_path)?;
+        ast.set_source_file(path);
+        Ok(ast)
     }
 }
 
@@ -426,7 +718,9 @@ impl crate::translator_trait::Translator for RustTranslator {
                 .unwrap_or("root")
                 .to_string()
         };
-        self.translate(&source, &module)
+        let mut ast = self.translate(&source, &module)?;
+        ast.set_source_file(path);
+        Ok(ast)
     }
 }
 
@@ -489,19 +783,51 @@ mod tests {
     }
 
     #[test]
-    fn test_extract_module_path_empty() {
+    fn test_module_tree_empty() {
         let translator = RustTranslator::new();
         let source = "fn main() {}";
-        let path = translator.extract_module_path(source, "root").unwrap();
-        assert_eq!(path, "root");
+        let modules = translator.module_tree(source, "main").unwrap();
+        assert!(modules.is_empty());
     }
 
     #[test]
-    fn test_extract_module_path_with_mod() {
+    fn test_module_tree_inline_and_file_backed() {
         let translator = RustTranslator::new();
-        let source = "mod helpers;\nfn main() {}";
-        let path = translator.extract_module_path(source, "root").unwrap();
-        assert_eq!(path, "root::helpers");
+        let source = "mod helpers;\npub mod a {\n    mod b;\n    mod c { fn f() {} }\n}\nfn main() {}";
+        let modules = translator.module_tree(source, "app").unwrap();
+        let decl = |path: &str, inline| ModuleDecl { path: path.to_string(), inline, file: None };
+        assert_eq!(
+            modules,
+            vec![
+                decl("app::helpers", false),
+                decl("app::a", true),
+                decl("app::a::b", false),
+                decl("app::a::c", true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_module_tree_path_attribute() {
+        let translator = RustTranslator::new();
+        let source = "#[cfg(unix)]\n#[path = \"sys/unix.rs\"]\n/// Platform layer\nmod sys;\nmod plain;";
+        let modules = translator.module_tree(source, "app").unwrap();
+        assert_eq!(modules[0].file.as_deref(), Some("sys/unix.rs"));
+        assert_eq!(modules[1].file, None);
+    }
+
+    #[test]
+    fn test_inline_modules_scope_functions() {
+        let source = "fn top() {}\nmod a {\n    fn f() { g(); }\n    fn g() {}\n    pub mod b {\n        struct S;\n        impl S { fn m(&self) {} }\n    }\n}\n#[cfg(test)]\nmod tests {\n    use super::*;\n    fn t() { top(); }\n}";
+
+        let ast = RustTranslator::new().translate(source, "app").unwrap();
+        let module_of = |name: &str| ast.get_function(name).map(|f| f.module.as_str());
+        assert_eq!(module_of("top"), Some("app"));
+        assert_eq!(module_of("f"), Some("app::a"));
+        assert_eq!(module_of("g"), Some("app::a"));
+        assert_eq!(module_of("S::m"), Some("app::a::b"));
+        assert_eq!(module_of("t"), Some("app::tests"));
+        assert_eq!(ast.imports, vec![Import::glob("app::tests".into(), "super".into())]);
     }
 
     #[test]
@@ -511,4 +837,146 @@ mod tests {
         let calls = translator.extract_function_calls(source).unwrap();
         assert_eq!(calls.len(), 0);
     }
+
+    fn signature_of(source: &str, name: &str) -> String {
+        let ast = RustTranslator::new().translate(source, "root").unwrap();
+        ast.get_function(name).unwrap().signature.to_string()
+    }
+
+    #[test]
+    fn test_signature_params_and_return() {
+        let source = "fn add(a: i32, mut b: Vec<String>) -> Option<usize> { None }";
+        assert_eq!(signature_of(source, "add"), "(a: i32, b: Vec<String>) -> Option<usize>");
+    }
+
+    #[test]
+    fn test_signature_without_return_type() {
+        assert_eq!(signature_of("fn main() {}", "main"), "() -> ()");
+    }
+
+    #[test]
+    fn test_signature_self_receivers() {
+        let source = "struct S;\nimpl S {\n    fn a(&self) {}\n    fn b(&mut self) {}\n    fn c(mut self) {}\n    fn d<'a>(&'a self) -> &'a str { \"\" }\n}";
+        assert_eq!(signature_of(source, "S::a"), "(self: &Self) -> ()");
+        assert_eq!(signature_of(source, "S::b"), "(self: &mut Self) -> ()");
+        assert_eq!(signature_of(source, "S::c"), "(self: Self) -> ()");
+        assert_eq!(signature_of(source, "S::d"), "<'a>(self: &'a Self) -> &'a str");
+    }
+
+    #[test]
+    fn test_signature_generics_and_multiline() {
+        let source = "fn pick<T: Clone, const N: usize>(\n    items: &[T;\n        N],\n) -> T { items[0].clone() }";
+        assert_eq!(signature_of(source, "pick"), "<T: Clone, const N: usize>(items: &[T; N]) -> T");
+    }
+
+    #[test]
+    fn test_trait_impls_use_implementing_type() {
+        let source = "struct A;\nstruct B<T>(T);\nimpl Default for A { fn default() -> Self { A } }\nimpl<T: Default> Default for B<T> { fn default() -> Self { B(T::default()) } }";
+        let ast = RustTranslator::new().translate(source, "root").unwrap();
+        assert!(ast.get_function("<A as Default>::default").is_some());
+        assert!(ast.get_function("<B as Default>::default").is_some());
+    }
+
+    #[test]
+    fn test_same_name_methods_get_distinct_ids() {
+        let source = "struct X;\nimpl From<u8> for X { fn from(v: u8) -> Self { X } }\nimpl From<bool> for X { fn from(v: bool) -> Self { X } }";
+        let ast = RustTranslator::new().translate(source, "root").unwrap();
+        let names: Vec<&str> = ast.functions.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, ["<X as From<u8>>::from", "<X as From<bool>>::from"]);
+
+        let mut builder = trackast_lib::builder::CallGraphBuilder::new();
+        assert!(builder.add_ast(ast).is_ok());
+    }
+
+    #[test]
+    fn test_trait_methods_are_named_by_trait_in_any_order() {
+        let trait_first = "struct T;\nimpl Tr for T { fn run(&self) { a(); } }\nimpl T { fn run(&self) { b(); } }";
+        let inherent_first = "struct T;\nimpl T { fn run(&self) { b(); } }\nimpl Tr for T { fn run(&self) { a(); } }";
+        let trait_only = "struct T;\nimpl Tr for T { fn run(&self) { a(); } }";
+        for source in [trait_first, inherent_first, trait_only] {
+            let ast = RustTranslator::new().translate(source, "app").unwrap();
+            assert_eq!(ast.get_function("<T as Tr>::run").unwrap().calls[0].target_name, "a");
+        }
+
+        let ast = RustTranslator::new().translate(trait_first, "app").unwrap();
+        assert_eq!(ast.get_function("T::run").unwrap().calls[0].target_name, "b");
+    }
+
+    #[test]
+    fn test_cfg_alternatives_merge_into_one_definition() {
+        let source = "#[cfg(unix)]\nfn open() { unix_open(); }\n#[cfg(not(unix))]\nfn open() { other_open(); }";
+        let ast = RustTranslator::new().translate(source, "app").unwrap();
+
+        let opens: Vec<_> = ast.functions.iter().filter(|f| f.name == "open").collect();
+        assert_eq!(opens.len(), 1);
+        let calls: Vec<&str> = opens[0].calls.iter().map(|c| c.target_name.as_str()).collect();
+        assert_eq!(calls, ["unix_open", "other_open"]);
+    }
+
+    #[test]
+    fn test_duplicate_definitions_without_cfg_are_not_merged() {
+        let source = "#[inline]\nfn open() { first(); }\nfn open() { second(); }";
+        let ast = RustTranslator::new().translate(source, "app").unwrap();
+
+        let opens: Vec<_> = ast.functions.iter().filter(|f| f.name == "open").collect();
+        assert_eq!(opens.len(), 2);
+        assert_eq!(opens[0].calls.len(), 1);
+
+        let mut builder = trackast_lib::builder::CallGraphBuilder::new();
+        assert!(builder.add_ast(ast).is_err());
+    }
+
+    #[test]
+    fn test_call_sites_have_line_and_column() {
+        let source = "fn main() {\n    let s = S;\n    helper();\n    s.run();\n}";
+        let ast = RustTranslator::new().translate(source, "root").unwrap();
+        let calls = &ast.get_function("main").unwrap().calls;
+
+        let helper = calls.iter().find(|c| c.target_name == "helper").unwrap();
+        assert_eq!((helper.line, helper.column), (3, 5));
+        let run = calls.iter().find(|c| c.target_name == "run").unwrap();
+        assert_eq!((run.line, run.column), (4, 7));
+    }
+
+    #[test]
+    fn test_function_spans() {
+        let source = "fn main() {\n    helper();\n}\n\nfn helper() {}";
+        let ast = RustTranslator::new().translate(source, "root").unwrap();
+
+        let main = ast.get_function("main").unwrap().span.unwrap();
+        assert_eq!((main.start_line, main.end_line), (1, 3));
+        assert_eq!(&source[main.start_byte..main.end_byte], "fn main() {\n    helper();\n}");
+        let helper = ast.get_function("helper").unwrap().span.unwrap();
+        assert_eq!((helper.start_line, helper.end_line), (5, 5));
+    }
+
+    #[test]
+    fn test_use_declarations_become_imports() {
+        let source = "use a::b::{self, c as d, e::*};\nuse utils::helper;\nfn main() {}";
+        let ast = RustTranslator::new().translate(source, "app").unwrap();
+        assert_eq!(
+            ast.imports,
+            vec![
+                Import::item("app".into(), "a".into(), "b".into(), "b".into()),
+                Import::item("app".into(), "a::b".into(), "c".into(), "d".into()),
+                Import::glob("app".into(), "a::b::e".into()),
+                Import::item("app".into(), "utils".into(), "helper".into(), "helper".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_path_calls_record_qualifier() {
+        let source = "struct P;\nimpl P {\n    fn new() -> Self { P }\n    fn make() -> Self { Self::new() }\n}\nfn main() { utils::fmt::run(); P::new(); }";
+        let ast = RustTranslator::new().translate(source, "app").unwrap();
+
+        let main_calls = &ast.get_function("main").unwrap().calls;
+        let run = main_calls.iter().find(|c| c.target_name == "run").unwrap();
+        assert_eq!(run.qualifier.as_deref(), Some("utils::fmt"));
+        let new = main_calls.iter().find(|c| c.target_name == "new").unwrap();
+        assert_eq!(new.qualifier.as_deref(), Some("P"));
+
+        let make_calls = &ast.get_function("P::make").unwrap().calls;
+        assert_eq!(make_calls[0].qualifier.as_deref(), Some("P"));
+    }
 }
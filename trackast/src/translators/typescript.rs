@@ -0,0 +1,481 @@
+use std::collections::HashMap;
+use tree_sitter::{Node, Parser};
+use trackast_lib::ast::{AbstractAST, FunctionCall, FunctionDef, Signature};
+use super::{normalize_type, span_of, CallSite, JavaScriptTranslator};
+
+/// Translator for TypeScript and TSX source code to abstract AST
+///
+/// Expressions, imports and calls share their syntax with JavaScript and are
+/// extracted the same way. Declarations are read here, so signatures carry
+/// parameter and return type annotations, and interfaces, abstract members
+/// and namespaces are understood.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TypeScriptTranslator {
+    /// Parse with the TSX grammar, which allows JSX but not `<T>x` casts
+    tsx: bool,
+}
+
+/// Where a declaration sits while walking the tree
+#[derive(Clone, Copy)]
+struct Scope<'a> {
+    module: &'a str,
+    /// Enclosing class or interface, empty at module level
+    class: &'a str,
+    /// Directly in the module or a namespace body, not inside a function
+    top_level: bool,
+}
+
+impl TypeScriptTranslator {
+    /// Create a new TypeScript translator
+    #[must_use]
+    pub fn new() -> Self {
+        TypeScriptTranslator { tsx: false }
+    }
+
+    /// Use the TSX grammar
+    #[must_use]
+    pub fn with_tsx(mut self, tsx: bool) -> Self {
+        self.tsx = tsx;
+        self
+    }
+
+    /// Set up a parser for TypeScript, or TSX when `tsx` is set
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the parser cannot be initialized or language set.
+    pub fn setup_parser(tsx: bool) -> Result<Parser, String> {
+        let mut parser = Parser::new();
+        let language = if tsx {
+            tree_sitter_typescript::language_tsx()
+        } else {
+            tree_sitter_typescript::language_typescript()
+        };
+        parser
+            .set_language(language)
+            .map_err(|_| "Failed to set TypeScript language".to_string())?;
+        Ok(parser)
+    }
+
+    /// Parse source code and return the tree
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if parsing fails.
+    pub fn parse_source(&self, source: &str) -> Result<tree_sitter::Tree, String> {
+        let mut parser = Self::setup_parser(self.tsx)?;
+        parser
+            .parse(source, None)
+            .ok_or_else(|| "Failed to parse source".to_string())
+    }
+
+    /// Translate TypeScript source to abstract AST
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if parsing fails.
+    pub fn translate(&self, source: &str, module_path: &str) -> Result<AbstractAST, String> {
+        let tree = self.parse_source(source)?;
+        let mut ast = AbstractAST::new(module_path.to_string());
+        let mut heritage = Vec::new();
+
+        let scope = Scope {
+            module: module_path,
+            class: "",
+            top_level: true,
+        };
+        Self::extract_ast_recursive(tree.root_node(), source, scope, &mut ast, &mut heritage);
+        Self::add_dispatch_calls(&mut ast, &heritage);
+
+        Ok(ast)
+    }
+
+    /// Recursively extract functions and build AST
+    ///
+    /// `heritage` collects `(module, class, supertypes)` for every class with
+    /// an `extends` or `implements` clause.
+    fn extract_ast_recursive(
+        node: Node,
+        source: &str,
+        scope: Scope,
+        ast: &mut AbstractAST,
+        heritage: &mut Vec<(String, String, Vec<String>)>,
+    ) {
+        let text = |n: Node| source[n.start_byte()..n.end_byte()].to_string();
+        let name_of = |n: Node| n.child_by_field_name("name").map(text);
+        let mut inner = scope;
+
+        match node.kind() {
+            "import_statement" => {
+                JavaScriptTranslator::extract_import(node, source, scope.module, ast);
+                return;
+            }
+            // Overload and ambient declarations have no body to call anything
+            "function_signature" | "method_signature" | "ambient_declaration" => return,
+            "variable_declarator" => {
+                JavaScriptTranslator::extract_require(node, source, scope.module, ast);
+                let value = node.child_by_field_name("value").filter(|v| matches!(v.kind(), "arrow_function" | "function"));
+                if let (Some(name), Some(value), "") = (name_of(node), value, scope.class) {
+                    Self::add_function(node, value, name, source, scope, ast);
+                    inner.top_level = false;
+                }
+            }
+            "function_declaration" | "generator_function_declaration" => {
+                if let Some(name) = name_of(node) {
+                    let name = Self::scoped_name(scope.class, &name);
+                    Self::add_function(node, node, name, source, scope, ast);
+                }
+                inner.top_level = false;
+            }
+            "method_definition" => {
+                if let Some(name) = name_of(node) {
+                    let name = Self::scoped_name(scope.class, &name);
+                    Self::add_function(node, node, name, source, scope, ast);
+                }
+                inner.top_level = false;
+            }
+            // Abstract methods are declared so calls through the base class bind
+            "abstract_method_signature" => {
+                if let Some(name) = name_of(node) {
+                    let name = Self::scoped_name(scope.class, &name);
+                    Self::add_function(node, node, name, source, scope, ast);
+                }
+                return;
+            }
+            "class_declaration" | "abstract_class_declaration" | "class" => {
+                let class = name_of(node).unwrap_or_else(|| "default".to_string());
+                let supertypes = Self::supertypes(node, source);
+                if !supertypes.is_empty() {
+                    heritage.push((scope.module.to_string(), class.clone(), supertypes));
+                }
+                if let Some(body) = node.child_by_field_name("body") {
+                    let scope = Scope { class: &class, ..scope };
+                    for child in body.named_children(&mut body.walk()) {
+                        Self::extract_ast_recursive(child, source, scope, ast, heritage);
+                    }
+                }
+                return;
+            }
+            // Interface methods are declared so calls on interface-typed values bind
+            "interface_declaration" => {
+                let (Some(interface), Some(body)) = (name_of(node), node.child_by_field_name("body")) else {
+                    return;
+                };
+                for method in body.named_children(&mut body.walk()).filter(|m| m.kind() == "method_signature") {
+                    if let Some(name) = name_of(method) {
+                        let scope = Scope { class: &interface, ..scope };
+                        Self::add_function(method, method, Self::scoped_name(&interface, &name), source, scope, ast);
+                    }
+                }
+                return;
+            }
+            // `namespace Geo.Util { ... }` scopes its members under `module::Geo::Util`
+            "internal_module" | "module" => {
+                let name = node.child_by_field_name("name").filter(|n| n.kind() != "string");
+                if let (Some(name), Some(body)) = (name, node.child_by_field_name("body")) {
+                    let module = format!("{}::{}", scope.module, text(name).replace('.', "::"));
+                    let scope = Scope {
+                        module: &module,
+                        class: "",
+                        top_level: true,
+                    };
+                    for child in body.named_children(&mut body.walk()) {
+                        Self::extract_ast_recursive(child, source, scope, ast, heritage);
+                    }
+                }
+                return;
+            }
+            "expression_statement" if scope.top_level && scope.class.is_empty() => {
+                let is_namespace = node
+                    .named_child(0)
+                    .is_some_and(|child| matches!(child.kind(), "internal_module" | "module"));
+                if !is_namespace {
+                    Self::add_module_calls(node, source, scope.module, ast);
+                    inner.top_level = false;
+                }
+            }
+            _ => {}
+        }
+
+        for child in node.children(&mut node.walk()) {
+            Self::extract_ast_recursive(child, source, inner, ast, heritage);
+        }
+    }
+
+    /// `Class.method` inside a class, the plain name outside one
+    fn scoped_name(class: &str, name: &str) -> String {
+        if class.is_empty() {
+            name.to_string()
+        } else {
+            format!("{class}.{name}")
+        }
+    }
+
+    /// Add a function declared at `node`, whose parameters, return type and
+    /// body belong to `func_node`
+    ///
+    /// Calls on a parameter annotated with a named type go through that type,
+    /// so `g.greet()` with `g: Greeter` binds to `Greeter.greet`. A type
+    /// parameter stands for its constraint (`T extends Greeter`).
+    fn add_function(node: Node, func_node: Node, name: String, source: &str, scope: Scope, ast: &mut AbstractAST) {
+        let signature = Self::extract_signature(func_node, source);
+        let constraints: HashMap<&str, &str> = signature
+            .type_params
+            .iter()
+            .filter_map(|param| param.split_once(" extends "))
+            .collect();
+        let receivers: HashMap<String, String> = signature
+            .params
+            .iter()
+            .filter_map(|(param, ty)| {
+                let ty = constraints.get(ty.as_str()).copied().unwrap_or(ty);
+                Some((param.trim_end_matches('?').to_string(), Self::named_type(ty)?))
+            })
+            .collect();
+
+        let mut sites = Vec::new();
+        JavaScriptTranslator::extract_calls_from_function_with_context(func_node, source, &mut sites, scope.class);
+
+        let mut func_def = FunctionDef::new(name, signature, scope.module.to_string()).with_span(span_of(node));
+        for mut site in sites {
+            if let Some(ty) = site.qualifier.as_deref().and_then(|q| receivers.get(q)) {
+                site.qualifier = Some(ty.clone());
+            }
+            func_def.add_call(Self::into_call(site, scope.module));
+        }
+        ast.add_function(func_def);
+    }
+
+    /// Record the calls of a top-level statement on the `<module>` function
+    fn add_module_calls(node: Node, source: &str, module: &str, ast: &mut AbstractAST) {
+        let mut sites = Vec::new();
+        JavaScriptTranslator::extract_calls_recursive(node, source, &mut sites);
+        if sites.is_empty() {
+            return;
+        }
+
+        let calls = sites.into_iter().map(|site| Self::into_call(site, module));
+        let existing = ast.functions.iter_mut().find(|f| f.name == "<module>" && f.module == module);
+        if let Some(existing) = existing {
+            calls.for_each(|call| existing.add_call(call));
+        } else {
+            let mut func_def = FunctionDef::new("<module>".to_string(), Signature::empty(), module.to_string());
+            calls.for_each(|call| func_def.add_call(call));
+            ast.add_function(func_def);
+        }
+    }
+
+    /// Method calls on `this` (`Class.method`) stay in the current module;
+    /// everything else is left to resolution
+    fn into_call(site: CallSite, module: &str) -> FunctionCall {
+        let target_module = site.name.contains('.').then(|| module.to_string());
+        site.into_call(target_module)
+    }
+
+    /// Build a signature from the type parameters, parameters and return type annotation
+    fn extract_signature(func_node: Node, source: &str) -> Signature {
+        let text = |n: Node| normalize_type(&source[n.start_byte()..n.end_byte()]);
+        // `: T` annotations hold the type as their only named child
+        let annotation = |n: Node| n.named_child(0).map(text).unwrap_or_default();
+
+        let mut params = Vec::new();
+        if let Some(parameters) = func_node.child_by_field_name("parameters") {
+            for param in parameters.named_children(&mut parameters.walk()) {
+                let Some(pattern) = param.child_by_field_name("pattern") else {
+                    continue;
+                };
+                let mut name = text(pattern);
+                if param.kind() == "optional_parameter" {
+                    name.push('?');
+                }
+                let ty = param.child_by_field_name("type").map(annotation).unwrap_or_default();
+                params.push((name, ty));
+            }
+        } else if let Some(parameter) = func_node.child_by_field_name("parameter") {
+            // `x => x + 1`
+            params.push((text(parameter), String::new()));
+        }
+
+        let return_type = func_node
+            .child_by_field_name("return_type")
+            .map_or_else(|| "()".to_string(), annotation);
+
+        let type_params = func_node
+            .child_by_field_name("type_parameters")
+            .map(|tp| tp.named_children(&mut tp.walk()).map(text).collect())
+            .unwrap_or_default();
+
+        Signature::new(params, return_type).with_type_params(type_params)
+    }
+
+    /// The named type a parameter annotation refers to, without type
+    /// arguments: `Repo<User>` gives `Repo`, `Geo.Point` gives `Geo::Point`
+    fn named_type(ty: &str) -> Option<String> {
+        let base = ty.split('<').next().unwrap_or(ty);
+        let is_name = base
+            .split('.')
+            .all(|part| part.starts_with(|c: char| c.is_ascii_uppercase()) && part.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '$'));
+        is_name.then(|| base.replace('.', "::"))
+    }
+
+    /// Names of the classes and interfaces in a class's `extends` and
+    /// `implements` clauses, without type arguments
+    fn supertypes(class_node: Node, source: &str) -> Vec<String> {
+        let Some(heritage) = class_node
+            .children(&mut class_node.walk())
+            .find(|child| child.kind() == "class_heritage")
+        else {
+            return Vec::new();
+        };
+
+        let mut supertypes = Vec::new();
+        for clause in heritage.named_children(&mut heritage.walk()) {
+            for ty in clause.named_children(&mut clause.walk()) {
+                let name_node = ty.child_by_field_name("name").unwrap_or(ty);
+                if matches!(name_node.kind(), "identifier" | "type_identifier") {
+                    supertypes.push(source[name_node.start_byte()..name_node.end_byte()].to_string());
+                }
+            }
+        }
+        supertypes
+    }
+
+    /// Link interface and base class methods to the methods overriding them
+    ///
+    /// A call to `Shape.area` may run `Circle.area` when `Circle implements
+    /// Shape` (or extends it), so `Shape.area` gets a call to each override.
+    /// Only supertypes declared in the same file are linked.
+    fn add_dispatch_calls(ast: &mut AbstractAST, heritage: &[(String, String, Vec<String>)]) {
+        let mut dispatch = Vec::new();
+        for (module, class, supertypes) in heritage {
+            let prefix = format!("{class}.");
+            for method in ast.functions.iter().filter(|f| &f.module == module) {
+                let Some(name) = method.name.strip_prefix(&prefix) else {
+                    continue;
+                };
+                let line = method.span.map_or(0, |span| span.start_line);
+                for supertype in supertypes {
+                    dispatch.push((module.clone(), format!("{supertype}.{name}"), method.name.clone(), line));
+                }
+            }
+        }
+
+        for (module, declared, overriding, line) in dispatch {
+            if let Some(declaration) = ast.functions.iter_mut().find(|f| f.module == module && f.name == declared) {
+                declaration.add_call(FunctionCall::new(overriding, Some(module), line));
+            }
+        }
+    }
+
+    /// Translate a TypeScript file to abstract AST, with the TSX grammar for `.tsx` files
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if file reading or parsing fails.
+    pub fn translate_file(&self, path: &str, module_path: Option<&str>) -> Result<AbstractAST, String> {
+        let source = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read file: {e}"))?;
+
+        let module = if let Some(m) = module_path {
+            m.to_string()
+        } else {
+            JavaScriptTranslator::new().extract_module_path(path)?
+        };
+
+        let translator = self.with_tsx(self.tsx || path.ends_with(".tsx"));
+        let mut ast = translator.translate(&source, &module)?;
+
+        ast.set_source_file(path);
+
+        Ok(ast)
+    }
+}
+
+impl crate::translator_trait::Translator for TypeScriptTranslator {
+    fn translate_file(&self, path: &str, module_path: Option<&str>) -> Result<AbstractAST, String> {
+        TypeScriptTranslator::translate_file(self, path, module_path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use trackast_lib::ast::Import;
+
+    fn translate(source: &str) -> AbstractAST {
+        TypeScriptTranslator::new().translate(source, "app").unwrap()
+    }
+
+    fn signature_of(ast: &AbstractAST, name: &str) -> String {
+        ast.get_function(name).unwrap().signature.to_string()
+    }
+
+    #[test]
+    fn test_signatures_from_annotations() {
+        let source = "function add(a: number, b?: string, ...rest: number[]): Promise<number> { return a; }\n\
+                      const pick = <T,>(items: T[]): T => items[0];\n\
+                      function untyped(x) {}";
+        let ast = translate(source);
+        assert_eq!(signature_of(&ast, "add"), "(a: number, b?: string, ...rest: number[]) -> Promise<number>");
+        assert_eq!(signature_of(&ast, "pick"), "<T>(items: T[]) -> T");
+        assert_eq!(signature_of(&ast, "untyped"), "(x: ) -> ()");
+    }
+
+    #[test]
+    fn test_overload_signatures_are_not_definitions() {
+        let source = "function over(a: string): void;\nfunction over(a: any): void { helper(a); }";
+        let ast = translate(source);
+        let overs: Vec<&FunctionDef> = ast.functions.iter().filter(|f| f.name == "over").collect();
+        assert_eq!(overs.len(), 1);
+        assert_eq!(overs[0].signature.to_string(), "(a: any) -> void");
+    }
+
+    #[test]
+    fn test_abstract_classes_and_implements() {
+        let source = "interface Shape { area(scale?: number): number; }\n\
+                      abstract class Base implements Shape {\n  abstract area(scale?: number): number;\n  describe(): string { return this.area(2).toString(); }\n}\n\
+                      class Square extends Base {\n  area(scale?: number): number { return 1; }\n}";
+        let ast = translate(source);
+
+        assert_eq!(signature_of(&ast, "Shape.area"), "(scale?: number) -> number");
+        assert_eq!(signature_of(&ast, "Base.area"), "(scale?: number) -> number");
+        let describe = ast.get_function("Base.describe").unwrap();
+        assert!(describe.calls.iter().any(|c| c.target_name == "Base.area"));
+
+        let dispatch = |name: &str| -> Vec<String> {
+            ast.get_function(name).unwrap().calls.iter().map(|c| c.target_name.clone()).collect()
+        };
+        assert_eq!(dispatch("Shape.area"), ["Base.area"]);
+        assert_eq!(dispatch("Base.area"), ["Square.area"]);
+    }
+
+    #[test]
+    fn test_typed_parameters_qualify_calls() {
+        let source = "function render<S extends Shape>(shape: S, repo: Repo<User>, n: number) {\n  shape.area();\n  repo.find();\n}";
+        let ast = translate(source);
+        let calls = &ast.get_function("render").unwrap().calls;
+        let qualifier = |name: &str| calls.iter().find(|c| c.target_name == name).unwrap().qualifier.clone();
+        assert_eq!(qualifier("area").as_deref(), Some("Shape"));
+        assert_eq!(qualifier("find").as_deref(), Some("Repo"));
+    }
+
+    #[test]
+    fn test_namespaces_scope_functions() {
+        let source = "namespace Geo.Util {\n  export function dist(): number { return 0; }\n}\nfunction main() { Geo.Util.dist(); }";
+        let ast = translate(source);
+        let dist = ast.functions.iter().find(|f| f.name == "dist").unwrap();
+        assert_eq!(dist.module, "app::Geo::Util");
+        let call = &ast.get_function("main").unwrap().calls[0];
+        assert_eq!(call.qualifier.as_deref(), Some("Geo::Util"));
+    }
+
+    #[test]
+    fn test_imports_and_tsx() {
+        let source = "import { useState } from 'react';\nimport type { Props } from './props';\n\
+                      export function App(props: Props): JSX.Element {\n  const [n] = useState(0);\n  return <div>{n}</div>;\n}";
+        let ast = TypeScriptTranslator::new().with_tsx(true).translate(source, "app").unwrap();
+        assert_eq!(ast.imports[0], Import::item("app".into(), "react".into(), "useState".into(), "useState".into()));
+        assert_eq!(signature_of(&ast, "App"), "(props: Props) -> JSX.Element");
+        assert!(ast.get_function("App").unwrap().calls.iter().any(|c| c.target_name == "useState"));
+    }
+}
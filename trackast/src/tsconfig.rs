@@ -0,0 +1,261 @@
+//! Path mapping from `tsconfig.json` and `jsconfig.json`
+//!
+//! Only `compilerOptions.baseUrl` and `compilerOptions.paths` are read,
+//! following the `extends` chain. As in `tsc`, options of an extending config
+//! replace inherited ones, `baseUrl` is relative to the config defining it,
+//! and `paths` targets are relative to the effective `baseUrl` or, without
+//! one, to the config defining `paths`.
+
+use crate::node::normalize;
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+/// How many `extends` links are followed before giving up, which also stops cycles
+const MAX_EXTENDS_DEPTH: usize = 16;
+
+/// Config file names looked for in a directory, in order
+const CONFIG_NAMES: [&str; 2] = ["tsconfig.json", "jsconfig.json"];
+
+/// The module resolution options of a config, with `extends` applied
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TsConfig {
+    /// Absolute `baseUrl`; bare specifiers are also looked up under it
+    pub base_url: Option<PathBuf>,
+    /// `paths` patterns and their targets, in file order
+    pub paths: Vec<(String, Vec<String>)>,
+    /// Directory of the config defining `paths`
+    pub paths_dir: PathBuf,
+}
+
+impl TsConfig {
+    /// The config file in `dir`, if there is one
+    #[must_use]
+    pub fn find(dir: &Path) -> Option<PathBuf> {
+        CONFIG_NAMES
+            .iter()
+            .map(|name| dir.join(name))
+            .find(|candidate| candidate.is_file())
+    }
+
+    /// Read a config and the configs it extends
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a config in the chain cannot be read or parsed,
+    /// names a base config that does not exist, or the chain is too deep.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        Self::load_chain(path, MAX_EXTENDS_DEPTH)
+    }
+
+    fn load_chain(path: &Path, depth: usize) -> Result<Self, String> {
+        if depth == 0 {
+            return Err(format!("Too many `extends` levels at {}", path.display()));
+        }
+        let source = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {e}", path.display()))?;
+        let config: Value = serde_json::from_str(&strip_jsonc(&source))
+            .map_err(|e| format!("Failed to parse {}: {e}", path.display()))?;
+        let dir = path.parent().unwrap_or_else(|| Path::new(""));
+
+        // `extends` is a string or, since TypeScript 5.0, a list applied in order
+        let mut merged = TsConfig {
+            paths_dir: dir.to_path_buf(),
+            ..TsConfig::default()
+        };
+        let extends: Vec<&str> = match config.get("extends") {
+            Some(Value::String(base)) => vec![base.as_str()],
+            Some(Value::Array(bases)) => bases.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        };
+        for base in extends {
+            let base_path = extended_config(dir, base)
+                .ok_or_else(|| format!("Cannot find config `{base}` extended by {}", path.display()))?;
+            merged.apply(Self::load_chain(&base_path, depth - 1)?);
+        }
+
+        let options = config.get("compilerOptions");
+        if let Some(base_url) = options.and_then(|o| o.get("baseUrl")).and_then(Value::as_str) {
+            merged.base_url = Some(normalize(&dir.join(base_url)));
+        }
+        if let Some(paths) = options.and_then(|o| o.get("paths")).and_then(Value::as_object) {
+            merged.paths = paths
+                .iter()
+                .map(|(pattern, targets)| {
+                    let targets = targets.as_array().into_iter().flatten().filter_map(Value::as_str);
+                    (pattern.clone(), targets.map(str::to_string).collect())
+                })
+                .collect();
+            merged.paths_dir = dir.to_path_buf();
+        }
+        Ok(merged)
+    }
+
+    /// Take the options `other` sets
+    fn apply(&mut self, other: TsConfig) {
+        if other.base_url.is_some() {
+            self.base_url = other.base_url;
+        }
+        if !other.paths.is_empty() {
+            self.paths = other.paths;
+            self.paths_dir = other.paths_dir;
+        }
+    }
+
+    /// Paths a bare specifier may name, in the order to try them
+    ///
+    /// The `paths` entry matching the specifier most closely comes first: an
+    /// exact pattern, else the wildcard pattern with the longest prefix, with
+    /// `*` in each target replaced by what it matched. Then the specifier
+    /// under `baseUrl`. Each path still needs extension and `index` probing.
+    #[must_use]
+    pub fn candidates(&self, specifier: &str) -> Vec<PathBuf> {
+        let base = self.base_url.as_deref().unwrap_or(&self.paths_dir);
+
+        let best = self
+            .paths
+            .iter()
+            .filter_map(|(pattern, targets)| match pattern.split_once('*') {
+                None => (pattern == specifier).then_some((usize::MAX, "", targets)),
+                Some((prefix, suffix)) => {
+                    let matched = specifier.strip_prefix(prefix)?.strip_suffix(suffix)?;
+                    Some((prefix.len(), matched, targets))
+                }
+            })
+            .max_by_key(|(rank, _, _)| *rank);
+
+        let mut candidates: Vec<PathBuf> = best
+            .into_iter()
+            .flat_map(|(_, matched, targets)| targets.iter().map(move |target| normalize(&base.join(target.replace('*', matched)))))
+            .collect();
+        if let Some(base_url) = &self.base_url {
+            candidates.push(normalize(&base_url.join(specifier)));
+        }
+        candidates
+    }
+}
+
+/// The file an `extends` value names: a path relative to the extending
+/// config, or a config shipped in a package under `node_modules`
+fn extended_config(dir: &Path, base: &str) -> Option<PathBuf> {
+    let with_json = |path: PathBuf| {
+        if path.is_file() {
+            Some(path)
+        } else {
+            let file_name = path.file_name()?.to_str()?;
+            Some(path.with_file_name(format!("{file_name}.json"))).filter(|p| p.is_file())
+        }
+    };
+
+    if base.starts_with('.') || base.starts_with('/') {
+        return with_json(normalize(&dir.join(base)));
+    }
+    dir.ancestors()
+        .map(|ancestor| ancestor.join("node_modules").join(base))
+        .find_map(|package| with_json(package.clone()).or_else(|| with_json(package.join("tsconfig.json"))))
+}
+
+/// Turn JSON with comments and trailing commas, as `tsc` accepts, into JSON
+fn strip_jsonc(source: &str) -> String {
+    let mut without_comments = String::with_capacity(source.len());
+    let mut chars = source.chars().peekable();
+    let mut in_string = false;
+    while let Some(c) = chars.next() {
+        if in_string {
+            without_comments.push(c);
+            match c {
+                '\\' => without_comments.extend(chars.next()),
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match (c, chars.peek()) {
+            ('"', _) => {
+                in_string = true;
+                without_comments.push(c);
+            }
+            ('/', Some('/')) => {
+                while chars.next_if(|&next| next != '\n').is_some() {}
+            }
+            ('/', Some('*')) => {
+                chars.next();
+                let mut previous = ' ';
+                for next in chars.by_ref() {
+                    if previous == '*' && next == '/' {
+                        break;
+                    }
+                    previous = next;
+                }
+            }
+            _ => without_comments.push(c),
+        }
+    }
+
+    // Drop commas followed only by whitespace before a closing bracket
+    let mut json = String::with_capacity(without_comments.len());
+    let mut in_string = false;
+    let mut escaped = false;
+    for (index, c) in without_comments.char_indices() {
+        if in_string {
+            in_string = escaped || c != '"';
+            escaped = !escaped && c == '\\';
+        } else if c == '"' {
+            in_string = true;
+        } else if c == ',' {
+            let next = without_comments[index + 1..].trim_start().chars().next();
+            if matches!(next, Some('}' | ']')) {
+                continue;
+            }
+        }
+        json.push(c);
+    }
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_jsonc() {
+        let source = "{\n  // line comment\n  \"a\": \"http://x\", /* block */\n  \"b\": [1, 2,],\n  \"c\": \"q\\\"/*\",\n}";
+        let value: Value = serde_json::from_str(&strip_jsonc(source)).unwrap();
+        assert_eq!(value["a"], "http://x");
+        assert_eq!(value["b"], serde_json::json!([1, 2]));
+        assert_eq!(value["c"], "q\"/*");
+    }
+
+    #[test]
+    fn test_candidates_prefer_exact_then_longest_prefix() {
+        let config = TsConfig {
+            base_url: Some(PathBuf::from("/app/src")),
+            paths: vec![
+                ("@app/*".to_string(), vec!["app/*".to_string()]),
+                ("@app/services/*".to_string(), vec!["services/*".to_string(), "legacy/*".to_string()]),
+                ("config".to_string(), vec!["../config/index.ts".to_string()]),
+            ],
+            paths_dir: PathBuf::from("/app"),
+        };
+        assert_eq!(
+            config.candidates("@app/services/api"),
+            [
+                PathBuf::from("/app/src/services/api"),
+                PathBuf::from("/app/src/legacy/api"),
+                PathBuf::from("/app/src/@app/services/api"),
+            ]
+        );
+        assert_eq!(config.candidates("config")[0], PathBuf::from("/app/config/index.ts"));
+        assert_eq!(config.candidates("lodash"), [PathBuf::from("/app/src/lodash")]);
+    }
+
+    #[test]
+    fn test_paths_without_base_url_are_relative_to_their_config() {
+        let config = TsConfig {
+            base_url: None,
+            paths: vec![("~/*".to_string(), vec!["./src/*".to_string()])],
+            paths_dir: PathBuf::from("/app"),
+        };
+        assert_eq!(config.candidates("~/util"), [PathBuf::from("/app/src/util")]);
+        assert!(config.candidates("lodash").is_empty());
+    }
+}
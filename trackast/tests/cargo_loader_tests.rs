@@ -0,0 +1,86 @@
+mod common;
+
+use common::{build, fixture_root, internal_callees};
+use trackast::cargo::{discover_targets, TargetKind};
+use trackast::language::Language;
+use trackast::module_loader::ModuleLoader;
+use trackast_lib::graph::CallGraph;
+
+fn workspace_graph() -> CallGraph {
+    let root = fixture_root("cargo");
+    let mut loader = ModuleLoader::new(&root, Language::Rust);
+    build(Language::Rust, loader.load_cargo(&root).expect("Failed to load workspace"))
+}
+
+#[test]
+fn test_discover_workspace_targets() {
+    let targets = discover_targets(&fixture_root("cargo").join("Cargo.toml")).unwrap();
+    let names: Vec<(&str, TargetKind)> = targets.iter().map(|t| (t.name.as_str(), t.kind)).collect();
+
+    assert_eq!(
+        names,
+        vec![
+            ("core_util", TargetKind::Lib),
+            ("tool", TargetKind::Bin),
+            ("app", TargetKind::Bin),
+            ("extra", TargetKind::Bin),
+        ]
+    );
+    assert!(targets[1].root.ends_with("app/src/tool.rs"));
+    assert!(targets[3].root.ends_with("app/src/bin/extra.rs"));
+}
+
+#[test]
+fn test_modules_are_named_by_crate() {
+    let graph = workspace_graph();
+    let mut modules: Vec<&str> = graph
+        .nodes
+        .values()
+        .filter(|n| !n.is_external)
+        .map(|n| n.metadata.module.as_str())
+        .collect();
+    modules.sort_unstable();
+    modules.dedup();
+
+    assert_eq!(
+        modules,
+        ["app", "core_util", "core_util::platform", "core_util::text", "extra", "tool"]
+    );
+}
+
+#[test]
+fn test_path_attribute_selects_module_file() {
+    let graph = workspace_graph();
+    let setup = graph
+        .nodes
+        .values()
+        .find(|n| n.metadata.module == "core_util::platform")
+        .unwrap();
+    assert!(setup.metadata.file.as_deref().unwrap().ends_with("platform_unix.rs"));
+    assert_eq!(internal_callees(&graph, "core_util", "init"), ["core_util::platform::setup"]);
+}
+
+#[test]
+fn test_calls_cross_crate_boundaries() {
+    let graph = workspace_graph();
+
+    assert_eq!(
+        internal_callees(&graph, "app", "main"),
+        ["core_util::init", "core_util::text::shout"]
+    );
+    assert_eq!(internal_callees(&graph, "tool", "main"), ["core_util::text::shout"]);
+}
+
+#[test]
+fn test_targets_sharing_a_module_file_each_load_it() {
+    // `src/lib.rs` and `src/main.rs` both declare `mod util;`
+    let root = fixture_root("cargo_package");
+    let mut loader = ModuleLoader::new(&root, Language::Rust);
+    let graph = build(Language::Rust, loader.load_cargo(&root).expect("Failed to load package"));
+
+    assert_eq!(internal_callees(&graph, "widget", "build"), ["widget::util::helper"]);
+    assert_eq!(
+        internal_callees(&graph, "widget_bin", "main"),
+        ["widget::build", "widget_bin::util::helper"]
+    );
+}
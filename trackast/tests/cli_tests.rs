@@ -0,0 +1,39 @@
+mod common;
+
+use common::fixture_root;
+use serde_json::Value;
+use std::process::Command;
+
+/// Run the `trackast` binary on the nested Rust modules fixture and parse
+/// its JSON output
+fn trackast(args: &[&str]) -> Value {
+    let entry = fixture_root("modules/rust").join("main.rs");
+    let output = Command::new(env!("CARGO_BIN_EXE_trackast"))
+        .args(args)
+        .arg("--input")
+        .arg(entry)
+        .args(["--format", "json"])
+        .output()
+        .expect("Failed to run trackast");
+    assert!(output.status.success(), "trackast {args:?} failed: {}", String::from_utf8_lossy(&output.stderr));
+    serde_json::from_slice(&output.stdout).expect("Output is not JSON")
+}
+
+#[test]
+fn test_unreachable_from_entry_point_in_nested_module() {
+    let report = trackast(&["unreachable", "--entry-points", "main::store::save"]);
+    let unreachable: Vec<&str> = report["unreachable"].as_array().unwrap().iter().map(|id| id.as_str().unwrap()).collect();
+    assert_eq!(report["entry_points"], serde_json::json!(["main::store::save::() -> ()"]));
+    assert!(unreachable.contains(&"main::main::() -> ()"));
+    assert!(!unreachable.contains(&"main::store::cache::put::() -> ()"));
+    assert!(!unreachable.contains(&"main::util::log::(message: &str) -> ()"));
+}
+
+#[test]
+fn test_impact_entry_point_in_nested_module() {
+    let report = trackast(&["impact", "main::util::log", "--entry-points", "main::store::save"]);
+    let entry_points = report["entry_points"].as_array().unwrap();
+    assert_eq!(entry_points.len(), 1);
+    assert_eq!(entry_points[0]["start"], "main::store::save::() -> ()");
+    assert_eq!(entry_points[0]["hops"][0]["to"], "main::store::cache::put::() -> ()");
+}
@@ -0,0 +1,81 @@
+//! Fixture loading shared by the integration tests
+//!
+//! Each test crate includes this with `mod common;` and uses only some of
+//! the helpers, hence the `dead_code` allowance.
+#![allow(dead_code)]
+
+use trackast::language::Language;
+use trackast::module_loader::ModuleLoader;
+use trackast::resolver::get_resolver;
+use trackast_lib::ast::AbstractAST;
+use trackast_lib::builder::CallGraphBuilder;
+use trackast_lib::graph::CallGraph;
+use std::path::PathBuf;
+
+/// `tests/fixtures/<dir>` at the repository root
+pub fn fixture_root(dir: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .join("tests/fixtures")
+        .join(dir)
+}
+
+/// Resolve `ast` with the resolver for `language` and build its graph
+pub fn build(language: Language, mut ast: AbstractAST) -> CallGraph {
+    get_resolver(language).resolve(&mut ast);
+
+    let mut builder = CallGraphBuilder::new();
+    builder.add_ast(ast).expect("Failed to add AST");
+    builder.build().expect("Failed to build graph")
+}
+
+/// Load the fixture `dir` from `entry`, resolve and build the graph the way
+/// the CLI does
+pub fn graph_for(language: Language, dir: &str, entry: &str) -> CallGraph {
+    let root = fixture_root(dir);
+    let mut loader = ModuleLoader::new(&root, language);
+    let ast = loader
+        .load_all(root.join(entry).to_str().unwrap())
+        .expect("Failed to load modules");
+    build(language, ast)
+}
+
+/// Internal callees of `module::name`, as `module::name`
+pub fn internal_callees(graph: &CallGraph, module: &str, name: &str) -> Vec<String> {
+    let caller = graph
+        .nodes
+        .values()
+        .find(|n| n.metadata.module == module && n.metadata.name == name)
+        .unwrap_or_else(|| panic!("{module}::{name} not found"));
+
+    let mut callees: Vec<String> = graph
+        .get_edges_from(&caller.id)
+        .iter()
+        .filter_map(|edge| graph.get_node(&edge.to))
+        .filter(|node| !node.is_external)
+        .map(|node| format!("{}::{}", node.metadata.module, node.metadata.name))
+        .collect();
+    callees.sort();
+    callees
+}
+
+/// Internal callees of the function with ID `caller`, as IDs
+///
+/// For languages with overloads, where `module::name` is ambiguous.
+pub fn internal_callee_ids(graph: &CallGraph, caller: &str) -> Vec<String> {
+    let caller = graph
+        .node_ids()
+        .find(|id| id.as_str() == caller)
+        .unwrap_or_else(|| panic!("{caller} not found"));
+    let mut callees: Vec<String> = graph
+        .get_edges_from(caller)
+        .iter()
+        .filter_map(|edge| graph.get_node(&edge.to))
+        .filter(|node| !node.is_external)
+        .map(|node| node.id.to_string())
+        .collect();
+    callees.sort();
+    callees.dedup();
+    callees
+}
@@ -0,0 +1,113 @@
+mod common;
+
+use common::{build, graph_for, internal_callee_ids};
+use trackast::language::Language;
+use trackast::translators::CppTranslator;
+use trackast_lib::cycles::find_cycles;
+use trackast_lib::graph::CallGraph;
+
+fn cpp_graph() -> CallGraph {
+    graph_for(Language::Cpp, "cpp", "src/main.cpp")
+}
+
+#[test]
+fn test_overloads_have_distinct_ids() {
+    let graph = cpp_graph();
+    let mut scales: Vec<String> = graph
+        .nodes
+        .values()
+        .filter(|n| n.metadata.name == "scale")
+        .map(|n| n.id.to_string())
+        .collect();
+    scales.sort();
+    assert_eq!(
+        scales,
+        [
+            "::scale::(value: double, factor: double) -> double",
+            "::scale::(value: double, factor: int) -> double",
+        ]
+    );
+}
+
+#[test]
+fn test_header_declarations_pair_with_definitions() {
+    let graph = cpp_graph();
+    let node = |id: &str| {
+        graph
+            .nodes
+            .values()
+            .find(|n| n.id.as_str() == id)
+            .unwrap_or_else(|| panic!("{id} not found"))
+    };
+
+    // Declared in include/geo/shapes.h, defined in src/geo/shapes.cpp
+    let area = node("geo::Circle::area::(this: const Circle&) -> double");
+    assert!(area.metadata.file.as_deref().is_some_and(|f| f.ends_with("src/geo/shapes.cpp")));
+    assert!(area.metadata.span.is_some());
+
+    // Declared without parameter names in src/util.h
+    let log = node("::log_value::(value: int) -> void");
+    assert!(log.metadata.file.as_deref().is_some_and(|f| f.ends_with("src/util.cpp")));
+
+    // Pure virtual: declared only
+    assert!(node("geo::Shape::area::(this: const Shape&) -> double").metadata.span.is_none());
+}
+
+#[test]
+fn test_calls_resolve_through_scopes() {
+    let graph = cpp_graph();
+    // Overloads are narrowed by argument count, then by the argument types
+    // casts and literals show; what is left ambiguous links to every candidate
+    assert_eq!(
+        internal_callee_ids(&graph, "::main::(argc: int, argv: char**) -> int"),
+        [
+            "::log_value::(value: double) -> void",
+            "::log_value::(value: int) -> void",
+            "::scale::(value: double, factor: int) -> double",
+            "geo::Circle::Circle::(radius: double) -> ()",
+            "geo::Circle::area::(this: const Circle&) -> double",
+            "geo::Square::Square::(side: double) -> ()",
+            "src::main::report::(shape: const Shape&) -> void",
+        ]
+    );
+    assert_eq!(
+        internal_callee_ids(&graph, "geo::Circle::area::(this: const Circle&) -> double"),
+        ["geo::pi::() -> double", "src::geo::shapes::geo::square::(x: double) -> double"]
+    );
+    // `area()` in a `Shape` method dispatches through the base class
+    assert_eq!(
+        internal_callee_ids(&graph, "geo::Shape::describe::(this: const Shape&) -> double"),
+        ["geo::Shape::area::(this: const Shape&) -> double"]
+    );
+    // `static` functions are bound within their file
+    assert_eq!(
+        internal_callee_ids(&graph, "::scale::(value: double, factor: int) -> double"),
+        ["src::util::clamp::(value: double) -> double"]
+    );
+}
+
+#[test]
+fn test_overload_forwarding_to_another_is_not_recursion() {
+    let graph = cpp_graph();
+    // `log_value(int)` calls `log_value(static_cast<double>(value))`
+    assert_eq!(
+        internal_callee_ids(&graph, "::log_value::(value: int) -> void"),
+        ["::log_value::(value: double) -> void"]
+    );
+    assert!(find_cycles(&graph).is_empty());
+}
+
+#[test]
+fn test_recursion_through_untyped_overload_call_is_kept() {
+    let source = "void log(int x) {\n    if (x > 0) log(x - 1);\n}\nvoid log(double x) {}\n";
+    let ast = CppTranslator::new().translate(source, "").expect("Failed to translate");
+    let graph = build(Language::Cpp, ast);
+    // `x - 1` has no known type, so both overloads stay candidates
+    assert_eq!(
+        internal_callee_ids(&graph, "::log::(x: int) -> void"),
+        ["::log::(x: double) -> void", "::log::(x: int) -> void"]
+    );
+    let cycles = find_cycles(&graph);
+    assert_eq!(cycles.len(), 1);
+    assert_eq!(cycles[0].nodes[0].as_str(), "::log::(x: int) -> void");
+}
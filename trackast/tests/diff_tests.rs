@@ -0,0 +1,90 @@
+use trackast::git::Worktree;
+use trackast::module_loader::ModuleLoader;
+use trackast::resolver::{MixedResolver, Resolver};
+use trackast_lib::builder::CallGraphBuilder;
+use trackast_lib::diff::diff;
+use trackast_lib::function_id::FunctionId;
+use trackast_lib::graph::CallGraph;
+use std::path::Path;
+use std::process::Command;
+
+fn git(dir: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(["-c", "user.name=trackast", "-c", "user.email=trackast@example.com"])
+        .args(args)
+        .status()
+        .expect("Failed to run git");
+    assert!(status.success(), "git {args:?} failed");
+}
+
+/// Paths of the checkout's worktrees, the main one included
+fn worktrees(dir: &Path) -> Vec<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(["worktree", "list", "--porcelain"])
+        .output()
+        .expect("Failed to run git");
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| line.strip_prefix("worktree "))
+        .map(str::to_string)
+        .collect()
+}
+
+fn graph_of(root: &Path) -> CallGraph {
+    let mut loader = ModuleLoader::new_mixed(root);
+    let mut ast = loader.load_file(&root.join("app.py")).expect("Failed to load");
+    MixedResolver::new(std::slice::from_ref(&ast)).resolve(&mut ast);
+    let mut builder = CallGraphBuilder::new();
+    builder.add_ast(ast).expect("Failed to add AST");
+    builder.build().expect("Failed to build graph")
+}
+
+#[test]
+fn test_diff_between_revisions() {
+    let temp = tempfile::tempdir().unwrap();
+    let dir = temp.path();
+    std::fs::write(dir.join("app.py"), "def main():\n    legacy()\n\n\ndef legacy():\n    pass\n").unwrap();
+    git(dir, &["init", "-q"]);
+    git(dir, &["add", "app.py"]);
+    git(dir, &["commit", "-q", "-m", "Initial"]);
+    std::fs::write(
+        dir.join("app.py"),
+        "import time\n\n\ndef main():\n    poll()\n\n\ndef poll():\n    time.sleep(1)\n    poll()\n",
+    )
+    .unwrap();
+    git(dir, &["commit", "-q", "-am", "Poll"]);
+
+    let worktree = Worktree::add(dir, "HEAD~1").expect("Failed to add worktree");
+    assert_eq!(worktrees(dir).len(), 2);
+    let old = graph_of(worktree.path());
+    let worktree_path = worktree.path().to_path_buf();
+    drop(worktree);
+    assert!(!worktree_path.exists());
+    assert_eq!(worktrees(dir).len(), 1);
+
+    // Worktrees are uniquely named, and a failed one leaves nothing behind
+    let first = Worktree::add(dir, "HEAD").expect("Failed to add worktree");
+    let second = Worktree::add(dir, "HEAD").expect("Failed to add worktree");
+    assert_ne!(first.path(), second.path());
+    drop((first, second));
+    assert!(Worktree::add(dir, "no-such-revision").is_err());
+    assert_eq!(worktrees(dir).len(), 1);
+
+    // A worktree deleted behind git's back is pruned
+    let worktree = Worktree::add(dir, "HEAD").expect("Failed to add worktree");
+    std::fs::remove_dir_all(worktree.path()).unwrap();
+    drop(worktree);
+    assert_eq!(worktrees(dir).len(), 1);
+
+    let changes = diff(&old, &graph_of(dir));
+    assert_eq!(changes.added_functions, [FunctionId::new("app::poll::() -> ()".to_string())]);
+    assert_eq!(changes.removed_functions, [FunctionId::new("app::legacy::() -> ()".to_string())]);
+    assert_eq!(changes.new_cycles.len(), 1);
+    assert_eq!(changes.new_cycles[0].nodes, [FunctionId::new("app::poll::() -> ()".to_string())]);
+    assert_eq!(changes.new_external_dependencies.len(), 1);
+    assert!(changes.new_external_dependencies[0].as_str().contains("sleep"));
+}
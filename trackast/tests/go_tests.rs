@@ -0,0 +1,67 @@
+mod common;
+
+use common::{fixture_root, graph_for, internal_callees};
+use trackast::gomod::GoModule;
+use trackast::language::Language;
+use trackast_lib::graph::CallGraph;
+
+const CART: &str = "example.com::shop::internal::cart";
+
+fn go_graph() -> CallGraph {
+    graph_for(Language::Go, "go", "cmd/shop/main.go")
+}
+
+#[test]
+fn test_go_mod_names_packages() {
+    let module = GoModule::load(&fixture_root("go").join("go.mod")).unwrap();
+    assert_eq!(module.path, "example.com/shop");
+    assert_eq!(module.package_dir("example.com/shop/internal/cart"), Some(fixture_root("go").join("internal/cart")));
+    assert_eq!(module.package_dir("github.com/google/uuid"), None);
+}
+
+#[test]
+fn test_package_files_share_a_module() {
+    let graph = go_graph();
+    let module_of = |name: &str| {
+        graph
+            .nodes
+            .values()
+            .find(|n| n.metadata.name == name)
+            .map(|n| n.metadata.module.clone())
+            .unwrap_or_else(|| panic!("{name} not found"))
+    };
+    assert_eq!(module_of("main"), "example.com::shop::cmd::shop");
+    assert_eq!(module_of("parseFlags"), "example.com::shop::cmd::shop");
+    assert_eq!(module_of("Cart::validate"), CART);
+    assert!(graph.nodes.values().all(|n| n.metadata.name != "TestReport"));
+}
+
+#[test]
+fn test_imports_resolve_through_go_mod() {
+    let graph = go_graph();
+    assert_eq!(
+        internal_callees(&graph, "example.com::shop::cmd::shop", "main"),
+        [
+            "example.com::shop::cmd::shop::report".to_string(),
+            format!("{CART}::Cart::Add"),
+            format!("{CART}::New"),
+            "example.com::shop::pkg::go-money::Cents".to_string(),
+        ]
+    );
+    assert_eq!(internal_callees(&graph, "example.com::shop::cmd::shop", "init"), ["example.com::shop::cmd::shop::parseFlags"]);
+    assert_eq!(internal_callees(&graph, "example.com::shop::cmd::shop", "report"), [format!("{CART}::Cart::Total")]);
+}
+
+#[test]
+fn test_methods_bind_through_receivers() {
+    let graph = go_graph();
+    assert_eq!(internal_callees(&graph, CART, "Cart::Add"), [format!("{CART}::Cart::validate")]);
+    assert_eq!(internal_callees(&graph, CART, "Cart::Total"), ["example.com::shop::internal::pricing::WithTax"]);
+    assert_eq!(
+        internal_callees(&graph, "example.com::shop::internal::pricing", "WithTax"),
+        ["example.com::shop::internal::pricing::percent"]
+    );
+
+    let add = graph.nodes.values().find(|n| n.metadata.name == "Cart::Add").unwrap();
+    assert_eq!(add.metadata.signature.to_string(), "(c: *Cart, item: Item) -> ()");
+}
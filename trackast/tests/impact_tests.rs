@@ -0,0 +1,75 @@
+use trackast::git::{git_diff, git_toplevel};
+use trackast::module_loader::ModuleLoader;
+use trackast::patch::{changed_functions, parse_unified_diff};
+use trackast::resolver::{MixedResolver, Resolver};
+use trackast::scan::DirectoryScanner;
+use trackast_lib::builder::CallGraphBuilder;
+use trackast_lib::function_id::FunctionId;
+use trackast_lib::graph::CallGraph;
+use trackast_lib::impact::impact;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn repo_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).parent().unwrap().to_path_buf()
+}
+
+fn build(mut asts: Vec<trackast_lib::ast::AbstractAST>) -> CallGraph {
+    MixedResolver::new(&asts).resolve_all(&mut asts);
+    let mut builder = CallGraphBuilder::new();
+    for ast in asts {
+        builder.add_ast(ast).expect("Failed to add AST");
+    }
+    builder.build().expect("Failed to build graph")
+}
+
+#[test]
+fn test_patch_hunks_map_to_functions_and_entry_points() {
+    let root = repo_root();
+    let graph = build(DirectoryScanner::new(root.join("tests/fixtures/scan")).scan().expect("Failed to scan"));
+    let patch = std::fs::read_to_string(root.join("tests/fixtures/patches/scan_helpers.patch")).unwrap();
+    let changes = parse_unified_diff(&patch).expect("Failed to parse patch");
+
+    let changed = changed_functions(&graph, &changes, &root);
+    assert_eq!(changed, [FunctionId::new("helpers::shout::() -> ()".to_string())]);
+
+    let report = impact(&graph, &changed, &[]);
+    let entries: Vec<&str> = report.entry_points.iter().map(|path| path.start.as_str()).collect();
+    assert_eq!(entries, ["plugins::greet::<module>::() -> ()", "plugins::greet::run::() -> ()"]);
+    assert_eq!(report.entry_points[1].hops[0].line, 5);
+}
+
+fn git(dir: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(["-c", "user.name=trackast", "-c", "user.email=trackast@example.com"])
+        .args(args)
+        .status()
+        .expect("Failed to run git");
+    assert!(status.success(), "git {args:?} failed");
+}
+
+#[test]
+fn test_git_range_against_local_checkout() {
+    let temp = tempfile::tempdir().unwrap();
+    let dir = temp.path();
+    let source = "def main():\n    step()\n\n\ndef step():\n    return 1\n\n\ndef other():\n    return 2\n";
+    std::fs::write(dir.join("app.py"), source).unwrap();
+    git(dir, &["init", "-q"]);
+    git(dir, &["add", "app.py"]);
+    git(dir, &["commit", "-q", "-m", "Initial"]);
+    std::fs::write(dir.join("app.py"), source.replace("return 1", "return 3")).unwrap();
+
+    let mut loader = ModuleLoader::new_mixed(dir);
+    let graph = build(vec![loader.load_file(&dir.join("app.py")).expect("Failed to load")]);
+    let changes = parse_unified_diff(&git_diff(dir, "HEAD").expect("git diff failed")).unwrap();
+    let base = git_toplevel(dir).expect("Not a checkout");
+    let changed = changed_functions(&graph, &changes, &base);
+    assert_eq!(changed, [FunctionId::new("app::step::() -> ()".to_string())]);
+
+    let report = impact(&graph, &changed, &[]);
+    assert!(report.callers.contains(&FunctionId::new("app::main::() -> ()".to_string())));
+    assert!(!report.callers.contains(&FunctionId::new("app::other::() -> ()".to_string())));
+    assert!(git_diff(dir, "--output=/tmp/x").is_err());
+}
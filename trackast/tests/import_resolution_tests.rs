@@ -0,0 +1,63 @@
+mod common;
+
+use common::graph_for;
+use trackast::language::Language;
+use trackast_lib::graph::CallGraph;
+
+/// Names of the functions `main::main` calls, with their module relative to
+/// the fixture root
+fn callees_of_main(graph: &CallGraph) -> Vec<String> {
+    let main = graph
+        .nodes
+        .values()
+        .find(|n| n.metadata.module == "main" && n.metadata.name == "main")
+        .expect("main::main not found");
+
+    let mut callees: Vec<String> = graph
+        .get_edges_from(&main.id)
+        .iter()
+        .map(|edge| {
+            let target = graph.get_node(&edge.to).unwrap();
+            assert!(!target.is_external, "{} should resolve to an internal node", edge.to);
+            // Rust modules are nested under the crate root `main`
+            let module = target.metadata.module.strip_prefix("main::").unwrap_or(&target.metadata.module);
+            format!("{module}::{}", target.metadata.name)
+        })
+        .collect();
+    callees.sort();
+    callees
+}
+
+const EXPECTED: [&str; 3] = ["helpers::normalize", "helpers::parse", "helpers::report"];
+
+#[test]
+fn test_rust_use_aliases_and_globs_resolve() {
+    let graph = graph_for(Language::Rust, "imports/rust", "main.rs");
+    assert_eq!(callees_of_main(&graph), EXPECTED);
+}
+
+#[test]
+fn test_python_import_aliases_and_globs_resolve() {
+    let graph = graph_for(Language::Python, "imports/python", "main.py");
+    assert_eq!(callees_of_main(&graph), EXPECTED);
+}
+
+#[test]
+fn test_javascript_import_and_require_bindings_resolve() {
+    let graph = graph_for(Language::JavaScript, "imports/javascript", "main.js");
+    assert_eq!(callees_of_main(&graph), EXPECTED);
+}
+
+#[test]
+fn test_unknown_calls_stay_external() {
+    let graph = graph_for(Language::Python, "imports/python", "main.py");
+    let externals: Vec<&str> = graph
+        .nodes
+        .values()
+        .filter(|n| n.is_external)
+        .map(|n| n.metadata.name.as_str())
+        .collect();
+    assert!(externals.contains(&"print"));
+    assert!(!externals.contains(&"normalize"));
+    assert!(!externals.contains(&"clean"));
+}
@@ -0,0 +1,104 @@
+mod common;
+
+use common::{graph_for, internal_callee_ids};
+use trackast::language::Language;
+use trackast_lib::graph::CallGraph;
+
+fn java_graph() -> CallGraph {
+    graph_for(Language::Java, "java", "src/main/java/com/acme/shop/App.java")
+}
+
+#[test]
+fn test_classes_load_through_imports_and_packages() {
+    let graph = java_graph();
+    let mut modules: Vec<&str> = graph
+        .nodes
+        .values()
+        .filter(|n| !n.is_external)
+        .map(|n| n.metadata.module.as_str())
+        .collect();
+    modules.sort_unstable();
+    modules.dedup();
+    assert_eq!(
+        modules,
+        [
+            "com::acme::shop::App",
+            // Same package, never imported
+            "com::acme::shop::Formatter",
+            "com::acme::shop::cart::Cart",
+            "com::acme::shop::cart::Cart::Line",
+            // Every class of a wildcard-imported package
+            "com::acme::shop::pricing::BaseDiscount",
+            "com::acme::shop::pricing::Discount",
+            "com::acme::shop::pricing::PriceList",
+            "com::acme::shop::pricing::SeasonalDiscount",
+            // The class of a static import
+            "com::acme::shop::util::Strings",
+        ]
+    );
+}
+
+#[test]
+fn test_overloads_and_constructors_have_distinct_ids() {
+    let graph = java_graph();
+    let mut ids: Vec<String> = graph
+        .nodes
+        .values()
+        .filter(|n| n.metadata.module == "com::acme::shop::cart::Cart" || n.metadata.name == "<init>")
+        .filter(|n| !n.is_external)
+        .map(|n| n.id.to_string())
+        .collect();
+    ids.sort();
+    assert_eq!(
+        ids,
+        [
+            "com::acme::shop::cart::Cart::Line::<init>::(sku: String, quantity: int) -> void",
+            "com::acme::shop::cart::Cart::add::(sku: String) -> void",
+            "com::acme::shop::cart::Cart::add::(sku: String, quantity: int) -> void",
+            "com::acme::shop::cart::Cart::lines::() -> List<Line>",
+            "com::acme::shop::pricing::BaseDiscount::<init>::(percent: int) -> void",
+            "com::acme::shop::pricing::SeasonalDiscount::<init>::(percent: int) -> void",
+            "com::acme::shop::util::Strings::<init>::() -> void",
+        ]
+    );
+}
+
+#[test]
+fn test_calls_resolve_through_imports_and_supertypes() {
+    let graph = java_graph();
+    // Each `cart.add(..)` binds to the overload taking as many arguments;
+    // `new Cart()` calls an implicit constructor and stays external
+    assert_eq!(
+        internal_callee_ids(&graph, "com::acme::shop::App::main::(args: String[]) -> void"),
+        [
+            "com::acme::shop::App::report::(total: double) -> String",
+            "com::acme::shop::cart::Cart::add::(sku: String) -> void",
+            "com::acme::shop::cart::Cart::add::(sku: String, quantity: int) -> void",
+            "com::acme::shop::pricing::Discount::apply::(total: double) -> double",
+            "com::acme::shop::pricing::PriceList::total::(cart: Cart) -> double",
+            "com::acme::shop::pricing::SeasonalDiscount::<init>::(percent: int) -> void",
+            "com::acme::shop::util::Strings::capitalize::(text: String) -> String",
+        ]
+    );
+    assert_eq!(
+        internal_callee_ids(&graph, "com::acme::shop::cart::Cart::add::(sku: String) -> void"),
+        ["com::acme::shop::cart::Cart::add::(sku: String, quantity: int) -> void"]
+    );
+    // `Cart.Line` names a nested class through an import
+    assert_eq!(
+        internal_callee_ids(&graph, "com::acme::shop::pricing::PriceList::total::(cart: Cart) -> double"),
+        [
+            "com::acme::shop::cart::Cart::Line::quantity::() -> int",
+            "com::acme::shop::cart::Cart::lines::() -> List<Line>",
+        ]
+    );
+    // `super(...)` and inherited methods go through the superclass
+    assert_eq!(
+        internal_callee_ids(&graph, "com::acme::shop::pricing::SeasonalDiscount::<init>::(percent: int) -> void"),
+        ["com::acme::shop::pricing::BaseDiscount::<init>::(percent: int) -> void"]
+    );
+    assert_eq!(
+        internal_callee_ids(&graph, "com::acme::shop::pricing::SeasonalDiscount::apply::(total: double) -> double"),
+        ["com::acme::shop::pricing::BaseDiscount::reduce::(total: double) -> double"]
+    );
+}
@@ -0,0 +1,41 @@
+mod common;
+
+use common::{graph_for, internal_callees};
+use trackast::language::Language;
+use trackast_lib::graph::CallGraph;
+
+fn lua_graph() -> CallGraph {
+    graph_for(Language::Lua, "lua", "main.lua")
+}
+
+#[test]
+fn test_requires_load_modules_and_init_files() {
+    let graph = lua_graph();
+    let mut modules: Vec<&str> = graph
+        .nodes
+        .values()
+        .filter(|n| !n.is_external)
+        .map(|n| n.metadata.module.as_str())
+        .collect();
+    modules.sort_unstable();
+    modules.dedup();
+    assert_eq!(modules, ["bank", "bank::account", "config", "main", "util::globals"]);
+}
+
+#[test]
+fn test_calls_through_required_modules_and_globals() {
+    let graph = lua_graph();
+    assert_eq!(
+        internal_callees(&graph, "main", "run"),
+        ["bank::account::new", "bank::open", "config::load", "util::globals::log_line"]
+    );
+    assert_eq!(internal_callees(&graph, "main", "<module>"), ["main::run"]);
+    assert_eq!(internal_callees(&graph, "bank", "open"), ["bank::audit"]);
+}
+
+#[test]
+fn test_module_table_and_self_calls() {
+    let graph = lua_graph();
+    assert_eq!(internal_callees(&graph, "config", "load"), ["config::parse"]);
+    assert_eq!(internal_callees(&graph, "bank::account", "deposit"), ["bank::account::validate"]);
+}
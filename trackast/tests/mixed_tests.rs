@@ -0,0 +1,62 @@
+mod common;
+
+use common::fixture_root;
+use trackast::module_loader::ModuleLoader;
+use trackast::resolver::{MixedResolver, Resolver};
+use trackast_lib::builder::CallGraphBuilder;
+use trackast_lib::export::to_dot;
+use trackast_lib::function_id::FunctionId;
+use trackast_lib::graph::CallGraph;
+
+fn mixed_graph() -> CallGraph {
+    let root = fixture_root("mixed");
+    let entries: Vec<String> = ["app.py", "fastmath.rs", "web/client.js"]
+        .iter()
+        .map(|entry| root.join(entry).to_str().unwrap().to_string())
+        .collect();
+    let entries: Vec<&str> = entries.iter().map(String::as_str).collect();
+
+    let mut loader = ModuleLoader::new_mixed(&root);
+    let mut ast = loader.load_entries(&entries).expect("Failed to load modules");
+    MixedResolver::new(std::slice::from_ref(&ast)).resolve(&mut ast);
+
+    let mut builder = CallGraphBuilder::new();
+    builder.add_ast(ast).expect("Failed to add AST");
+    builder.build().expect("Failed to build graph")
+}
+
+#[test]
+fn test_functions_are_tagged_with_their_language() {
+    let graph = mixed_graph();
+    let mut languages: Vec<(String, &str)> = graph
+        .nodes
+        .values()
+        .filter(|n| !n.is_external && n.metadata.name != "<module>")
+        .map(|n| (n.id.to_string(), n.metadata.language.as_deref().unwrap_or("?")))
+        .collect();
+    languages.sort();
+    assert_eq!(
+        languages,
+        [
+            ("app::main::() -> ()".to_string(), "Python"),
+            ("app::total::() -> ()".to_string(), "Python"),
+            ("fastmath::add::(a: i64, b: i64) -> i64".to_string(), "Rust"),
+            ("fastmath::saturate::(value: Option<i64>) -> i64".to_string(), "Rust"),
+            ("web::client::fetchTotal::() -> ()".to_string(), "JavaScript"),
+            // Loaded through the JavaScript import, not as an entry point
+            ("web::format::formatTotal::() -> ()".to_string(), "JavaScript"),
+        ]
+    );
+}
+
+#[test]
+fn test_calls_bind_across_languages_by_module_path() {
+    let graph = mixed_graph();
+    let total = FunctionId::new("app::total::() -> ()".to_string());
+    let callees: Vec<&str> = graph.get_edges_from(&total).iter().map(|edge| edge.to.as_str()).collect();
+    assert_eq!(callees, ["fastmath::add::(a: i64, b: i64) -> i64"]);
+
+    let dot = to_dot(&graph);
+    assert!(dot.contains("fastmath\nadd\n(a: i64, b: i64) -> i64\n[Rust]"));
+    assert!(graph.to_json().contains("\"language\": \"Python\""));
+}
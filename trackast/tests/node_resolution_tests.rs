@@ -0,0 +1,70 @@
+mod common;
+
+use common::graph_for;
+use trackast::language::Language;
+use trackast_lib::graph::CallGraph;
+
+fn node_graph(entry: &str) -> CallGraph {
+    graph_for(Language::JavaScript, "node_packages", entry)
+}
+
+/// Callees of `module::name`, as `module::name`, with externals as `<external>::name`
+fn callees(graph: &CallGraph, module: &str, name: &str) -> Vec<String> {
+    let caller = graph
+        .nodes
+        .values()
+        .find(|n| n.metadata.module == module && n.metadata.name == name)
+        .unwrap_or_else(|| panic!("{module}::{name} not found"));
+
+    let mut callees: Vec<String> = graph
+        .get_edges_from(&caller.id)
+        .iter()
+        .filter_map(|edge| graph.get_node(&edge.to))
+        .map(|node| {
+            if node.is_external {
+                format!("<external>::{}", node.metadata.name)
+            } else {
+                format!("{}::{}", node.metadata.module, node.metadata.name)
+            }
+        })
+        .collect();
+    callees.sort();
+    callees
+}
+
+fn has_module(graph: &CallGraph, module: &str) -> bool {
+    graph.nodes.values().any(|n| !n.is_external && n.metadata.module == module)
+}
+
+#[test]
+fn test_relative_paths_probe_extensions_and_index_files() {
+    let graph = node_graph("src/main.js");
+
+    // `./lib` is src/lib/index.js, which requires `../util/pad.js`
+    assert!(callees(&graph, "src::main", "main").contains(&"src::lib::formatDate".to_string()));
+    assert!(callees(&graph, "src::lib", "formatDate").contains(&"src::util::pad::pad".to_string()));
+}
+
+#[test]
+fn test_workspace_packages_resolve_through_main_and_exports() {
+    let graph = node_graph("src/main.js");
+
+    let main = callees(&graph, "src::main", "main");
+    assert!(main.contains(&"packages::strings::src::capitalize".to_string()));
+    assert!(main.contains(&"packages::strings::src::case::snakeCase".to_string()));
+    assert!(main.contains(&"packages::math::lib::math::add".to_string()));
+}
+
+#[test]
+fn test_node_modules_stay_external() {
+    let graph = node_graph("src/main.js");
+
+    assert!(callees(&graph, "src::main", "main").contains(&"<external>::chunk".to_string()));
+    assert!(!graph.nodes.values().any(|n| n.metadata.module.starts_with("node_modules")));
+}
+
+#[test]
+fn test_side_effect_imports_and_inline_requires_load() {
+    assert!(has_module(&node_graph("src/main.js"), "src::polyfill"));
+    assert!(has_module(&node_graph("src/server.js"), "src::routes"));
+}
@@ -90,6 +90,35 @@ fn test_build_rust_callgraph() {
     assert!(graph.edge_count() > 0);
 }
 
+#[test]
+fn test_graph_records_source_locations() {
+    for (language, fixture) in [
+        (Language::Rust, "rust/simple.rs"),
+        (Language::Python, "python/simple.py"),
+        (Language::JavaScript, "javascript/simple.js"),
+    ] {
+        let path = fixture_path(fixture);
+        let path = path.to_str().unwrap();
+        let ast = get_translator(language)
+            .translate_file(path, None)
+            .expect("Failed to translate");
+
+        let mut builder = CallGraphBuilder::new();
+        builder.add_ast(ast).expect("Failed to add AST");
+        let graph = builder.build().expect("Failed to build graph");
+
+        assert!(graph.edge_count() > 0);
+        for edge in &graph.edges {
+            assert!(edge.line > 0 && edge.column > 0, "{fixture}: {edge:?} has no position");
+            assert_eq!(edge.file.as_deref(), Some(path));
+        }
+        for node in graph.nodes.values().filter(|n| !n.is_external && n.metadata.name != "<module>") {
+            assert_eq!(node.metadata.file.as_deref(), Some(path));
+            assert!(node.metadata.span.is_some(), "{fixture}: {} has no span", node.id);
+        }
+    }
+}
+
 #[test]
 fn test_language_detection_python() {
     let language = Language::from_file_path("script.py").expect("Failed to detect Python");
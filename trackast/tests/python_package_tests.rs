@@ -0,0 +1,57 @@
+mod common;
+
+use common::{graph_for, internal_callees};
+use trackast::language::Language;
+use trackast_lib::graph::CallGraph;
+
+fn package_graph() -> CallGraph {
+    graph_for(Language::Python, "python_packages", "app.py")
+}
+
+#[test]
+fn test_dotted_imports_and_init_reexports() {
+    let graph = package_graph();
+
+    // `from services.api import create_app` goes through api/__init__.py;
+    // `import services.helpers.text` binds `services`
+    assert_eq!(
+        internal_callees(&graph, "app", "main"),
+        ["services::api::routes::create_app", "services::helpers::text::clean"]
+    );
+}
+
+#[test]
+fn test_relative_imports_anchor_at_package() {
+    let graph = package_graph();
+
+    assert_eq!(
+        internal_callees(&graph, "services::api::routes", "create_app"),
+        ["services::api::routes::register", "services::api::validators::check_config"]
+    );
+    // `from ..helpers import slugify` and `from .. import helpers`, both
+    // re-exported by helpers/__init__.py's `from .text import *`
+    assert_eq!(
+        internal_callees(&graph, "services::api::routes", "register"),
+        ["plugins::audit::record", "services::helpers::text::clean", "services::helpers::text::slugify"]
+    );
+}
+
+#[test]
+fn test_namespace_package_module_loads() {
+    let graph = package_graph();
+    let record = graph
+        .nodes
+        .values()
+        .find(|n| n.metadata.module == "plugins::audit" && n.metadata.name == "record")
+        .expect("plugins/audit.py should load without an __init__.py");
+    assert!(!record.is_external);
+}
+
+#[test]
+fn test_third_party_imports_stay_external() {
+    let graph = package_graph();
+    assert!(graph
+        .nodes
+        .values()
+        .any(|n| n.is_external && n.metadata.name == "Flask"));
+}
@@ -0,0 +1,57 @@
+mod common;
+
+use common::{graph_for, internal_callees};
+use trackast::language::Language;
+
+#[test]
+fn test_rust_crate_self_and_super_paths() {
+    let graph = graph_for(Language::Rust, "resolver/rust", "main.rs");
+
+    assert_eq!(
+        internal_callees(&graph, "main", "main"),
+        ["main::net::Client::new", "main::net::fetch", "main::util::log", "main::util::retry"]
+    );
+    assert_eq!(
+        internal_callees(&graph, "main::net", "fetch"),
+        ["main::net::Client::new", "main::util::log", "main::util::retry"]
+    );
+    assert_eq!(internal_callees(&graph, "main::net", "Client::new"), ["main::util::log"]);
+}
+
+#[test]
+fn test_rust_inline_and_file_backed_modules() {
+    let graph = graph_for(Language::Rust, "modules/rust", "main.rs");
+
+    assert_eq!(
+        internal_callees(&graph, "main", "main"),
+        ["main::net::client::connect", "main::store::save", "main::util::fmt::pad"]
+    );
+    assert_eq!(internal_callees(&graph, "main::util::fmt", "pad"), ["main::util::log"]);
+    assert_eq!(internal_callees(&graph, "main::store", "save"), ["main::store::cache::put"]);
+    assert_eq!(internal_callees(&graph, "main::store::cache", "put"), ["main::util::log"]);
+    assert_eq!(internal_callees(&graph, "main::net::client", "connect"), ["main::util::log"]);
+    assert_eq!(internal_callees(&graph, "main::tests", "smoke"), ["main::main"]);
+}
+
+#[test]
+fn test_rust_modules_of_a_used_file_sit_in_its_directory() {
+    // `helpers.rs` is reached through `use`, not `mod`, so it is no crate root
+    let graph = graph_for(Language::Rust, "modules/rust_use", "main.rs");
+
+    assert_eq!(internal_callees(&graph, "helpers", "run"), ["helpers::inner::step"]);
+}
+
+#[test]
+fn test_python_cls_calls_and_imported_class() {
+    let graph = graph_for(Language::Python, "resolver/python", "main.py");
+
+    assert_eq!(internal_callees(&graph, "main", "main"), ["models::Model.create"]);
+    assert_eq!(internal_callees(&graph, "models", "Model.create"), ["models::Model.validate"]);
+}
+
+#[test]
+fn test_javascript_default_import() {
+    let graph = graph_for(Language::JavaScript, "resolver/javascript", "main.js");
+
+    assert_eq!(internal_callees(&graph, "main", "main"), ["greeter::greet"]);
+}
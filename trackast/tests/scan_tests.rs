@@ -0,0 +1,65 @@
+mod common;
+
+use common::fixture_root;
+use trackast::language::Language;
+use trackast::resolver::{MixedResolver, Resolver};
+use trackast::scan::DirectoryScanner;
+use trackast_lib::builder::CallGraphBuilder;
+use trackast_lib::function_id::FunctionId;
+
+fn relative_files(scanner: &DirectoryScanner) -> Vec<String> {
+    let root = fixture_root("scan");
+    scanner
+        .files()
+        .expect("Failed to walk fixture")
+        .iter()
+        .map(|path| path.strip_prefix(&root).unwrap().to_str().unwrap().replace('\\', "/"))
+        .collect()
+}
+
+#[test]
+fn test_scan_honours_gitignore() {
+    // build/ and *.generated.py are ignored, and nothing imports the plugins
+    let files = relative_files(&DirectoryScanner::new(fixture_root("scan")));
+    assert_eq!(
+        files,
+        ["app.py", "helpers.py", "plugins/greet.py", "plugins/legacy.py", "scripts/deploy.js"]
+    );
+}
+
+#[test]
+fn test_scan_filters_by_glob_and_language() {
+    let scanner = DirectoryScanner::new(fixture_root("scan"))
+        .with_include("plugins/**")
+        .with_include("helpers.py")
+        .with_exclude("**/legacy.py");
+    assert_eq!(relative_files(&scanner), ["helpers.py", "plugins/greet.py"]);
+
+    let scanner = DirectoryScanner::new(fixture_root("scan")).with_language(Language::JavaScript);
+    assert_eq!(relative_files(&scanner), ["scripts/deploy.js"]);
+
+    let invalid = DirectoryScanner::new(fixture_root("scan")).with_include("[");
+    assert!(invalid.files().is_err());
+}
+
+#[test]
+fn test_scanned_files_build_one_graph() {
+    let mut asts = DirectoryScanner::new(fixture_root("scan")).scan().expect("Failed to scan");
+    assert_eq!(asts.len(), 5);
+    MixedResolver::new(&asts).resolve_all(&mut asts);
+
+    let mut builder = CallGraphBuilder::new();
+    for ast in asts {
+        builder.add_ast(ast).expect("Failed to add AST");
+    }
+    let graph = builder.build().expect("Failed to build graph");
+
+    // A plugin nothing imports is in the graph, bound to a helper in another file
+    let run = FunctionId::new("plugins::greet::run::() -> ()".to_string());
+    let callees: Vec<&str> = graph.get_edges_from(&run).iter().map(|edge| edge.to.as_str()).collect();
+    assert!(callees.contains(&"helpers::shout::() -> ()"));
+
+    let deploy = FunctionId::new("scripts::deploy::deploy::() -> ()".to_string());
+    assert_eq!(graph.get_node(&deploy).unwrap().metadata.language.as_deref(), Some("JavaScript"));
+    assert!(!graph.node_ids().any(|id| id.as_str().starts_with("build::")));
+}
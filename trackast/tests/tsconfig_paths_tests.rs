@@ -0,0 +1,27 @@
+mod common;
+
+use common::{graph_for, internal_callees};
+use trackast::language::Language;
+use trackast_lib::graph::CallGraph;
+
+fn aliased_graph() -> CallGraph {
+    graph_for(Language::TypeScript, "tsconfig_paths", "src/main.ts")
+}
+
+#[test]
+fn test_paths_and_base_url_from_extended_config() {
+    let graph = aliased_graph();
+
+    // `@app/*` and `@shared` come from `paths`, `utils/format` from `baseUrl`,
+    // all declared in the config `tsconfig.json` extends
+    assert_eq!(
+        internal_callees(&graph, "src::main", "main"),
+        ["shared::log", "src::app::services::api::fetchUser", "src::utils::format::formatName"]
+    );
+}
+
+#[test]
+fn test_aliases_resolve_from_nested_modules() {
+    let graph = aliased_graph();
+    assert_eq!(internal_callees(&graph, "src::app::services::api", "fetchUser"), ["shared::log"]);
+}
@@ -0,0 +1,37 @@
+mod common;
+
+use common::{graph_for, internal_callees};
+use trackast::language::Language;
+use trackast_lib::graph::CallGraph;
+
+fn typescript_graph() -> CallGraph {
+    graph_for(Language::TypeScript, "typescript", "main.ts")
+}
+
+#[test]
+fn test_signatures_carry_type_annotations() {
+    let graph = typescript_graph();
+    let report = graph
+        .nodes
+        .values()
+        .find(|n| n.metadata.name == "report")
+        .expect("report not found");
+    assert_eq!(report.metadata.signature.to_string(), "<T extends Shape>(shape: T, scale: number) -> string");
+}
+
+#[test]
+fn test_typed_receivers_and_namespaces_resolve() {
+    let graph = typescript_graph();
+    assert_eq!(
+        internal_callees(&graph, "main", "report"),
+        ["geometry::Units::toCentimetres", "shapes::Shape.area", "shapes::Shape.describe"]
+    );
+}
+
+#[test]
+fn test_interface_and_abstract_methods_dispatch_to_overrides() {
+    let graph = typescript_graph();
+    assert_eq!(internal_callees(&graph, "shapes", "Shape.area"), ["shapes::Polygon.area"]);
+    assert_eq!(internal_callees(&graph, "shapes", "Polygon.area"), ["shapes::Square.area"]);
+    assert_eq!(internal_callees(&graph, "shapes", "Polygon.describe"), ["shapes::Polygon.area"]);
+}